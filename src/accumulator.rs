@@ -0,0 +1,137 @@
+use crate::MinMaxResult;
+use std::marker::PhantomData;
+
+/// Trait for the dtype strategies with which an [`ArgMinMaxAccumulator`] can be used.
+///
+/// Implemented for [`Int`](crate::dtype_strategy::Int) for the integers and, for the
+/// floats, for [`FloatIgnoreNaN`](crate::dtype_strategy::FloatIgnoreNaN) (the behavior
+/// of [`ArgMinMax`](crate::ArgMinMax)) and
+/// [`FloatReturnNaN`](crate::dtype_strategy::FloatReturnNaN) (the behavior of
+/// [`NaNArgMinMax`](crate::NaNArgMinMax)).
+///
+pub trait AccumulatorStrategy<T> {
+    /// Get the index of the minimum and maximum values in the (non-empty) chunk, using
+    /// the (SIMD) implementation of this strategy.
+    fn argminmax(data: &[T]) -> (usize, usize);
+
+    /// Get the index and value of the minimum and maximum values in the (non-empty)
+    /// chunk, using the (SIMD) implementation of this strategy.
+    ///
+    /// The default implementation looks up the values at the indices of
+    /// [`argminmax`](AccumulatorStrategy::argminmax).
+    ///
+    fn argminmax_with_values(data: &[T]) -> MinMaxResult<T>
+    where
+        T: Copy,
+    {
+        let (min_index, max_index) = Self::argminmax(data);
+        MinMaxResult {
+            min_index,
+            min_value: data[min_index],
+            max_index,
+            max_value: data[max_index],
+        }
+    }
+}
+
+/// Accumulator for finding the minimum and maximum values in data that arrives in
+/// chunks (e.g., blocks that are read from a socket or a file).
+///
+/// Each chunk that is passed to [`update`](ArgMinMaxAccumulator::update) is processed
+/// by the (SIMD) implementation of the `Strategy` (see [`AccumulatorStrategy`]), after
+/// which only the global offset and the index and value of the minimum and maximum
+/// are kept. The result of [`finish`](ArgMinMaxAccumulator::finish) (and
+/// [`result`](ArgMinMaxAccumulator::result)) is the same as that of a single
+/// `argminmax` (or `nanargminmax` for
+/// [`FloatReturnNaN`](crate::dtype_strategy::FloatReturnNaN)) call on the concatenated
+/// chunks - including the tie-breaking and the index of the first NaN.
+///
+pub struct ArgMinMaxAccumulator<T, Strategy> {
+    offset: usize,
+    result: Option<MinMaxResult<T>>,
+    _strategy: PhantomData<Strategy>,
+}
+
+impl<T: Copy, Strategy: AccumulatorStrategy<T>> ArgMinMaxAccumulator<T, Strategy> {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        ArgMinMaxAccumulator {
+            offset: 0,
+            result: None,
+            _strategy: PhantomData,
+        }
+    }
+
+    /// Process the next chunk of data (empty chunks are allowed).
+    pub fn update(&mut self, data: &[T]) {
+        if data.is_empty() {
+            return;
+        }
+        let chunk = Strategy::argminmax_with_values(data);
+        self.push(chunk.with_offset(self.offset), data.len());
+    }
+
+    /// Merge the accumulator of the data that follows the data of this accumulator.
+    ///
+    /// This allows to process different parts of the data independently (e.g., in
+    /// different threads), the indices of `later` are shifted by the number of values
+    /// in this accumulator.
+    ///
+    pub fn merge(&mut self, later: Self) {
+        match later.result {
+            Some(result) => self.push(result.with_offset(self.offset), later.offset),
+            None => self.offset += later.offset,
+        }
+    }
+
+    /// Get the number of values that have been accumulated (i.e., the offset of the
+    /// next chunk).
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get the index and value of the minimum and maximum values so far.
+    ///
+    /// # Returns
+    /// The [`MinMaxResult`] of the accumulated data, or `None` when no values have been
+    /// accumulated.
+    ///
+    pub fn result(&self) -> Option<MinMaxResult<T>> {
+        self.result
+    }
+
+    /// Get the index of the minimum and maximum values of all the accumulated data.
+    ///
+    /// # Returns
+    /// `Some((min_index, max_index))`, or `None` when no values have been accumulated.
+    ///
+    pub fn finish(&self) -> Option<(usize, usize)> {
+        self.result.map(|result| result.indices())
+    }
+
+    /// Merge the (shifted) result of the `len` values that follow the current data.
+    #[inline(always)]
+    fn push(&mut self, later: MinMaxResult<T>, len: usize) {
+        self.result = Some(match self.result {
+            Some(result) => result.merge(later, Strategy::argminmax),
+            None => later,
+        });
+        self.offset += len;
+    }
+}
+
+impl<T: Copy, Strategy: AccumulatorStrategy<T>> Default for ArgMinMaxAccumulator<T, Strategy> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, Strategy> Clone for ArgMinMaxAccumulator<T, Strategy> {
+    fn clone(&self) -> Self {
+        ArgMinMaxAccumulator {
+            offset: self.offset,
+            result: self.result,
+            _strategy: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use num_traits::AsPrimitive;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Trait for finding the minimum and maximum values in equal-width bins of an array
+/// (i.e., MinMax downsampling). For floats, NaNs are ignored.
+///
+/// This trait is implemented for slices and `Vec`s of the same data types as
+/// [`ArgMinMax`](crate::ArgMinMax). The array of `n` values is split into `n_bins`
+/// bins, where bin `i` consists of the values in
+/// `floor(i * n / n_bins)..floor((i + 1) * n / n_bins)` - the size of each bin is thus
+/// either `floor(n / n_bins)` or `ceil(n / n_bins)`.
+///
+/// The bins can also be defined on a sorted x-axis (e.g., timestamps of unevenly
+/// spaced samples), see [`binned_argminmax_x`](BinnedArgMinMax::binned_argminmax_x)
+/// and [`binned_argminmax_edges`](BinnedArgMinMax::binned_argminmax_edges). The bins
+/// are then found by binary searching the x-axis, and the (SIMD) kernels run on the
+/// corresponding values.
+///
+pub trait BinnedArgMinMax {
+    /// Get the index of the minimum and maximum values of each bin.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// A flat vector with the sorted indices of the minimum and maximum values of the
+    /// bins (i.e., at most `2 * n_bins` indices). When the minimum and maximum of a bin
+    /// are at the same index, this index is returned once. Empty bins (when
+    /// `n_bins > n`) are skipped.
+    ///
+    /// # Caution
+    /// When a bin contains *only* NaNs, the index of its first value is returned.
+    ///
+    /// # Panics
+    /// When `n_bins` is 0.
+    ///
+    fn binned_argminmax(&self, n_bins: usize) -> Vec<usize>;
+
+    /// Get the index of the minimum and maximum values of each bin, where the bins are
+    /// processed in parallel (using [`rayon`](https://docs.rs/rayon)).
+    ///
+    /// The result is the same as that of [`binned_argminmax`](BinnedArgMinMax::binned_argminmax).
+    ///
+    /// # Panics
+    /// When `n_bins` is 0.
+    ///
+    #[cfg(feature = "rayon")]
+    fn par_binned_argminmax(&self, n_bins: usize) -> Vec<usize>;
+
+    /// Get the index of the minimum and maximum values of each bin, where the bins
+    /// are `n_bins` equal-width intervals of the sorted x-axis `x` (from its first to
+    /// its last value).
+    ///
+    /// Bin `i` contains the values whose x lies in `[edge_i, edge_i+1)` (the last bin
+    /// also contains its right edge). The edges are computed in `f64`.
+    ///
+    /// When dealing with floats, NaNs (in the values) are ignored.
+    ///
+    /// # Returns
+    /// For each bin, a tuple of the index of the minimum and maximum values
+    /// `(min_index, max_index)`, or `None` when the bin is empty.
+    ///
+    /// # Panics
+    /// When `n_bins` is 0 or when `x` and the array have a different length.
+    ///
+    fn binned_argminmax_x<X>(&self, x: &[X], n_bins: usize) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>;
+
+    /// Get the index of the minimum and maximum values of each bin, where the bins are
+    /// defined by the sorted `edges` on the sorted x-axis `x`.
+    ///
+    /// Bin `i` contains the values whose x lies in `[edges[i], edges[i + 1])` (the last
+    /// bin also contains its right edge). Values outside the edges are not part of any
+    /// bin.
+    ///
+    /// When dealing with floats, NaNs (in the values) are ignored.
+    ///
+    /// # Returns
+    /// For each of the `edges.len() - 1` bins, a tuple of the index of the minimum and
+    /// maximum values `(min_index, max_index)`, or `None` when the bin is empty.
+    ///
+    /// # Panics
+    /// When `x` and the array have a different length.
+    ///
+    fn binned_argminmax_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd;
+}
+
+/// Trait for finding the minimum and maximum values in equal-width bins of an array
+/// (i.e., MinMax downsampling). For floats, NaNs are propagated - index of the first
+/// NaN in the bin is returned.
+///
+/// See [`BinnedArgMinMax`] for the supported types and the bins.
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub trait NaNBinnedArgMinMax {
+    /// Get the index of the minimum and maximum values of each bin.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN in the
+    /// bin is returned.
+    ///
+    /// # Returns
+    /// A flat vector with the sorted indices of the minimum and maximum values of the
+    /// bins (i.e., at most `2 * n_bins` indices). When the minimum and maximum of a bin
+    /// are at the same index, this index is returned once. Empty bins (when
+    /// `n_bins > n`) are skipped.
+    ///
+    /// # Panics
+    /// When `n_bins` is 0.
+    ///
+    fn binned_nanargminmax(&self, n_bins: usize) -> Vec<usize>;
+
+    /// Get the index of the minimum and maximum values of each bin, where the bins are
+    /// processed in parallel (using [`rayon`](https://docs.rs/rayon)).
+    ///
+    /// The result is the same as that of [`binned_nanargminmax`](NaNBinnedArgMinMax::binned_nanargminmax).
+    ///
+    /// # Panics
+    /// When `n_bins` is 0.
+    ///
+    #[cfg(feature = "rayon")]
+    fn par_binned_nanargminmax(&self, n_bins: usize) -> Vec<usize>;
+
+    /// Get the index of the minimum and maximum values of each bin, where the bins
+    /// are `n_bins` equal-width intervals of the sorted x-axis `x`.
+    ///
+    /// See [`BinnedArgMinMax::binned_argminmax_x`] for the bins. NaNs (in the values)
+    /// are propagated - index of the first NaN in the bin is returned.
+    ///
+    /// # Panics
+    /// When `n_bins` is 0 or when `x` and the array have a different length.
+    ///
+    fn binned_nanargminmax_x<X>(&self, x: &[X], n_bins: usize) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>;
+
+    /// Get the index of the minimum and maximum values of each bin, where the bins are
+    /// defined by the sorted `edges` on the sorted x-axis `x`.
+    ///
+    /// See [`BinnedArgMinMax::binned_argminmax_edges`] for the bins. NaNs (in the
+    /// values) are propagated - index of the first NaN in the bin is returned.
+    ///
+    /// # Panics
+    /// When `x` and the array have a different length.
+    ///
+    fn binned_nanargminmax_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd;
+}
+
+/// Get the start of the given bin, when splitting `n` values in `n_bins` bins.
+#[inline(always)]
+fn _bin_start(n: usize, n_bins: usize, bin: usize) -> usize {
+    // u128 to avoid overflow of bin * n
+    (bin as u128 * n as u128 / n_bins as u128) as usize
+}
+
+/// Get the (start, end) of the given bin, when splitting `n` values in `n_bins` bins.
+#[inline(always)]
+fn _bin_bounds(n: usize, n_bins: usize, bin: usize) -> (usize, usize) {
+    (_bin_start(n, n_bins, bin), _bin_start(n, n_bins, bin + 1))
+}
+
+/// Get the offsets (see `segmented_argminmax_generic`) of the `n_bins` equal-width bins
+/// of an array of `n` values.
+///
+/// # Panics
+/// When `n_bins` is 0.
+///
+pub(crate) fn index_to_offsets(n: usize, n_bins: usize) -> Vec<usize> {
+    assert!(n_bins > 0, "n_bins must be larger than 0");
+    (0..=n_bins).map(|bin| _bin_start(n, n_bins, bin)).collect()
+}
+
+/// Get the sorted indices of the minimum and maximum values of the given bin (none when
+/// the bin is empty, one when both are at the same index).
+#[inline(always)]
+fn _bin_indices<T: Copy>(
+    data: &[T],
+    n_bins: usize,
+    bin: usize,
+    slice_fn: impl Fn(&[T]) -> (usize, usize),
+) -> impl Iterator<Item = usize> {
+    let (start, end) = _bin_bounds(data.len(), n_bins, bin);
+    let indices = match start == end {
+        true => None,
+        false => {
+            let (min_index, max_index) = slice_fn(&data[start..end]);
+            let (first, last) = (min_index.min(max_index), min_index.max(max_index));
+            Some((start + first, (first != last).then_some(start + last)))
+        }
+    };
+    indices
+        .into_iter()
+        .flat_map(|(first, last)| std::iter::once(first).chain(last))
+}
+
+/// Get the sorted indices of the minimum and maximum values of each bin.
+///
+/// `slice_fn` returns the (min_index, max_index) of a bin.
+///
+/// # Panics
+/// When `n_bins` is 0.
+///
+pub(crate) fn binned_argminmax_generic<T: Copy>(
+    data: &[T],
+    n_bins: usize,
+    slice_fn: impl Fn(&[T]) -> (usize, usize),
+) -> Vec<usize> {
+    assert!(n_bins > 0, "n_bins must be larger than 0");
+    let mut result = Vec::with_capacity(2 * std::cmp::min(n_bins, data.len()));
+    for bin in 0..n_bins {
+        result.extend(_bin_indices(data, n_bins, bin, &slice_fn));
+    }
+    result
+}
+
+/// Get the sorted indices of the minimum and maximum values of each bin, where the
+/// bins are processed in parallel (see `binned_argminmax_generic`).
+///
+/// # Panics
+/// When `n_bins` is 0.
+///
+#[cfg(feature = "rayon")]
+pub(crate) fn par_binned_argminmax_generic<T: Copy + Send + Sync>(
+    data: &[T],
+    n_bins: usize,
+    slice_fn: impl Fn(&[T]) -> (usize, usize) + Sync,
+) -> Vec<usize> {
+    assert!(n_bins > 0, "n_bins must be larger than 0");
+    // Collecting a parallel iterator preserves the order of the bins
+    (0..n_bins)
+        .into_par_iter()
+        .flat_map_iter(|bin| _bin_indices(data, n_bins, bin, &slice_fn))
+        .collect()
+}
+
+/// Get the offsets (see `segmented_argminmax_generic`) of the bins defined by the
+/// sorted `edges` on the sorted x-axis.
+///
+/// Bin `i` is `[edges[i], edges[i + 1])`, the last bin also contains its right edge.
+/// As the edges are sorted, each edge is searched for in the part of the x-axis after
+/// the previous edge.
+///
+pub(crate) fn edges_to_offsets<X: Copy + PartialOrd>(x: &[X], edges: &[X]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(edges.len());
+    let mut offset = 0;
+    for (i, &edge) in edges.iter().enumerate() {
+        offset += match i + 1 == edges.len() {
+            // The last bin also contains its right edge
+            true => x[offset..].partition_point(|&v| v <= edge),
+            false => x[offset..].partition_point(|&v| v < edge),
+        };
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// Get the offsets of the `n_bins` equal-width bins of the sorted x-axis (from its
+/// first to its last value), where the edges are computed in `f64`.
+///
+/// # Panics
+/// When `n_bins` is 0.
+///
+pub(crate) fn x_to_offsets<X: Copy + AsPrimitive<f64>>(x: &[X], n_bins: usize) -> Vec<usize> {
+    assert!(n_bins > 0, "n_bins must be larger than 0");
+    if x.is_empty() {
+        return vec![0; n_bins + 1];
+    }
+    let (first, last): (f64, f64) = (x[0].as_(), x[x.len() - 1].as_());
+    let width = (last - first) / n_bins as f64;
+    let mut offsets = Vec::with_capacity(n_bins + 1);
+    offsets.push(0);
+    let mut offset = 0;
+    for i in 1..n_bins {
+        let edge = first + i as f64 * width;
+        offset += x[offset..].partition_point(|&v| v.as_() < edge);
+        offsets.push(offset);
+    }
+    // The last bin also contains its right edge (i.e., the last value)
+    offsets.push(x.len());
+    offsets
+}
@@ -0,0 +1,166 @@
+use crate::MinMaxResult;
+
+/// The position of a value in chunked data (i.e., a sequence of arrays).
+///
+/// This struct is returned by the methods of the [`ChunkedArgMinMax`] and
+/// [`NaNChunkedArgMinMax`] traits.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChunkedIndex {
+    /// The index of the chunk (array) that contains the value
+    pub chunk_index: usize,
+    /// The index of the value within its chunk
+    pub offset: usize,
+    /// The (flat) index of the value in the concatenation of all chunks
+    pub index: usize,
+}
+
+/// Trait for finding the minimum and maximum values in chunked data (e.g., one column
+/// across multiple record batches). Null slots are skipped. For floats, NaNs are
+/// ignored.
+///
+/// This trait is implemented for slices of apache [`arrow::PrimitiveArray`](https://docs.rs/arrow/latest/arrow/array/struct.PrimitiveArray.html)
+/// and [`arrow2::PrimitiveArray`](https://docs.rs/arrow2/latest/arrow2/array/struct.PrimitiveArray.html)
+/// (when the `arrow` or `arrow2` feature is enabled), and for slices of arrow
+/// [`ArrayRef`](https://docs.rs/arrow/latest/arrow/array/type.ArrayRef.html)s. Each
+/// chunk is reduced as in [`NullableArgMinMax`](crate::NullableArgMinMax) and the
+/// partial results are merged.
+///
+pub trait ChunkedArgMinMax {
+    /// Get the position of the minimum and maximum values in the chunked data.
+    ///
+    /// When dealing with floats, NaNs are ignored. Null slots (and thus empty chunks
+    /// and chunks with only nulls) are skipped.
+    ///
+    /// # Returns
+    /// `Some((min_index, max_index))` with the position of the minimum and maximum
+    /// values, or `None` when there are no valid (non-null) values. For a slice of
+    /// `ArrayRef`s, `None` is also returned when the chunks are not primitive arrays of
+    /// the same (supported) data type.
+    ///
+    /// # Caution
+    /// When the valid values of a float array are *only* NaNs, the position of the
+    /// first valid value is returned for both.
+    ///
+    fn argminmax_chunked(&self) -> Option<(ChunkedIndex, ChunkedIndex)>;
+
+    /// Get the position of the minimum value in the chunked data.
+    ///
+    /// When dealing with floats, NaNs are ignored. Null slots are skipped.
+    ///
+    /// # Returns
+    /// `Some(min_index)`, or `None` when there are no valid (non-null) values (see
+    /// [`argminmax_chunked`](Self::argminmax_chunked)).
+    ///
+    fn argmin_chunked(&self) -> Option<ChunkedIndex>;
+
+    /// Get the position of the maximum value in the chunked data.
+    ///
+    /// When dealing with floats, NaNs are ignored. Null slots are skipped.
+    ///
+    /// # Returns
+    /// `Some(max_index)`, or `None` when there are no valid (non-null) values (see
+    /// [`argminmax_chunked`](Self::argminmax_chunked)).
+    ///
+    fn argmax_chunked(&self) -> Option<ChunkedIndex>;
+}
+
+/// Trait for finding the minimum and maximum values in chunked data (e.g., one column
+/// across multiple record batches). Null slots are skipped. For floats, NaNs are
+/// propagated - position of the first (non-null) NaN is returned.
+///
+/// See [`ChunkedArgMinMax`] for the supported types.
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub trait NaNChunkedArgMinMax {
+    /// Get the position of the minimum and maximum values in the chunked data.
+    ///
+    /// When dealing with floats, NaNs are propagated - position of the first NaN is
+    /// returned. Null slots (and thus empty chunks and chunks with only nulls) are
+    /// skipped.
+    ///
+    /// # Returns
+    /// `Some((min_index, max_index))` with the position of the minimum and maximum
+    /// values, or `None` when there are no valid (non-null) values. For a slice of
+    /// `ArrayRef`s, `None` is also returned when the chunks are not float arrays of the
+    /// same (supported) data type.
+    ///
+    fn nanargminmax_chunked(&self) -> Option<(ChunkedIndex, ChunkedIndex)>;
+
+    /// Get the position of the minimum value in the chunked data.
+    ///
+    /// When dealing with floats, NaNs are propagated - position of the first NaN is
+    /// returned. Null slots are skipped.
+    ///
+    /// # Returns
+    /// `Some(min_index)`, or `None` when there are no valid (non-null) values (see
+    /// [`nanargminmax_chunked`](Self::nanargminmax_chunked)).
+    ///
+    fn nanargmin_chunked(&self) -> Option<ChunkedIndex>;
+
+    /// Get the position of the maximum value in the chunked data.
+    ///
+    /// When dealing with floats, NaNs are propagated - position of the first NaN is
+    /// returned. Null slots are skipped.
+    ///
+    /// # Returns
+    /// `Some(max_index)`, or `None` when there are no valid (non-null) values (see
+    /// [`nanargminmax_chunked`](Self::nanargminmax_chunked)).
+    ///
+    fn nanargmax_chunked(&self) -> Option<ChunkedIndex>;
+}
+
+/// Get the position of the minimum and maximum values in the chunks.
+///
+/// - `chunk_len` returns the length of a chunk
+/// - `chunk_fn` returns the (min_index, max_index) of the valid values of a chunk -
+///   chunks without valid values (`None`) are skipped
+/// - `chunk_value` returns the value at the given index of a chunk
+/// - `pair_fn` returns the (min_index, max_index) of a slice of two values, which is
+///   used to merge the partial results (where earlier chunks win ties) - this way a
+///   chunk with only NaNs is overruled by a later chunk (when NaNs are ignored)
+///
+/// # Returns
+/// `None` when no chunk has valid values.
+///
+pub(crate) fn argminmax_chunked_generic<A, T: Copy>(
+    chunks: &[A],
+    chunk_len: impl Fn(&A) -> usize,
+    chunk_fn: impl Fn(&A) -> Option<(usize, usize)>,
+    chunk_value: impl Fn(&A, usize) -> T,
+    pair_fn: impl Fn(&[T]) -> (usize, usize),
+) -> Option<(ChunkedIndex, ChunkedIndex)> {
+    let mut result: Option<MinMaxResult<T>> = None;
+    let mut starts: Vec<usize> = Vec::with_capacity(chunks.len());
+    let mut start = 0;
+    for chunk in chunks {
+        starts.push(start);
+        if let Some((min_index, max_index)) = chunk_fn(chunk) {
+            let chunk_result = MinMaxResult {
+                min_index,
+                min_value: chunk_value(chunk, min_index),
+                max_index,
+                max_value: chunk_value(chunk, max_index),
+            }
+            .with_offset(start);
+            result = Some(match result {
+                Some(result) => result.merge(chunk_result, &pair_fn),
+                None => chunk_result,
+            });
+        }
+        start += chunk_len(chunk);
+    }
+
+    let (min_index, max_index) = result?.indices();
+    // The last chunk that starts at or before the index contains the index (empty chunks
+    // that start at the same index come before it)
+    let to_chunked_index = |index: usize| {
+        let chunk_index = starts.partition_point(|&start| start <= index) - 1;
+        ChunkedIndex {
+            chunk_index,
+            offset: index - starts[chunk_index],
+            index,
+        }
+    };
+    Some((to_chunked_index(min_index), to_chunked_index(max_index)))
+}
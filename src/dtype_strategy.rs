@@ -7,18 +7,43 @@ pub struct Int;
 
 /// Strategy for floating point numbers - ignoring NaNs.
 ///
-/// This strategy is available when the `float` or `half` feature is enabled.
+/// This strategy is available when the `float`, `half` or `float8` feature is enabled.
 ///
 /// Note that this strategy is the strategy for floats in the
 /// [`ArgMinMax`](crate::ArgMinMax) trait.
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 pub struct FloatIgnoreNaN;
 
 /// Strategy for floating point numbers - returning NaNs.
 ///
-/// This strategy is available when the `float` or `half` feature is enabled.
+/// This strategy is available when the `float`, `half` or `float8` feature is enabled.
 ///
 /// Note that this strategy is the strategy for floats in the
 /// [`NaNArgMinMax`](crate::NaNArgMinMax) trait.
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 pub struct FloatReturnNaN;
+
+/// Check whether `value` is strictly better than `best`, where `better` is the
+/// comparison (`<` for the minimum, `>` for the maximum).
+///
+/// This is the scalar counterpart of the kernels, for places where a kernel call per
+/// comparison would be too expensive. Ties keep `best` (i.e., the earlier value). The
+/// NaN handling follows the strategy:
+/// - `RETURN_NAN` (`FloatReturnNaN`) -> the first NaN wins
+/// - otherwise (`Int` and `FloatIgnoreNaN`) -> NaNs are ignored, and an initial
+///   NaN is replaced by the first non-NaN value
+///
+#[inline(always)]
+#[allow(clippy::eq_op)]
+pub(crate) fn scalar_is_better<T: PartialOrd, const RETURN_NAN: bool>(
+    value: T,
+    best: T,
+    better: fn(&T, &T) -> bool,
+) -> bool {
+    // NaN is the only value that is not equal to itself
+    if RETURN_NAN {
+        best == best && (value != value || better(&value, &best))
+    } else {
+        better(&value, &best) || (best != best && value == value)
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+/// Error type for the fallible (`try_*`) methods of the [`ArgMinMax`](crate::ArgMinMax)
+/// and [`NaNArgMinMax`](crate::NaNArgMinMax) traits.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArgMinMaxError {
+    /// The array is empty - there is no minimum or maximum value.
+    EmptyInput,
+    /// The (float) array contains only NaNs - there is no minimum or maximum value
+    /// when NaNs are ignored.
+    AllNaN,
+}
+
+impl fmt::Display for ArgMinMaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgMinMaxError::EmptyInput => write!(f, "the array is empty"),
+            ArgMinMaxError::AllNaN => write!(f, "the array contains only NaNs"),
+        }
+    }
+}
+
+impl std::error::Error for ArgMinMaxError {}
+
+/// Error type for [`argminmax_reader`](crate::argminmax_reader) and
+/// [`nanargminmax_reader`](crate::nanargminmax_reader) (and for the
+/// `argminmax_bytes_stream` method of the `ArgMinMaxStreamExt` trait).
+///
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ArgMinMaxReadError {
+    /// Reading the stream failed.
+    Io(std::io::Error),
+    /// The stream ends with the given number of bytes, which do not form a whole value.
+    TrailingBytes(usize),
+    /// The stream is empty - there is no minimum or maximum value.
+    EmptyInput,
+}
+
+impl fmt::Display for ArgMinMaxReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgMinMaxReadError::Io(e) => write!(f, "failed to read the stream: {}", e),
+            ArgMinMaxReadError::TrailingBytes(n) => {
+                write!(f, "the stream ends with a partial value ({} bytes)", n)
+            }
+            ArgMinMaxReadError::EmptyInput => write!(f, "the stream is empty"),
+        }
+    }
+}
+
+impl std::error::Error for ArgMinMaxReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ArgMinMaxReadError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ArgMinMaxReadError {
+    fn from(e: std::io::Error) -> Self {
+        ArgMinMaxReadError::Io(e)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::MinMaxResult;
+
+/// The number of values that are buffered (on the stack) before they are passed to the
+/// (SIMD) kernels. As this is a power of two, it is a multiple of the SIMD lane size
+/// for all data types.
+const ITER_CHUNK_LEN: usize = 1 << 10;
+
+/// Extension trait for finding the minimum and maximum values of an iterator, without
+/// collecting it first.
+///
+/// This trait is implemented for all iterators whose items are of the same data types
+/// as [`ArgMinMax`](crate::ArgMinMax). The items are buffered in fixed-size chunks on
+/// the stack, each chunk is processed by the (SIMD) kernels and the partial results are
+/// merged. The result is the same as that of the corresponding
+/// [`ArgMinMax`](crate::ArgMinMax) (or [`NaNArgMinMax`](crate::NaNArgMinMax)) method on
+/// the collected items - the indices are the positions of the items in the iterator.
+///
+pub trait ArgMinMaxIterExt: Iterator + Sized {
+    /// Get the index of the minimum and maximum items of the iterator.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum items `(min_index, max_index)`,
+    /// or `None` when the iterator is empty.
+    ///
+    /// # Caution
+    /// When the float items are *only* NaNs and / or infinities unexpected behavior may
+    /// occur (in which case index 0 is returned for both).
+    ///
+    fn argminmax_iter(self) -> Option<(usize, usize)>
+    where
+        Self::Item: Copy,
+        for<'a> &'a [Self::Item]: crate::ArgMinMax<Item = Self::Item>;
+
+    /// Get the index of the minimum and maximum items of the iterator.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum items `(min_index, max_index)`,
+    /// or `None` when the iterator is empty.
+    ///
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    fn nanargminmax_iter(self) -> Option<(usize, usize)>
+    where
+        Self::Item: Copy,
+        for<'a> &'a [Self::Item]: crate::NaNArgMinMax<Item = Self::Item>;
+}
+
+/// Get the (min_index, max_index) of the items of the iterator - `None` when the
+/// iterator is empty.
+///
+/// The items are buffered in chunks of `ITER_CHUNK_LEN` (on the stack), `slice_fn`
+/// returns the MinMaxResult of a chunk. The partial results are merged in order, using
+/// `slice_fn` on pairs of values - this way the NaN handling and tie policy are the
+/// same as those of `slice_fn` on all the items.
+///
+pub(crate) fn iter_argminmax_generic<T: Copy>(
+    mut iter: impl Iterator<Item = T>,
+    slice_fn: impl Fn(&[T]) -> MinMaxResult<T>,
+) -> Option<(usize, usize)> {
+    // The first item initializes the buffer (no Default bound on T is required)
+    let first = iter.next()?;
+    let mut buffer = [first; ITER_CHUNK_LEN];
+    let mut len = 1;
+    let mut offset = 0;
+    let mut result: Option<MinMaxResult<T>> = None;
+    loop {
+        for (slot, value) in buffer[len..].iter_mut().zip(&mut iter) {
+            *slot = value;
+            len += 1;
+        }
+        if len == 0 {
+            break; // the iterator was exhausted after the previous (full) chunk
+        }
+        let chunk = &buffer[..len];
+        let chunk_result = slice_fn(chunk).with_offset(offset);
+        result = Some(match result {
+            Some(result) => result.merge(chunk_result, |pair| slice_fn(pair).indices()),
+            None => chunk_result,
+        });
+        offset += len;
+        if len < ITER_CHUNK_LEN {
+            break; // the iterator is exhausted
+        }
+        len = 0;
+    }
+    result.map(|result| result.indices())
+}
@@ -12,13 +12,54 @@
 //!
 //! This crate provides two traits: [`ArgMinMax`](trait.ArgMinMax.html) and [`NaNArgMinMax`](trait.NaNArgMinMax.html).
 //!
-//! These traits are implemented for [`slice`](https://doc.rust-lang.org/std/primitive.slice.html) and [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html).  
+//! These traits are implemented for [`slice`](https://doc.rust-lang.org/std/primitive.slice.html), [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html) and [`VecDeque`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html).  
 //! - For [`ArgMinMax`](trait.ArgMinMax.html) the supported data types are
-//!   - ints: `i8`, `i16`, `i32`, `i64`
-//!   - uints: `u8`, `u16`, `u32`, `u64`
-//!   - floats: `f16`, `f32`, `f64` (see [Features](#features))
+//!   - ints: `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+//!   - uints: `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
+//!   - floats: `F8E4M3`, `F8E5M2`, `f16`, `bf16`, `f32`, `f64` (see [Features](#features))
 //! - For [`NaNArgMinMax`](trait.NaNArgMinMax.html) the supported data types are
-//!   - floats: `f16`, `f32`, `f64` (see [Features](#features))
+//!   - floats: `F8E4M3`, `F8E5M2`, `f16`, `bf16`, `f32`, `f64` (see [Features](#features))
+//!
+//! For a `VecDeque` (e.g., a ring buffer of the last samples) both slices of the deque
+//! are processed without copying, the indices are the logical positions in the deque.
+//!
+//! Besides the indices, both traits can also return the corresponding values (see
+//! [`MinMaxResult`](struct.MinMaxResult.html)), as found by the SIMD / scalar
+//! implementations.
+//!
+//! By default the index of the first occurrence is returned when the minimum / maximum
+//! value occurs multiple times; the `*_with_tie` methods allow to return the index of
+//! the last occurrence instead (see [`TiePolicy`](enum.TiePolicy.html)).
+//!
+//! The argmin / argmax of each (sliding) window of an array is provided by the
+//! [`RollingArgMinMax`](trait.RollingArgMinMax.html) and
+//! [`NaNRollingArgMinMax`](trait.NaNRollingArgMinMax.html) traits, in amortized linear
+//! time.
+//!
+//! The argmin / argmax of each segment of an array (described by an offsets array, as
+//! in apache arrow its `ListArray`) is provided by the
+//! [`SegmentedArgMinMax`](trait.SegmentedArgMinMax.html) and
+//! [`NaNSegmentedArgMinMax`](trait.NaNSegmentedArgMinMax.html) traits.
+//!
+//! MinMax downsampling (the argmin / argmax of each of `n_bins` equal-width bins) is
+//! provided by the [`BinnedArgMinMax`](trait.BinnedArgMinMax.html) and
+//! [`NaNBinnedArgMinMax`](trait.NaNBinnedArgMinMax.html) traits. The bins are either
+//! defined on the indices or on a sorted x-axis (e.g., timestamps).
+//!
+//! M4 downsampling (the first, last, argmin and argmax of each bin - lossless for line
+//! charts) is provided by the [`M4ArgMinMax`](trait.M4ArgMinMax.html) and
+//! [`NaNM4ArgMinMax`](trait.NaNM4ArgMinMax.html) traits, on the same bins.
+//!
+//! MinMaxLTTB downsampling (LTTB on the candidates that are preselected with the SIMD
+//! MinMax downsampling) is provided by the [`MinMaxLTTB`](trait.MinMaxLTTB.html) trait.
+//!
+//! Data that arrives in chunks (e.g., from a socket or a file) can be processed with the
+//! [`ArgMinMaxAccumulator`](struct.ArgMinMaxAccumulator.html), which keeps only the
+//! index and value of the minimum and maximum of the chunks seen so far. Iterators can
+//! be processed without collecting them first with the
+//! [`ArgMinMaxIterExt`](trait.ArgMinMaxIterExt.html) trait, and streams of raw
+//! little-endian values (e.g., sample files that are larger than the memory) with the
+//! [`argminmax_reader`](fn.argminmax_reader.html) function.
 //!
 //! Both traits differ in how they handle NaNs:
 //! - [`ArgMinMax`](trait.ArgMinMax.html) ignores NaNs and returns the index of the minimum and maximum values in an array.
@@ -33,10 +74,13 @@
 //!
 //! - **`nightly_simd`** *(default)* - enables the use of AVX512 & (often) NEON SIMD instructions (requires a nightly compiler).
 //! - **`float`** *(default)* - enables the traits for floats (`f32` and `f64`).
-//! - **`half`** - enables the traits for `f16` (requires the [`half`](https://crates.io/crates/half) crate).
-//! - **`ndarray`** - adds the traits to [`ndarray::ArrayBase`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html) (requires the `ndarray` crate).
-//! - **`arrow`** - adds the traits to [`arrow::array::PrimitiveArray`](https://docs.rs/arrow/latest/arrow/array/struct.PrimitiveArray.html) (requires the `arrow` crate).
-//! - **`arrow2`** - adds the traits to [`arrow2::array::PrimitiveArray`](https://docs.rs/arrow2/latest/arrow2/array/struct.PrimitiveArray.html) (requires the `arrow2` crate).
+//! - **`half`** - enables the traits for `f16` and `bf16` (requires the [`half`](https://crates.io/crates/half) crate).
+//! - **`float8`** - enables the traits for the FP8 formats `F8E4M3` and `F8E5M2` (requires the [`float8`](https://crates.io/crates/float8) crate).
+//! - **`ndarray`** - adds the traits to [`ndarray::ArrayBase`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html) (requires the `ndarray` crate). Arrays (and views) with any stride are supported. Multi-dimensional arrays support axis-wise and global (multi-dimensional index) argminmax through the [`ArgMinMaxNd`](trait.ArgMinMaxNd.html) trait.
+//! - **`arrow`** - adds the traits to [`arrow::array::PrimitiveArray`](https://docs.rs/arrow/latest/arrow/array/struct.PrimitiveArray.html) (requires the `arrow` crate). Null slots are skipped by the [`NullableArgMinMax`](trait.NullableArgMinMax.html) trait. Chunked data (slices of arrays) is supported by the [`ChunkedArgMinMax`](trait.ChunkedArgMinMax.html) trait.
+//! - **`arrow2`** - adds the traits to [`arrow2::array::PrimitiveArray`](https://docs.rs/arrow2/latest/arrow2/array/struct.PrimitiveArray.html) (requires the `arrow2` crate). Null slots are skipped by the [`NullableArgMinMax`](trait.NullableArgMinMax.html) trait. Chunked data (slices of arrays) is supported by the [`ChunkedArgMinMax`](trait.ChunkedArgMinMax.html) trait.
+//! - **`rayon`** - adds the [`ParArgMinMax`](trait.ParArgMinMax.html) and [`NaNParArgMinMax`](trait.NaNParArgMinMax.html) traits, which split large arrays into chunks that are processed in parallel (requires the [`rayon`](https://crates.io/crates/rayon) crate). The result is the same as that of the sequential traits. Also adds the parallel `par_binned_*` methods to [`BinnedArgMinMax`](trait.BinnedArgMinMax.html).
+//! - **`futures`** - adds the [`ArgMinMaxStreamExt`](trait.ArgMinMaxStreamExt.html) trait to (async) streams of chunks of values (e.g., `Vec<T>`) or of raw little-endian bytes (e.g., `bytes::Bytes`), which resolves to the argminmax of the whole stream as a future (requires the [`futures-core`](https://crates.io/crates/futures-core) crate).
 //!
 //!
 //! # Examples
@@ -51,6 +95,11 @@
 //! let (imin, imax) = a.argminmax();
 //! assert_eq!(imin, 0);
 //! assert_eq!(imax, 5);
+//!
+//! let result = a.argminmax_with_values();
+//! assert_eq!((result.min_index, result.min_value), (0, 0));
+//! assert_eq!((result.max_index, result.max_value), (5, 5));
+//! assert_eq!(a.minmax(), (0, 5));
 //! ```
 //!
 //! ## Example with NaNs (default `float` feature)
@@ -81,12 +130,86 @@ use rstest_reuse;
 // #[macro_use]
 // extern crate lazy_static;
 
+mod accumulator;
+mod binned;
+#[cfg(any(feature = "arrow", feature = "arrow2"))]
+mod chunked;
 pub mod dtype_strategy;
+mod error;
+mod iter;
+mod m4;
+mod minmaxlttb;
+#[cfg(feature = "ndarray")]
+mod ndarray_nd;
+#[cfg(any(feature = "arrow", feature = "arrow2"))]
+mod nullable;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod reader;
+mod result;
+mod rolling;
 pub mod scalar;
+mod segmented;
 pub mod simd;
+#[cfg(feature = "futures")]
+mod stream;
+mod tie_policy;
+mod topk;
+
+pub use accumulator::{AccumulatorStrategy, ArgMinMaxAccumulator};
+pub use binned::BinnedArgMinMax;
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub use binned::NaNBinnedArgMinMax;
+#[cfg(all(
+    any(feature = "arrow", feature = "arrow2"),
+    any(feature = "float", feature = "half", feature = "float8")
+))]
+pub use chunked::NaNChunkedArgMinMax;
+#[cfg(any(feature = "arrow", feature = "arrow2"))]
+pub use chunked::{ChunkedArgMinMax, ChunkedIndex};
+pub use error::{ArgMinMaxError, ArgMinMaxReadError};
+pub use iter::ArgMinMaxIterExt;
+pub use m4::M4ArgMinMax;
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub use m4::NaNM4ArgMinMax;
+pub use minmaxlttb::MinMaxLTTB;
+#[cfg(feature = "ndarray")]
+pub use ndarray_nd::ArgMinMaxNd;
+#[cfg(all(
+    feature = "ndarray",
+    any(feature = "float", feature = "half", feature = "float8")
+))]
+pub use ndarray_nd::NaNArgMinMaxNd;
+#[cfg(all(
+    any(feature = "arrow", feature = "arrow2"),
+    any(feature = "float", feature = "half", feature = "float8")
+))]
+pub use nullable::NaNNullableArgMinMax;
+#[cfg(any(feature = "arrow", feature = "arrow2"))]
+pub use nullable::NullableArgMinMax;
+#[cfg(all(
+    feature = "rayon",
+    any(feature = "float", feature = "half", feature = "float8")
+))]
+pub use parallel::NaNParArgMinMax;
+#[cfg(feature = "rayon")]
+pub use parallel::ParArgMinMax;
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub use reader::nanargminmax_reader;
+pub use reader::{argminmax_reader, FromLeBytes};
+pub use result::MinMaxResult;
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub use rolling::NaNRollingArgMinMax;
+pub use rolling::RollingArgMinMax;
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub use segmented::NaNSegmentedArgMinMax;
+pub use segmented::SegmentedArgMinMax;
+#[cfg(feature = "futures")]
+pub use stream::{ArgMinMaxBytesStream, ArgMinMaxStream, ArgMinMaxStreamExt};
+pub use tie_policy::TiePolicy;
 
 pub(crate) use dtype_strategy::Int;
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 pub(crate) use dtype_strategy::{FloatIgnoreNaN, FloatReturnNaN};
 pub(crate) use scalar::{ScalarArgMinMax, SCALAR};
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -100,8 +223,22 @@ pub(crate) use simd::{SIMDArgMinMax, AVX2, SSE};
 ))]
 pub(crate) use simd::{SIMDArgMinMax, NEON};
 
+#[cfg(feature = "rayon")]
+use binned::par_binned_argminmax_generic;
+use binned::{binned_argminmax_generic, edges_to_offsets, index_to_offsets, x_to_offsets};
+use iter::iter_argminmax_generic;
+use m4::m4_generic;
+use minmaxlttb::minmaxlttb_generic;
+use rolling::rolling_arg_best_generic;
+use segmented::segmented_argminmax_generic;
+use topk::{argmax_k_generic, argmin_k_generic};
+
+#[cfg(feature = "float8")]
+use float8::{F8E4M3, F8E5M2};
 #[cfg(feature = "half")]
-use half::f16;
+use half::{bf16, f16};
+use num_traits::AsPrimitive;
+use std::collections::VecDeque;
 
 /// Trait for finding the minimum and maximum values in an array. For floats, NaNs are ignored.  
 ///
@@ -113,7 +250,9 @@ pub trait ArgMinMax {
     // TODO: future work implement these other functions?
     // fn min(self) -> Self::Item;
     // fn max(self) -> Self::Item;
-    // fn minmax(self) -> (T, T);
+
+    /// The type of the values in the array.
+    type Item: Copy;
 
     /// Get the index of the minimum and maximum values in the array.
     ///
@@ -161,6 +300,137 @@ pub trait ArgMinMax {
     /// may occur (in which case index 0 is returned).
     ///
     fn argmax(&self) -> usize;
+
+    /// Get the index and value of the minimum and maximum values in the array.
+    ///
+    /// When dealing with floats, NaNs are ignored (see [`argminmax`](ArgMinMax::argminmax)).
+    ///
+    /// # Returns
+    /// A [`MinMaxResult`] containing the index and value of the minimum and maximum
+    /// values in the array.
+    ///
+    /// # Caution
+    /// When a float array contains *only* NaNs and / or infinities unexpected behavior
+    /// may occur (in which case the index 0 is returned for both). The returned values
+    /// are those found by the implementation - for an array of only NaNs these are
+    /// `+inf` (min) and `-inf` (max).
+    ///
+    fn argminmax_with_values(&self) -> MinMaxResult<Self::Item>;
+
+    /// Get the minimum and maximum values in the array.
+    ///
+    /// When dealing with floats, NaNs are ignored (see [`argminmax`](ArgMinMax::argminmax)).
+    ///
+    /// # Returns
+    /// A tuple of the minimum and maximum values in the array `(min_value, max_value)`.
+    ///
+    /// # Caution
+    /// When a float array contains *only* NaNs and / or infinities unexpected behavior
+    /// may occur (see [`argminmax_with_values`](ArgMinMax::argminmax_with_values)).
+    ///
+    fn minmax(&self) -> (Self::Item, Self::Item) {
+        self.argminmax_with_values().values()
+    }
+
+    /// Get the index of the minimum and maximum values in the array - without
+    /// panicking on empty arrays.
+    ///
+    /// When dealing with floats, NaNs are ignored (see [`argminmax`](ArgMinMax::argminmax)).
+    /// In contrast to `argminmax`, float arrays containing only NaNs and / or
+    /// infinities are handled correctly.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the array
+    /// `(min_index, max_index)`.
+    ///
+    /// # Errors
+    /// - [`ArgMinMaxError::EmptyInput`] when the array is empty.
+    /// - [`ArgMinMaxError::AllNaN`] when the (float) array contains only NaNs.
+    ///
+    fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError>;
+
+    /// Get the index of the minimum value in the array - without panicking on empty
+    /// arrays.
+    ///
+    /// When dealing with floats, NaNs are ignored (see [`argmin`](ArgMinMax::argmin)).
+    ///
+    /// # Returns
+    /// The index of the minimum value in the array.
+    ///
+    /// # Errors
+    /// - [`ArgMinMaxError::EmptyInput`] when the array is empty.
+    /// - [`ArgMinMaxError::AllNaN`] when the (float) array contains only NaNs.
+    ///
+    fn try_argmin(&self) -> Result<usize, ArgMinMaxError>;
+
+    /// Get the index of the maximum value in the array - without panicking on empty
+    /// arrays.
+    ///
+    /// When dealing with floats, NaNs are ignored (see [`argmax`](ArgMinMax::argmax)).
+    ///
+    /// # Returns
+    /// The index of the maximum value in the array.
+    ///
+    /// # Errors
+    /// - [`ArgMinMaxError::EmptyInput`] when the array is empty.
+    /// - [`ArgMinMaxError::AllNaN`] when the (float) array contains only NaNs.
+    ///
+    fn try_argmax(&self) -> Result<usize, ArgMinMaxError>;
+
+    /// Get the indices of the k smallest values in the array.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// The indices of the k smallest values in the array, sorted from the smallest to
+    /// the largest value (equal values are sorted on their index).  
+    /// When the array contains less than k (non-NaN) values, all the indices of the
+    /// (non-NaN) values are returned.
+    ///
+    fn argmin_k(&self, k: usize) -> Vec<usize>;
+
+    /// Get the indices of the k largest values in the array.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// The indices of the k largest values in the array, sorted from the largest to
+    /// the smallest value (equal values are sorted on their index).  
+    /// When the array contains less than k (non-NaN) values, all the indices of the
+    /// (non-NaN) values are returned.
+    ///
+    fn argmax_k(&self, k: usize) -> Vec<usize>;
+
+    /// Get the index of the minimum and maximum values in the array - where the
+    /// [`TiePolicy`] determines which index is returned on ties.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the array
+    /// `(min_index, max_index)`.
+    ///
+    fn argminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize);
+
+    /// Get the index of the minimum value in the array - where the [`TiePolicy`]
+    /// determines which index is returned on ties.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// The index of the minimum value in the array.
+    ///
+    fn argmin_with_tie(&self, tie: TiePolicy) -> usize;
+
+    /// Get the index of the maximum value in the array - where the [`TiePolicy`]
+    /// determines which index is returned on ties.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// The index of the maximum value in the array.
+    ///
+    fn argmax_with_tie(&self, tie: TiePolicy) -> usize;
 }
 
 /// Trait for finding the minimum and maximum values in an array. For floats, NaNs are propagated - index of the first NaN is returned.  
@@ -169,8 +439,11 @@ pub trait ArgMinMax {
 ///  
 /// See the [feature documentation](index.html#features) for more information on the supported data types and array types.
 ///
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 pub trait NaNArgMinMax {
+    /// The type of the values in the array.
+    type Item: Copy;
+
     /// Get the index of the minimum and maximum values in the array.
     ///
     /// When dealing with floats, NaNs are propagated - index of the first NaN is
@@ -219,6 +492,135 @@ pub trait NaNArgMinMax {
     /// that the first NaN is returned.
     ///
     fn nanargmax(&self) -> usize;
+
+    /// Get the index and value of the minimum and maximum values in the array.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN (and the
+    /// NaN itself) is returned (see [`nanargminmax`](NaNArgMinMax::nanargminmax)).
+    ///
+    /// # Returns
+    /// A [`MinMaxResult`] containing the index and value of the minimum and maximum
+    /// values in the array.
+    ///
+    /// # Caution
+    /// When multiple bit-representations for NaNs are used, no guarantee is made
+    /// that the first NaN is returned.
+    ///
+    fn nanargminmax_with_values(&self) -> MinMaxResult<Self::Item>;
+
+    /// Get the minimum and maximum values in the array.
+    ///
+    /// When dealing with floats, NaNs are propagated - if the array contains a NaN,
+    /// NaN is returned for both (see [`nanargminmax`](NaNArgMinMax::nanargminmax)).
+    ///
+    /// # Returns
+    /// A tuple of the minimum and maximum values in the array `(min_value, max_value)`.
+    ///
+    fn nanminmax(&self) -> (Self::Item, Self::Item) {
+        self.nanargminmax_with_values().values()
+    }
+
+    /// Get the index of the minimum and maximum values in the array - without
+    /// panicking on empty arrays.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned (see [`nanargminmax`](NaNArgMinMax::nanargminmax)).
+    /// Note that an array containing only NaNs is thus *not* an error.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the array
+    /// `(min_index, max_index)`.
+    ///
+    /// # Errors
+    /// - [`ArgMinMaxError::EmptyInput`] when the array is empty.
+    ///
+    fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError>;
+
+    /// Get the index of the minimum value in the array - without panicking on empty
+    /// arrays.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned (see [`nanargmin`](NaNArgMinMax::nanargmin)).
+    ///
+    /// # Returns
+    /// The index of the minimum value in the array.
+    ///
+    /// # Errors
+    /// - [`ArgMinMaxError::EmptyInput`] when the array is empty.
+    ///
+    fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError>;
+
+    /// Get the index of the maximum value in the array - without panicking on empty
+    /// arrays.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned (see [`nanargmax`](NaNArgMinMax::nanargmax)).
+    ///
+    /// # Returns
+    /// The index of the maximum value in the array.
+    ///
+    /// # Errors
+    /// - [`ArgMinMaxError::EmptyInput`] when the array is empty.
+    ///
+    fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError>;
+
+    /// Get the indices of the k smallest values in the array.
+    ///
+    /// When dealing with floats, NaNs are propagated - NaNs are ranked before all
+    /// other values (in the order in which they occur in the array).
+    ///
+    /// # Returns
+    /// The indices of the k smallest values in the array, sorted from the smallest to
+    /// the largest value (equal values are sorted on their index).  
+    /// When the array contains less than k values, all the indices are returned.
+    ///
+    fn nanargmin_k(&self, k: usize) -> Vec<usize>;
+
+    /// Get the indices of the k largest values in the array.
+    ///
+    /// When dealing with floats, NaNs are propagated - NaNs are ranked before all
+    /// other values (in the order in which they occur in the array).
+    ///
+    /// # Returns
+    /// The indices of the k largest values in the array, sorted from the largest to
+    /// the smallest value (equal values are sorted on their index).  
+    /// When the array contains less than k values, all the indices are returned.
+    ///
+    fn nanargmax_k(&self, k: usize) -> Vec<usize>;
+
+    /// Get the index of the minimum and maximum values in the array - where the
+    /// [`TiePolicy`] determines which index is returned on ties.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned (regardless of the tie policy).
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the array
+    /// `(min_index, max_index)`.
+    ///
+    fn nanargminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize);
+
+    /// Get the index of the minimum value in the array - where the [`TiePolicy`]
+    /// determines which index is returned on ties.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned (regardless of the tie policy).
+    ///
+    /// # Returns
+    /// The index of the minimum value in the array.
+    ///
+    fn nanargmin_with_tie(&self, tie: TiePolicy) -> usize;
+
+    /// Get the index of the maximum value in the array - where the [`TiePolicy`]
+    /// determines which index is returned on ties.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned (regardless of the tie policy).
+    ///
+    /// # Returns
+    /// The index of the maximum value in the array.
+    ///
+    fn nanargmax_with_tie(&self, tie: TiePolicy) -> usize;
 }
 
 // ---- Helper macros ----
@@ -242,395 +644,647 @@ impl_nb_bits!(i8 i16 i32 i64 u8 u16 u32 u64);
 #[cfg(feature = "float")]
 impl_nb_bits!(f32 f64);
 #[cfg(feature = "half")]
-impl_nb_bits!(f16);
+impl_nb_bits!(f16 bf16);
+#[cfg(feature = "float8")]
+impl_nb_bits!(F8E4M3 F8E5M2);
 
 // ------------------------------ &[T] ------------------------------
 
-/// Macro for implementing ArgMinMax for signed and unsigned integers
-macro_rules! impl_argminmax_int {
-    // $int_type is the integer data type of the array (e.g. i32)
-    // you can pass multiple types (separated by commas) to this macro
-    ($($int_type:ty),*) => {
-        $(
-            impl ArgMinMax for &[$int_type] {
-                fn argminmax(&self) -> (usize, usize) {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        if is_x86_feature_detected!("sse4.1") & (<$int_type>::NB_BITS == 8) {
-                            // 8-bit numbers are best handled by SSE4.1
-                            return unsafe { SSE::<Int>::argminmax(self) }
-                        }
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$int_type>::NB_BITS <= 16) {
-                                // BW (ByteWord) instructions are needed for 8 or 16-bit avx512
-                                return unsafe { AVX512::<Int>::argminmax(self) }
-                            }
-                            else if is_x86_feature_detected!("avx512f") {  // TODO: check if avx512bw is included in avx512f
-                                return unsafe { AVX512::<Int>::argminmax(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            return unsafe { AVX2::<Int>::argminmax(self) }
-                        // SKIP SSE4.2 bc scalar is faster or equivalent for 64 bit numbers
-                        // // } else if is_x86_feature_detected!("sse4.2") & (<$int_type>::NB_BITS == 64) & (<$int_type>::IS_FLOAT == false) {
-                        //     // SSE4.2 is needed for comparing 64-bit integers
-                        //     return unsafe { SSE::argminmax(self) }
-                        } else if is_x86_feature_detected!("sse4.1") & (<$int_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            return unsafe { SSE::<Int>::argminmax(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") & (<$int_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            return unsafe { NEON::<Int>::argminmax(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$int_type>::NB_BITS < 64) {
-                            // TODO: requires v7?
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<Int>::argminmax(self) }
-                        }
-                    }
-                    SCALAR::<Int>::argminmax(self)
-                }
+/// Macro for implementing the `*_with_tie` methods of the ArgMinMax and NaNArgMinMax
+/// traits
+///
+/// `TiePolicy::First` calls the passed (first index) methods, `TiePolicy::Last` calls
+/// the `argminmax_last`, `argmin_last` and `argmax_last` implementations through
+/// `$dispatch` (the dispatch macro that the first index methods use as well, so both
+/// tie policies select the same SIMD or scalar implementation).
+macro_rules! impl_with_tie {
+    // $dispatch is the dispatch macro (e.g. dispatch_int!) and $arg its optional data
+    // type argument
+    // [$minmax_with_tie, $min_with_tie, $max_with_tie] are the generated methods
+    // [$minmax, $min, $max] are the methods that return the first index on ties
+    ($dispatch:ident!($($arg:ty)?), [$minmax_with_tie:ident, $min_with_tie:ident, $max_with_tie:ident], [$minmax:ident, $min:ident, $max:ident]) => {
+        fn $minmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            match tie {
+                TiePolicy::First => self.$minmax(),
+                TiePolicy::Last => $dispatch!(self, argminmax_last $(, $arg)?),
+            }
+        }
 
-                fn argmin(&self) -> usize {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        if is_x86_feature_detected!("sse4.1") & (<$int_type>::NB_BITS == 8) {
-                            // 8-bit numbers are best handled by SSE4.1
-                            return unsafe { SSE::<Int>::argmin(self) }
-                        }
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$int_type>::NB_BITS <= 16) {
-                                // BW (ByteWord) instructions are needed for 8 or 16-bit avx512
-                                return unsafe { AVX512::<Int>::argmin(self) }
-                            } else if is_x86_feature_detected!("avx512f") {
-                                return unsafe { AVX512::<Int>::argmin(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            return unsafe { AVX2::<Int>::argmin(self) }
-                        // SKIP SSE4.2 bc scalar is faster or equivalent for 64 bit numbers
-                        // // } else if is_x86_feature_detected!("sse4.2") & (<$int_type>::NB_BITS == 64) & (<$int_type>::IS_FLOAT == false) {
-                        //     // SSE4.2 is needed for comparing 64-bit integers
-                        //     return unsafe { SSE::argmin(self) }
-                        } else if is_x86_feature_detected!("sse4.1") & (<$int_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            return unsafe { SSE::<Int>::argmin(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") {
-                            return unsafe { NEON::<Int>::argmin(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$int_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<Int>::argmin(self) }
-                        }
-                    }
-                    SCALAR::<Int>::argmin(self)
-                }
+        fn $min_with_tie(&self, tie: TiePolicy) -> usize {
+            match tie {
+                TiePolicy::First => self.$min(),
+                TiePolicy::Last => $dispatch!(self, argmin_last $(, $arg)?),
+            }
+        }
 
-                fn argmax(&self) -> usize {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        if is_x86_feature_detected!("sse4.1") & (<$int_type>::NB_BITS == 8) {
-                            // 8-bit numbers are best handled by SSE4.1
-                            return unsafe { SSE::<Int>::argmax(self) }
-                        }
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$int_type>::NB_BITS <= 16) {
-                                // BW (ByteWord) instructions are needed for 8 or 16-bit avx512
-                                return unsafe { AVX512::<Int>::argmax(self) }
-                            } else if is_x86_feature_detected!("avx512f") {
-                                return unsafe { AVX512::<Int>::argmax(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            return unsafe { AVX2::<Int>::argmax(self) }
-                        // SKIP SSE4.2 bc scalar is faster or equivalent for 64 bit numbers
-                        // // } else if is_x86_feature_detected!("sse4.2") & (<$int_type>::NB_BITS == 64) & (<$int_type>::IS_FLOAT == false) {
-                        //     // SSE4.2 is needed for comparing 64-bit integers
-                        //     return unsafe { SSE::argmax(self) }
-                        } else if is_x86_feature_detected!("sse4.1") & (<$int_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            return unsafe { SSE::<Int>::argmax(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") {
-                            return unsafe { NEON::<Int>::argmax(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$int_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<Int>::argmax(self) }
-                        }
-                    }
-                    SCALAR::<Int>::argmax(self)
-                }
+        fn $max_with_tie(&self, tie: TiePolicy) -> usize {
+            match tie {
+                TiePolicy::First => self.$max(),
+                TiePolicy::Last => $dispatch!(self, argmax_last $(, $arg)?),
             }
-        )*
+        }
     };
 }
 
-/// Macro for implementing ArgMinMax for floats
-#[cfg(any(feature = "float", feature = "half"))]
-macro_rules! impl_argminmax_float {
-    // $float_type is the float data type of the array (e.g. f32)
-    // you can pass multiple types (separated by commas) to this macro
-    ($($float_type:ty),*) => {
-        $(
-            impl ArgMinMax for &[$float_type] {
-                fn argminmax(&self) -> (usize, usize) {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$float_type>::NB_BITS == 16) {
-                                // BW (ByteWord) instructions are needed for 16-bit avx512
-                                return unsafe { AVX512::<FloatIgnoreNaN>::argminmax(self) }
-                            } else if is_x86_feature_detected!("avx512f") {
-                                return unsafe { AVX512::<FloatIgnoreNaN>::argminmax(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            // f16 requires avx2
-                            return unsafe { AVX2::<FloatIgnoreNaN>::argminmax(self) }
-                        } else if is_x86_feature_detected!("avx") & (<$float_type>::NB_BITS > 16) {
-                            // f32 and f64 do not require avx2
-                            return unsafe { AVX2::<FloatIgnoreNaN>::argminmax(self) }
-                        } else if is_x86_feature_detected!("sse4.1") & (<$float_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            return unsafe { SSE::<FloatIgnoreNaN>::argminmax(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // NEON f64 is part of stable Rust (see code below this macro)
-                            return unsafe { NEON::<FloatIgnoreNaN>::argminmax(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatIgnoreNaN>::argminmax(self) }
-                        }
-                    }
-                    SCALAR::<FloatIgnoreNaN>::argminmax(self)
-                }
+/// Macro for calling the SIMDArgMinMax `$method` of the SIMD implementation `$simd` on
+/// `$data` - without `$data`, the (unsafe) method itself is returned, so that it can be
+/// called later on without repeating the dispatch
+macro_rules! simd_kernel {
+    ($simd:ty, $method:ident) => {
+        <$simd>::$method
+    };
+    ($simd:ty, $method:ident, $data:expr) => {
+        unsafe { <$simd>::$method($data) }
+    };
+}
 
-                fn argmin(&self) -> usize {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$float_type>::NB_BITS == 16) {
-                                // BW (ByteWord) instructions are needed for 16-bit avx512
-                                return unsafe { AVX512::<FloatIgnoreNaN>::argmin(self) }
-                            } else if is_x86_feature_detected!("avx512f") {
-                                return unsafe { AVX512::<FloatIgnoreNaN>::argmin(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            // f16 requires avx2
-                            return unsafe { AVX2::<FloatIgnoreNaN>::argmin(self) }
-                        } else if is_x86_feature_detected!("avx") & (<$float_type>::NB_BITS > 16) {
-                            // f32 and f64 do not require avx2
-                            return unsafe { AVX2::<FloatIgnoreNaN>::argmin(self) }
-                        } else if is_x86_feature_detected!("sse4.1") & (<$float_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            return unsafe { SSE::<FloatIgnoreNaN>::argmin(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // NEON f64 is part of stable Rust (see code below this macro)
-                            return unsafe { NEON::<FloatIgnoreNaN>::argmin(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatIgnoreNaN>::argmin(self) }
-                        }
+/// Macro for dispatching a SIMDArgMinMax `$method` on integer data to the best available
+/// SIMD implementation (or the scalar implementation)
+///
+/// `@kernel` returns the selected method (as an `unsafe fn`) instead of calling it, which
+/// is the same for all the dispatch macros below.
+macro_rules! dispatch_int {
+    (@kernel $method:ident, $int_type:ty) => {
+        dispatch_int!(@select $int_type, $method)
+    };
+    ($data:expr, $method:ident, $int_type:ty) => {
+        dispatch_int!(@select $int_type, $method, $data)
+    };
+    (@select $int_type:ty, $method:ident $(, $data:expr)?) => {{
+        // Targets without a SIMD implementation never break out of the labeled block
+        #[allow(unused_labels)]
+        'dispatch: {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                if is_x86_feature_detected!("sse4.1") & (<$int_type>::NB_BITS == 8) {
+                    // 8-bit numbers are best handled by SSE4.1
+                    break 'dispatch simd_kernel!(SSE::<Int>, $method $(, $data)?);
+                }
+                #[cfg(feature = "nightly_simd")]
+                {
+                    if is_x86_feature_detected!("avx512bw") & (<$int_type>::NB_BITS <= 16) {
+                        // BW (ByteWord) instructions are needed for 8 or 16-bit avx512
+                        break 'dispatch simd_kernel!(AVX512::<Int>, $method $(, $data)?);
+                    } else if is_x86_feature_detected!("avx512f") {
+                        break 'dispatch simd_kernel!(AVX512::<Int>, $method $(, $data)?);
                     }
-                    SCALAR::<FloatIgnoreNaN>::argmin(self)
                 }
+                if is_x86_feature_detected!("avx2") {
+                    break 'dispatch simd_kernel!(AVX2::<Int>, $method $(, $data)?);
+                } else if is_x86_feature_detected!("sse4.1") & (<$int_type>::NB_BITS < 64) {
+                    // Scalar is faster for 64-bit numbers
+                    break 'dispatch simd_kernel!(SSE::<Int>, $method $(, $data)?);
+                }
+            }
+            #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
+            {
+                if std::arch::is_aarch64_feature_detected!("neon") & (<$int_type>::NB_BITS < 64) {
+                    // Scalar is faster for 64-bit numbers
+                    break 'dispatch simd_kernel!(NEON::<Int>, $method $(, $data)?);
+                }
+            }
+            #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
+            {
+                if std::arch::is_arm_feature_detected!("neon") & (<$int_type>::NB_BITS < 64) {
+                    // We miss some NEON instructions for 64-bit numbers
+                    break 'dispatch simd_kernel!(NEON::<Int>, $method $(, $data)?);
+                }
+            }
+            SCALAR::<Int>::$method$(($data))?
+        }
+    }};
+}
 
-                fn argmax(&self) -> usize {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$float_type>::NB_BITS == 16) {
-                                // BW (ByteWord) instructions are needed for 16-bit avx512
-                                return unsafe { AVX512::<FloatIgnoreNaN>::argmax(self) }
-                            } else if is_x86_feature_detected!("avx512f") {
-                                return unsafe { AVX512::<FloatIgnoreNaN>::argmax(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            // f16 requires avx2
-                            return unsafe { AVX2::<FloatIgnoreNaN>::argmax(self) }
-                        } else if is_x86_feature_detected!("avx") & (<$float_type>::NB_BITS > 16) {
-                            // f32 and f64 do not require avx2
-                            return unsafe { AVX2::<FloatIgnoreNaN>::argmax(self) }
-                        } else if is_x86_feature_detected!("sse4.1") & (<$float_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            return unsafe { SSE::<FloatIgnoreNaN>::argmax(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // NEON f64 is part of stable Rust (see code below this macro)
-                            return unsafe { NEON::<FloatIgnoreNaN>::argmax(self) }
-                        }
-                    }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatIgnoreNaN>::argmax(self) }
-                        }
+/// Macro for dispatching a SIMDArgMinMax `$method` on 128-bit integer data to the best
+/// available SIMD implementation (or the scalar implementation)
+macro_rules! dispatch_int128 {
+    (@kernel $method:ident, $int_type:ty) => {
+        dispatch_int128!(@select $method)
+    };
+    ($data:expr, $method:ident) => {
+        dispatch_int128!(@select $method, $data)
+    };
+    (@select $method:ident $(, $data:expr)?) => {{
+        // Targets without a SIMD implementation never break out of the labeled block
+        #[allow(unused_labels)]
+        'dispatch: {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                #[cfg(feature = "nightly_simd")]
+                {
+                    if is_x86_feature_detected!("avx512f") {
+                        break 'dispatch simd_kernel!(AVX512::<Int>, $method $(, $data)?);
                     }
-                    SCALAR::<FloatIgnoreNaN>::argmax(self)
+                }
+                if is_x86_feature_detected!("avx2") {
+                    break 'dispatch simd_kernel!(AVX2::<Int>, $method $(, $data)?);
                 }
             }
-
-            impl NaNArgMinMax for &[$float_type] {
-                fn nanargminmax(&self) -> (usize, usize) {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$float_type>::NB_BITS == 16) {
-                                // BW (ByteWord) instructions are needed for 16-bit avx512
-                                return unsafe { AVX512::<FloatReturnNaN>::argminmax(self) }
-                            } else if is_x86_feature_detected!("avx512f") {
-                                return unsafe { AVX512::<FloatReturnNaN>::argminmax(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            return unsafe { AVX2::<FloatReturnNaN>::argminmax(self) }
-                        // SKIP SSE4.2 bc scalar is faster or equivalent for 64 bit numbers
-                        } else if is_x86_feature_detected!("sse4.1") & (<$float_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            // TODO: double check this (observed different things for new float implementation)
-                            return unsafe { SSE::<FloatReturnNaN>::argminmax(self) }
-                        }
+            SCALAR::<Int>::$method$(($data))?
+        }
+    }};
+}
+
+/// Macro for dispatching a SIMDArgMinMax `$method` on float data (ignoring NaNs) to the
+/// best available SIMD implementation (or the scalar implementation)
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+macro_rules! dispatch_float_ignore_nan {
+    (@kernel $method:ident, $float_type:ty) => {
+        dispatch_float_ignore_nan!(@select $float_type, $method)
+    };
+    ($data:expr, $method:ident, $float_type:ty) => {
+        dispatch_float_ignore_nan!(@select $float_type, $method, $data)
+    };
+    (@select $float_type:ty, $method:ident $(, $data:expr)?) => {{
+        // Targets without a SIMD implementation never break out of the labeled block
+        #[allow(unused_labels)]
+        'dispatch: {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                #[cfg(feature = "nightly_simd")]
+                {
+                    if is_x86_feature_detected!("avx512bw") & (<$float_type>::NB_BITS <= 16) {
+                        // BW (ByteWord) instructions are needed for 8 or 16-bit avx512
+                        break 'dispatch simd_kernel!(AVX512::<FloatIgnoreNaN>, $method $(, $data)?);
+                    } else if is_x86_feature_detected!("avx512f") {
+                        break 'dispatch simd_kernel!(AVX512::<FloatIgnoreNaN>, $method $(, $data)?);
                     }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatReturnNaN>::argminmax(self) }
-                        }
+                }
+                if is_x86_feature_detected!("avx2") {
+                    // f16 and FP8 require avx2
+                    break 'dispatch simd_kernel!(AVX2::<FloatIgnoreNaN>, $method $(, $data)?);
+                } else if is_x86_feature_detected!("avx") & (<$float_type>::NB_BITS > 16) {
+                    // f32 and f64 do not require avx2
+                    break 'dispatch simd_kernel!(AVX2::<FloatIgnoreNaN>, $method $(, $data)?);
+                } else if is_x86_feature_detected!("sse4.1") & (<$float_type>::NB_BITS < 64) {
+                    // Scalar is faster for 64-bit numbers
+                    break 'dispatch simd_kernel!(SSE::<FloatIgnoreNaN>, $method $(, $data)?);
+                }
+            }
+            #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
+            {
+                if std::arch::is_aarch64_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
+                    // NEON f64 is part of stable Rust (see code below this macro)
+                    break 'dispatch simd_kernel!(NEON::<FloatIgnoreNaN>, $method $(, $data)?);
+                }
+            }
+            #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
+            {
+                if std::arch::is_arm_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
+                    // We miss some NEON instructions for 64-bit numbers
+                    break 'dispatch simd_kernel!(NEON::<FloatIgnoreNaN>, $method $(, $data)?);
+                }
+            }
+            SCALAR::<FloatIgnoreNaN>::$method$(($data))?
+        }
+    }};
+}
+
+/// Macro for dispatching a SIMDArgMinMax `$method` on float data (returning NaNs) to the
+/// best available SIMD implementation (or the scalar implementation)
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+macro_rules! dispatch_float_return_nan {
+    (@kernel $method:ident, $float_type:ty) => {
+        dispatch_float_return_nan!(@select $float_type, $method)
+    };
+    ($data:expr, $method:ident, $float_type:ty) => {
+        dispatch_float_return_nan!(@select $float_type, $method, $data)
+    };
+    (@select $float_type:ty, $method:ident $(, $data:expr)?) => {{
+        // Targets without a SIMD implementation never break out of the labeled block
+        #[allow(unused_labels)]
+        'dispatch: {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                #[cfg(feature = "nightly_simd")]
+                {
+                    if is_x86_feature_detected!("avx512bw") & (<$float_type>::NB_BITS <= 16) {
+                        // BW (ByteWord) instructions are needed for 8 or 16-bit avx512
+                        break 'dispatch simd_kernel!(AVX512::<FloatReturnNaN>, $method $(, $data)?);
+                    } else if is_x86_feature_detected!("avx512f") {
+                        break 'dispatch simd_kernel!(AVX512::<FloatReturnNaN>, $method $(, $data)?);
                     }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatReturnNaN>::argminmax(self) }
-                        }
+                }
+                if is_x86_feature_detected!("avx2") {
+                    break 'dispatch simd_kernel!(AVX2::<FloatReturnNaN>, $method $(, $data)?);
+                } else if is_x86_feature_detected!("sse4.1") & (<$float_type>::NB_BITS < 64) {
+                    // Scalar is faster for 64-bit numbers
+                    break 'dispatch simd_kernel!(SSE::<FloatReturnNaN>, $method $(, $data)?);
+                }
+            }
+            #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
+            {
+                if std::arch::is_aarch64_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
+                    // We miss some NEON instructions for 64-bit numbers
+                    break 'dispatch simd_kernel!(NEON::<FloatReturnNaN>, $method $(, $data)?);
+                }
+            }
+            #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
+            {
+                if std::arch::is_arm_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
+                    // We miss some NEON instructions for 64-bit numbers
+                    break 'dispatch simd_kernel!(NEON::<FloatReturnNaN>, $method $(, $data)?);
+                }
+            }
+            SCALAR::<FloatReturnNaN>::$method$(($data))?
+        }
+    }};
+}
+
+/// Macro for dispatching a SIMDArgMinMax `$method` on f64 data (ignoring NaNs) on
+/// aarch64 - NEON intrinsics for f64 are part of stable Rust on aarch64
+#[cfg(all(feature = "float", target_arch = "aarch64"))]
+macro_rules! dispatch_neon_f64_ignore_nan {
+    (@kernel $method:ident, $float_type:ty) => {
+        NEON::<FloatIgnoreNaN>::$method
+    };
+    ($data:expr, $method:ident) => {
+        simd_kernel!(NEON::<FloatIgnoreNaN>, $method, $data)
+    };
+}
+
+/// Macro for dispatching a SIMDArgMinMax `$method` on f64 data (returning NaNs) on
+/// aarch64 - the required intrinsics are not part of stable Rust
+#[cfg(all(feature = "float", target_arch = "aarch64"))]
+macro_rules! dispatch_neon_f64_return_nan {
+    (@kernel $method:ident, $float_type:ty) => {
+        dispatch_neon_f64_return_nan!(@select $method)
+    };
+    ($data:expr, $method:ident) => {
+        dispatch_neon_f64_return_nan!(@select $method, $data)
+    };
+    (@select $method:ident $(, $data:expr)?) => {{
+        // Targets without a SIMD implementation never break out of the labeled block
+        #[allow(unused_labels)]
+        'dispatch: {
+            #[cfg(feature = "nightly_simd")]
+            {
+                if std::arch::is_aarch64_feature_detected!("neon") {
+                    break 'dispatch simd_kernel!(NEON::<FloatReturnNaN>, $method $(, $data)?);
+                }
+            }
+            SCALAR::<FloatReturnNaN>::$method$(($data))?
+        }
+    }};
+}
+
+/// Macro for implementing ArgMinMax for signed and unsigned integers
+macro_rules! impl_argminmax_int {
+    // $int_type is the integer data type of the array (e.g. i32)
+    // you can pass multiple types (separated by commas) to this macro
+    ($($int_type:ty),*) => {
+        $(
+            impl ArgMinMax for &[$int_type] {
+                type Item = $int_type;
+
+                fn argminmax(&self) -> (usize, usize) {
+                    self.argminmax_with_values().indices()
+                }
+
+                fn argminmax_with_values(&self) -> MinMaxResult<$int_type> {
+                    dispatch_int!(self, argminmax_with_values, $int_type).into()
+                }
+
+                fn argmin(&self) -> usize {
+                    dispatch_int!(self, argmin, $int_type)
+                }
+
+                fn argmax(&self) -> usize {
+                    dispatch_int!(self, argmax, $int_type)
+                }
+
+                impl_with_tie!(
+                    dispatch_int!($int_type),
+                    [argminmax_with_tie, argmin_with_tie, argmax_with_tie],
+                    [argminmax, argmin, argmax]
+                );
+
+                fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
                     }
-                    SCALAR::<FloatReturnNaN>::argminmax(self)
+                    Ok(self.argminmax())
                 }
 
-                fn nanargmin(&self) -> usize {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$float_type>::NB_BITS == 16) {
-                                // BW (ByteWord) instructions are needed for 16-bit avx512
-                                return unsafe { AVX512::<FloatReturnNaN>::argmin(self) }
-                            } else if is_x86_feature_detected!("avx512f") {
-                                return unsafe { AVX512::<FloatReturnNaN>::argmin(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            return unsafe { AVX2::<FloatReturnNaN>::argmin(self) }
-                        // SKIP SSE4.2 bc scalar is faster or equivalent for 64 bit numbers
-                        } else if is_x86_feature_detected!("sse4.1") & (<$float_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            // TODO: double check this (observed different things for new float implementation)
-                            return unsafe { SSE::<FloatReturnNaN>::argmin(self) }
+                fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
+                    }
+                    Ok(self.argmin())
+                }
+
+                fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
+                    }
+                    Ok(self.argmax())
+                }
+
+                fn argmin_k(&self, k: usize) -> Vec<usize> {
+                    argmin_k_generic(self, k, |block| block.argmin(), true)
+                }
+
+                fn argmax_k(&self, k: usize) -> Vec<usize> {
+                    argmax_k_generic(self, k, |block| block.argmax(), true)
+                }
+            }
+        )*
+    };
+}
+
+/// Macro for implementing ArgMinMax for 128-bit signed and unsigned integers
+///
+/// There are no SSE or NEON implementations for these data types, as their 128-bit
+/// registers hold only a single value.
+macro_rules! impl_argminmax_int128 {
+    // $int_type is the integer data type of the array (e.g. i128)
+    // you can pass multiple types (separated by commas) to this macro
+    ($($int_type:ty),*) => {
+        $(
+            impl ArgMinMax for &[$int_type] {
+                type Item = $int_type;
+
+                fn argminmax(&self) -> (usize, usize) {
+                    self.argminmax_with_values().indices()
+                }
+
+                fn argminmax_with_values(&self) -> MinMaxResult<$int_type> {
+                    dispatch_int128!(self, argminmax_with_values).into()
+                }
+
+                fn argmin(&self) -> usize {
+                    dispatch_int128!(self, argmin)
+                }
+
+                fn argmax(&self) -> usize {
+                    dispatch_int128!(self, argmax)
+                }
+
+                impl_with_tie!(
+                    dispatch_int128!(),
+                    [argminmax_with_tie, argmin_with_tie, argmax_with_tie],
+                    [argminmax, argmin, argmax]
+                );
+
+                fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
+                    }
+                    Ok(self.argminmax())
+                }
+
+                fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
+                    }
+                    Ok(self.argmin())
+                }
+
+                fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
+                    }
+                    Ok(self.argmax())
+                }
+
+                fn argmin_k(&self, k: usize) -> Vec<usize> {
+                    argmin_k_generic(self, k, |block| block.argmin(), true)
+                }
+
+                fn argmax_k(&self, k: usize) -> Vec<usize> {
+                    argmax_k_generic(self, k, |block| block.argmax(), true)
+                }
+            }
+        )*
+    };
+}
+
+/// Macro for implementing ArgMinMax for the pointer-sized integers (usize and isize)
+///
+/// The slice is viewed (without copying) as a slice of the fixed-size integer with the
+/// same width - i.e., the 32-bit or 64-bit implementation is used depending on the
+/// `target_pointer_width`.
+macro_rules! impl_argminmax_ptr_sized {
+    // $ptr_type is the pointer-sized integer data type of the array (e.g. usize)
+    // $int_type is the fixed-size integer data type with the same width (e.g. u64)
+    // $to_int_slice is the name of the (generated) function that views the slice as a
+    // slice of $int_type
+    ($ptr_type:ty, $int_type:ty, $to_int_slice:ident) => {
+        // View the pointer-sized integer slice as a slice of the fixed-size integer type
+        // To do so, the pointer to the slice is casted to a pointer to a slice of the
+        // fixed-size integer type (since both have the same size, alignment and bit
+        // representation)
+        #[inline(always)]
+        fn $to_int_slice(data: &[$ptr_type]) -> &[$int_type] {
+            unsafe { std::slice::from_raw_parts(data.as_ptr() as *const $int_type, data.len()) }
+        }
+
+        impl ArgMinMax for &[$ptr_type] {
+            type Item = $ptr_type;
+
+            fn argminmax(&self) -> (usize, usize) {
+                $to_int_slice(self).argminmax()
+            }
+
+            fn argmin(&self) -> usize {
+                $to_int_slice(self).argmin()
+            }
+
+            fn argmax(&self) -> usize {
+                $to_int_slice(self).argmax()
+            }
+
+            fn argminmax_with_values(&self) -> MinMaxResult<$ptr_type> {
+                let result = $to_int_slice(self).argminmax_with_values();
+                MinMaxResult {
+                    min_index: result.min_index,
+                    min_value: result.min_value as $ptr_type,
+                    max_index: result.max_index,
+                    max_value: result.max_value as $ptr_type,
+                }
+            }
+
+            fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+                $to_int_slice(self).try_argminmax()
+            }
+
+            fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+                $to_int_slice(self).try_argmin()
+            }
+
+            fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+                $to_int_slice(self).try_argmax()
+            }
+
+            fn argmin_k(&self, k: usize) -> Vec<usize> {
+                $to_int_slice(self).argmin_k(k)
+            }
+
+            fn argmax_k(&self, k: usize) -> Vec<usize> {
+                $to_int_slice(self).argmax_k(k)
+            }
+
+            fn argminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+                $to_int_slice(self).argminmax_with_tie(tie)
+            }
+
+            fn argmin_with_tie(&self, tie: TiePolicy) -> usize {
+                $to_int_slice(self).argmin_with_tie(tie)
+            }
+
+            fn argmax_with_tie(&self, tie: TiePolicy) -> usize {
+                $to_int_slice(self).argmax_with_tie(tie)
+            }
+        }
+
+        impl SegmentedArgMinMax for &[$ptr_type] {
+            fn segmented_argminmax<O: AsPrimitive<usize>>(
+                &self,
+                offsets: &[O],
+            ) -> Vec<Option<(usize, usize)>> {
+                $to_int_slice(self).segmented_argminmax(offsets)
+            }
+        }
+    };
+}
+
+/// Macro for implementing ArgMinMax for floats
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+macro_rules! impl_argminmax_float {
+    // $float_type is the float data type of the array (e.g. f32)
+    // you can pass multiple types (separated by commas) to this macro
+    ($($float_type:ty),*) => {
+        $(
+            impl ArgMinMax for &[$float_type] {
+                type Item = $float_type;
+
+                fn argminmax(&self) -> (usize, usize) {
+                    self.argminmax_with_values().indices()
+                }
+
+                fn argminmax_with_values(&self) -> MinMaxResult<$float_type> {
+                    dispatch_float_ignore_nan!(self, argminmax_with_values, $float_type).into()
+                }
+
+                fn argmin(&self) -> usize {
+                    dispatch_float_ignore_nan!(self, argmin, $float_type)
+                }
+
+                fn argmax(&self) -> usize {
+                    dispatch_float_ignore_nan!(self, argmax, $float_type)
+                }
+
+                impl_with_tie!(
+                    dispatch_float_ignore_nan!($float_type),
+                    [argminmax_with_tie, argmin_with_tie, argmax_with_tie],
+                    [argminmax, argmin, argmax]
+                );
+
+                fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
+                    }
+                    let (min_index, max_index) = self.argminmax();
+                    if self[min_index].is_nan() || self[max_index].is_nan() {
+                        // Only NaNs and / or infinities -> the SIMD result is not reliable
+                        if self.iter().all(|v| v.is_nan()) {
+                            return Err(ArgMinMaxError::AllNaN);
                         }
+                        return Ok(SCALAR::<FloatIgnoreNaN>::argminmax(self));
+                    }
+                    Ok((min_index, max_index))
+                }
+
+                fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
                     }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatReturnNaN>::argmin(self) }
+                    let min_index = self.argmin();
+                    if self[min_index].is_nan() {
+                        // Only NaNs and / or infinities -> the SIMD result is not reliable
+                        if self.iter().all(|v| v.is_nan()) {
+                            return Err(ArgMinMaxError::AllNaN);
                         }
+                        return Ok(SCALAR::<FloatIgnoreNaN>::argmin(self));
                     }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatReturnNaN>::argmin(self) }
+                    Ok(min_index)
+                }
+
+                fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
+                    }
+                    let max_index = self.argmax();
+                    if self[max_index].is_nan() {
+                        // Only NaNs and / or infinities -> the SIMD result is not reliable
+                        if self.iter().all(|v| v.is_nan()) {
+                            return Err(ArgMinMaxError::AllNaN);
                         }
+                        return Ok(SCALAR::<FloatIgnoreNaN>::argmax(self));
                     }
-                    SCALAR::<FloatReturnNaN>::argmin(self)
+                    Ok(max_index)
+                }
+
+                fn argmin_k(&self, k: usize) -> Vec<usize> {
+                    argmin_k_generic(self, k, |block| block.argmin(), true)
+                }
+
+                fn argmax_k(&self, k: usize) -> Vec<usize> {
+                    argmax_k_generic(self, k, |block| block.argmax(), true)
+                }
+            }
+
+            impl NaNArgMinMax for &[$float_type] {
+                type Item = $float_type;
+
+                fn nanargminmax(&self) -> (usize, usize) {
+                    self.nanargminmax_with_values().indices()
+                }
+
+                fn nanargminmax_with_values(&self) -> MinMaxResult<$float_type> {
+                    dispatch_float_return_nan!(self, argminmax_with_values, $float_type).into()
+                }
+
+                fn nanargmin(&self) -> usize {
+                    dispatch_float_return_nan!(self, argmin, $float_type)
                 }
 
                 fn nanargmax(&self) -> usize {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    {
-                        #[cfg(feature = "nightly_simd")]
-                        {
-                            if is_x86_feature_detected!("avx512bw") & (<$float_type>::NB_BITS == 16) {
-                                // BW (ByteWord) instructions are needed for 16-bit avx512
-                                return unsafe { AVX512::<FloatReturnNaN>::argmax(self) }
-                            } else if is_x86_feature_detected!("avx512f") {
-                                return unsafe { AVX512::<FloatReturnNaN>::argmax(self) }
-                            }
-                        }
-                        if is_x86_feature_detected!("avx2") {
-                            return unsafe { AVX2::<FloatReturnNaN>::argmax(self) }
-                        // SKIP SSE4.2 bc scalar is faster or equivalent for 64 bit numbers
-                        } else if is_x86_feature_detected!("sse4.1") & (<$float_type>::NB_BITS < 64) {
-                            // Scalar is faster for 64-bit numbers
-                            // TODO: double check this (observed different things for new float implementation)
-                            return unsafe { SSE::<FloatReturnNaN>::argmax(self) }
-                        }
+                    dispatch_float_return_nan!(self, argmax, $float_type)
+                }
+                impl_with_tie!(
+                    dispatch_float_return_nan!($float_type),
+                    [nanargminmax_with_tie, nanargmin_with_tie, nanargmax_with_tie],
+                    [nanargminmax, nanargmin, nanargmax]
+                );
+
+                fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
                     }
-                    #[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_aarch64_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatReturnNaN>::argmax(self) }
-                        }
+                    Ok(self.nanargminmax())
+                }
+
+                fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
                     }
-                    #[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
-                    {
-                        if std::arch::is_arm_feature_detected!("neon") & (<$float_type>::NB_BITS < 64) {
-                            // We miss some NEON instructions for 64-bit numbers
-                            return unsafe { NEON::<FloatReturnNaN>::argmax(self) }
-                        }
+                    Ok(self.nanargmin())
+                }
+
+                fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError> {
+                    if self.is_empty() {
+                        return Err(ArgMinMaxError::EmptyInput);
                     }
-                    SCALAR::<FloatReturnNaN>::argmax(self)
+                    Ok(self.nanargmax())
+                }
+
+                fn nanargmin_k(&self, k: usize) -> Vec<usize> {
+                    argmin_k_generic(self, k, |block| block.nanargmin(), false)
+                }
+
+                fn nanargmax_k(&self, k: usize) -> Vec<usize> {
+                    argmax_k_generic(self, k, |block| block.nanargmax(), false)
                 }
             }
         )*
@@ -644,14 +1298,72 @@ macro_rules! impl_argminmax_float {
 // from the compiler..
 #[cfg(all(feature = "float", target_arch = "aarch64"))]
 impl ArgMinMax for &[f64] {
+    type Item = f64;
+
     fn argminmax(&self) -> (usize, usize) {
-        unsafe { NEON::<FloatIgnoreNaN>::argminmax(self) }
+        self.argminmax_with_values().indices()
     }
     fn argmin(&self) -> usize {
-        unsafe { NEON::<FloatIgnoreNaN>::argmin(self) }
+        dispatch_neon_f64_ignore_nan!(self, argmin)
     }
     fn argmax(&self) -> usize {
-        unsafe { NEON::<FloatIgnoreNaN>::argmax(self) }
+        dispatch_neon_f64_ignore_nan!(self, argmax)
+    }
+    impl_with_tie!(
+        dispatch_neon_f64_ignore_nan!(),
+        [argminmax_with_tie, argmin_with_tie, argmax_with_tie],
+        [argminmax, argmin, argmax]
+    );
+    fn argminmax_with_values(&self) -> MinMaxResult<f64> {
+        dispatch_neon_f64_ignore_nan!(self, argminmax_with_values).into()
+    }
+    fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+        if self.is_empty() {
+            return Err(ArgMinMaxError::EmptyInput);
+        }
+        let (min_index, max_index) = self.argminmax();
+        if self[min_index].is_nan() || self[max_index].is_nan() {
+            // Only NaNs and / or infinities -> the SIMD result is not reliable
+            if self.iter().all(|v| v.is_nan()) {
+                return Err(ArgMinMaxError::AllNaN);
+            }
+            return Ok(SCALAR::<FloatIgnoreNaN>::argminmax(self));
+        }
+        Ok((min_index, max_index))
+    }
+    fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+        if self.is_empty() {
+            return Err(ArgMinMaxError::EmptyInput);
+        }
+        let min_index = self.argmin();
+        if self[min_index].is_nan() {
+            // Only NaNs and / or infinities -> the SIMD result is not reliable
+            if self.iter().all(|v| v.is_nan()) {
+                return Err(ArgMinMaxError::AllNaN);
+            }
+            return Ok(SCALAR::<FloatIgnoreNaN>::argmin(self));
+        }
+        Ok(min_index)
+    }
+    fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+        if self.is_empty() {
+            return Err(ArgMinMaxError::EmptyInput);
+        }
+        let max_index = self.argmax();
+        if self[max_index].is_nan() {
+            // Only NaNs and / or infinities -> the SIMD result is not reliable
+            if self.iter().all(|v| v.is_nan()) {
+                return Err(ArgMinMaxError::AllNaN);
+            }
+            return Ok(SCALAR::<FloatIgnoreNaN>::argmax(self));
+        }
+        Ok(max_index)
+    }
+    fn argmin_k(&self, k: usize) -> Vec<usize> {
+        argmin_k_generic(self, k, |block| block.argmin(), true)
+    }
+    fn argmax_k(&self, k: usize) -> Vec<usize> {
+        argmax_k_generic(self, k, |block| block.argmax(), true)
     }
 }
 
@@ -662,37 +1374,67 @@ impl ArgMinMax for &[f64] {
 // from the compiler..
 #[cfg(all(feature = "float", target_arch = "aarch64"))]
 impl NaNArgMinMax for &[f64] {
+    type Item = f64;
+
     fn nanargminmax(&self) -> (usize, usize) {
-        #[cfg(feature = "nightly_simd")]
-        {
-            if std::arch::is_aarch64_feature_detected!("neon") {
-                return unsafe { NEON::<FloatReturnNaN>::argminmax(self) };
-            }
-        }
-        SCALAR::<FloatReturnNaN>::argminmax(self)
+        self.nanargminmax_with_values().indices()
     }
     fn nanargmin(&self) -> usize {
-        #[cfg(feature = "nightly_simd")]
-        {
-            if std::arch::is_aarch64_feature_detected!("neon") {
-                return unsafe { NEON::<FloatReturnNaN>::argmin(self) };
-            }
-        }
-        SCALAR::<FloatReturnNaN>::argmin(self)
+        dispatch_neon_f64_return_nan!(self, argmin)
     }
     fn nanargmax(&self) -> usize {
-        #[cfg(feature = "nightly_simd")]
-        {
-            if std::arch::is_aarch64_feature_detected!("neon") {
-                return unsafe { NEON::<FloatReturnNaN>::argmax(self) };
-            }
+        dispatch_neon_f64_return_nan!(self, argmax)
+    }
+    impl_with_tie!(
+        dispatch_neon_f64_return_nan!(),
+        [
+            nanargminmax_with_tie,
+            nanargmin_with_tie,
+            nanargmax_with_tie
+        ],
+        [nanargminmax, nanargmin, nanargmax]
+    );
+    fn nanargminmax_with_values(&self) -> MinMaxResult<f64> {
+        dispatch_neon_f64_return_nan!(self, argminmax_with_values).into()
+    }
+    fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+        if self.is_empty() {
+            return Err(ArgMinMaxError::EmptyInput);
+        }
+        Ok(self.nanargminmax())
+    }
+    fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError> {
+        if self.is_empty() {
+            return Err(ArgMinMaxError::EmptyInput);
+        }
+        Ok(self.nanargmin())
+    }
+    fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError> {
+        if self.is_empty() {
+            return Err(ArgMinMaxError::EmptyInput);
         }
-        SCALAR::<FloatReturnNaN>::argmax(self)
+        Ok(self.nanargmax())
+    }
+    fn nanargmin_k(&self, k: usize) -> Vec<usize> {
+        argmin_k_generic(self, k, |block| block.nanargmin(), false)
+    }
+    fn nanargmax_k(&self, k: usize) -> Vec<usize> {
+        argmax_k_generic(self, k, |block| block.nanargmax(), false)
     }
 }
 
 // Implement ArgMinMax for (non-optional) integer rust primitive types
 impl_argminmax_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_argminmax_int128!(i128, u128);
+// Implement ArgMinMax for the pointer-sized integer types
+#[cfg(target_pointer_width = "64")]
+impl_argminmax_ptr_sized!(usize, u64, _usize_to_u64_slice);
+#[cfg(target_pointer_width = "64")]
+impl_argminmax_ptr_sized!(isize, i64, _isize_to_i64_slice);
+#[cfg(target_pointer_width = "32")]
+impl_argminmax_ptr_sized!(usize, u32, _usize_to_u32_slice);
+#[cfg(target_pointer_width = "32")]
+impl_argminmax_ptr_sized!(isize, i32, _isize_to_i32_slice);
 // Implement for (optional) float rust primitive types
 #[cfg(all(feature = "float", not(target_arch = "aarch64")))]
 impl_argminmax_float!(f32, f64);
@@ -702,118 +1444,2090 @@ impl_argminmax_float!(f32);
 
 // Implement ArgMinMax for other data types
 #[cfg(feature = "half")]
-impl_argminmax_float!(f16);
-
-// ------------------------------ [T] ------------------------------
+impl_argminmax_float!(f16, bf16);
+#[cfg(feature = "float8")]
+impl_argminmax_float!(F8E4M3, F8E5M2);
 
-// impl<T> ArgMinMax for [T]
-// where
-//     for<'a> &'a [T]: ArgMinMax,
-// {
-//     fn argminmax(&self) -> (usize, usize) {
-//         // TODO: use the slice implementation without having stack-overflow
-//     }
-// }
+// --------------------------- Segmented ---------------------------
 
-// ------------------------------ Vec ------------------------------
+/// Macro for implementing SegmentedArgMinMax - the SIMD kernel is selected once (by
+/// `$dispatch`) for all the segments, the short segments are processed by the scalar
+/// implementation of the given DTypeStrategy (e.g. Int)
+macro_rules! impl_segmented_argminmax {
+    ($dtype_strategy:ty, $dispatch:ident, $($data_type:ty),*) => {
+        $(
+            impl SegmentedArgMinMax for &[$data_type] {
+                fn segmented_argminmax<O: AsPrimitive<usize>>(
+                    &self,
+                    offsets: &[O],
+                ) -> Vec<Option<(usize, usize)>> {
+                    let kernel: unsafe fn(&[$data_type]) -> (usize, usize) =
+                        $dispatch!(@kernel argminmax, $data_type);
+                    segmented_argminmax_generic(
+                        self,
+                        offsets,
+                        // Safe: the kernel is supported by the running CPU (see $dispatch)
+                        |s| unsafe { kernel(s) },
+                        SCALAR::<$dtype_strategy>::argminmax,
+                    )
+                }
+            }
+        )*
+    };
+}
 
-impl<T> ArgMinMax for Vec<T>
-where
-    for<'a> &'a [T]: ArgMinMax,
+/// Macro for implementing NaNSegmentedArgMinMax for floats - the SIMD kernel is selected
+/// once (by `$dispatch`) for all the segments, the short segments are processed by the
+/// scalar implementation that returns NaNs
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+macro_rules! impl_nan_segmented_argminmax {
+    ($dispatch:ident, $($float_type:ty),*) => {
+        $(
+            impl NaNSegmentedArgMinMax for &[$float_type] {
+                fn segmented_nanargminmax<O: AsPrimitive<usize>>(
+                    &self,
+                    offsets: &[O],
+                ) -> Vec<Option<(usize, usize)>> {
+                    let kernel: unsafe fn(&[$float_type]) -> (usize, usize) =
+                        $dispatch!(@kernel argminmax, $float_type);
+                    segmented_argminmax_generic(
+                        self,
+                        offsets,
+                        // Safe: the kernel is supported by the running CPU (see $dispatch)
+                        |s| unsafe { kernel(s) },
+                        SCALAR::<FloatReturnNaN>::argminmax,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_segmented_argminmax!(Int, dispatch_int, i8, i16, i32, i64, u8, u16, u32, u64);
+impl_segmented_argminmax!(Int, dispatch_int128, i128, u128);
+#[cfg(all(feature = "float", not(target_arch = "aarch64")))]
+impl_segmented_argminmax!(FloatIgnoreNaN, dispatch_float_ignore_nan, f32, f64);
+#[cfg(all(feature = "float", not(target_arch = "aarch64")))]
+impl_nan_segmented_argminmax!(dispatch_float_return_nan, f32, f64);
+#[cfg(all(feature = "float", target_arch = "aarch64"))]
+impl_segmented_argminmax!(FloatIgnoreNaN, dispatch_float_ignore_nan, f32);
+#[cfg(all(feature = "float", target_arch = "aarch64"))]
+impl_nan_segmented_argminmax!(dispatch_float_return_nan, f32);
+#[cfg(all(feature = "float", target_arch = "aarch64"))]
+impl_segmented_argminmax!(FloatIgnoreNaN, dispatch_neon_f64_ignore_nan, f64);
+#[cfg(all(feature = "float", target_arch = "aarch64"))]
+impl_nan_segmented_argminmax!(dispatch_neon_f64_return_nan, f64);
+#[cfg(feature = "half")]
+impl_segmented_argminmax!(FloatIgnoreNaN, dispatch_float_ignore_nan, f16, bf16);
+#[cfg(feature = "half")]
+impl_nan_segmented_argminmax!(dispatch_float_return_nan, f16, bf16);
+#[cfg(feature = "float8")]
+impl_segmented_argminmax!(FloatIgnoreNaN, dispatch_float_ignore_nan, F8E4M3, F8E5M2);
+#[cfg(feature = "float8")]
+impl_nan_segmented_argminmax!(dispatch_float_return_nan, F8E4M3, F8E5M2);
+
+// ------------------------------ [T] ------------------------------
+
+// impl<T> ArgMinMax for [T]
+// where
+//     for<'a> &'a [T]: ArgMinMax,
+// {
+//     fn argminmax(&self) -> (usize, usize) {
+//         // TODO: use the slice implementation without having stack-overflow
+//     }
+// }
+
+// ------------------------------ Vec ------------------------------
+
+impl<T: Copy> ArgMinMax for Vec<T>
+where
+    for<'a> &'a [T]: ArgMinMax<Item = T>,
 {
+    type Item = T;
+
     fn argminmax(&self) -> (usize, usize) {
         self.as_slice().argminmax()
     }
 
-    fn argmin(&self) -> usize {
-        self.as_slice().argmin()
-    }
+    fn argmin(&self) -> usize {
+        self.as_slice().argmin()
+    }
+
+    fn argmax(&self) -> usize {
+        self.as_slice().argmax()
+    }
+
+    fn argminmax_with_values(&self) -> MinMaxResult<T> {
+        self.as_slice().argminmax_with_values()
+    }
+
+    fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+        self.as_slice().try_argminmax()
+    }
+
+    fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+        self.as_slice().try_argmin()
+    }
+
+    fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+        self.as_slice().try_argmax()
+    }
+
+    fn argmin_k(&self, k: usize) -> Vec<usize> {
+        self.as_slice().argmin_k(k)
+    }
+
+    fn argmax_k(&self, k: usize) -> Vec<usize> {
+        self.as_slice().argmax_k(k)
+    }
+
+    fn argminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+        self.as_slice().argminmax_with_tie(tie)
+    }
+
+    fn argmin_with_tie(&self, tie: TiePolicy) -> usize {
+        self.as_slice().argmin_with_tie(tie)
+    }
+
+    fn argmax_with_tie(&self, tie: TiePolicy) -> usize {
+        self.as_slice().argmax_with_tie(tie)
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T: Copy> NaNArgMinMax for Vec<T>
+where
+    for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+{
+    type Item = T;
+
+    fn nanargminmax(&self) -> (usize, usize) {
+        self.as_slice().nanargminmax()
+    }
+
+    fn nanargmin(&self) -> usize {
+        self.as_slice().nanargmin()
+    }
+
+    fn nanargmax(&self) -> usize {
+        self.as_slice().nanargmax()
+    }
+
+    fn nanargminmax_with_values(&self) -> MinMaxResult<T> {
+        self.as_slice().nanargminmax_with_values()
+    }
+
+    fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+        self.as_slice().try_nanargminmax()
+    }
+
+    fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError> {
+        self.as_slice().try_nanargmin()
+    }
+
+    fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError> {
+        self.as_slice().try_nanargmax()
+    }
+
+    fn nanargmin_k(&self, k: usize) -> Vec<usize> {
+        self.as_slice().nanargmin_k(k)
+    }
+
+    fn nanargmax_k(&self, k: usize) -> Vec<usize> {
+        self.as_slice().nanargmax_k(k)
+    }
+
+    fn nanargminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+        self.as_slice().nanargminmax_with_tie(tie)
+    }
+
+    fn nanargmin_with_tie(&self, tie: TiePolicy) -> usize {
+        self.as_slice().nanargmin_with_tie(tie)
+    }
+
+    fn nanargmax_with_tie(&self, tie: TiePolicy) -> usize {
+        self.as_slice().nanargmax_with_tie(tie)
+    }
+}
+
+// ---------------------------- VecDeque ----------------------------
+
+/// Get the MinMaxResult of a VecDeque, without making it contiguous.
+///
+/// The values of a VecDeque are stored as two slices (see `VecDeque::as_slices`),
+/// `slice_fn` computes the MinMaxResult of a slice. The results of both slices are
+/// merged (using `slice_fn` on pairs of values), so the returned indices are the
+/// logical positions in the deque.
+/// A slice that returns `ArgMinMaxError::AllNaN` is skipped; when both do, this error
+/// is returned.
+///
+fn _argminmax_vecdeque<T: Copy>(
+    data: &VecDeque<T>,
+    slice_fn: impl Fn(&[T]) -> Result<MinMaxResult<T>, ArgMinMaxError>,
+) -> Result<MinMaxResult<T>, ArgMinMaxError> {
+    let (front, back) = data.as_slices();
+    if back.is_empty() {
+        return slice_fn(front);
+    }
+    if front.is_empty() {
+        return slice_fn(back);
+    }
+    let (front_result, back_result) = (
+        slice_fn(front),
+        slice_fn(back).map(|result| result.with_offset(front.len())),
+    );
+    match (front_result, back_result) {
+        // When both values of the pair are NaN, the earlier one is kept
+        (Ok(front_result), Ok(back_result)) => Ok(front_result.merge(back_result, |pair| {
+            slice_fn(pair).map_or((0, 0), |result| result.indices())
+        })),
+        (Ok(result), Err(ArgMinMaxError::AllNaN)) | (Err(ArgMinMaxError::AllNaN), Ok(result)) => {
+            Ok(result)
+        }
+        (Err(err), _) | (_, Err(err)) => Err(err),
+    }
+}
+
+/// Get the (min_index, max_index) of a VecDeque, without making it contiguous.
+///
+/// Same as `_argminmax_vecdeque`, but `slice_fn` only computes the
+/// (min_index, max_index) of a slice.
+///
+fn _argminmax_indices_vecdeque<T: Copy>(
+    data: &VecDeque<T>,
+    slice_fn: impl Fn(&[T]) -> Result<(usize, usize), ArgMinMaxError>,
+) -> Result<(usize, usize), ArgMinMaxError> {
+    _argminmax_vecdeque(data, |s| {
+        slice_fn(s).map(|(min_index, max_index)| MinMaxResult {
+            min_index,
+            min_value: s[min_index],
+            max_index,
+            max_value: s[max_index],
+        })
+    })
+    .map(|result| result.indices())
+}
+
+/// Get the indices of the top-k values of a VecDeque, without making it contiguous.
+///
+/// `slice_fn` returns the top-k indices of a slice (sorted from best to worst) and
+/// `pair_fn` returns the index of the best value of a pair of values. The top-k of both
+/// slices of the deque are merged - on ties the value of the front slice (i.e., the
+/// lowest index) ranks first.
+///
+fn _topk_vecdeque<T: Copy>(
+    data: &VecDeque<T>,
+    k: usize,
+    slice_fn: impl Fn(&[T], usize) -> Vec<usize>,
+    pair_fn: impl Fn(&[T]) -> usize,
+) -> Vec<usize> {
+    let (front, back) = data.as_slices();
+    let (front_topk, back_topk) = (slice_fn(front, k), slice_fn(back, k));
+    let k = std::cmp::min(k, front_topk.len() + back_topk.len());
+    let mut result = Vec::with_capacity(k);
+    let (mut front_iter, mut back_iter) = (front_topk.iter().peekable(), back_topk.iter());
+    let mut back_next = back_iter.next();
+    while result.len() < k {
+        match (front_iter.peek(), back_next) {
+            (Some(&&i), Some(&j)) if pair_fn(&[front[i], back[j]]) != 0 => {
+                result.push(front.len() + j);
+                back_next = back_iter.next();
+            }
+            (Some(&&i), _) => {
+                result.push(i);
+                front_iter.next();
+            }
+            (None, Some(&j)) => {
+                result.push(front.len() + j);
+                back_next = back_iter.next();
+            }
+            (None, None) => break,
+        }
+    }
+    result
+}
+
+impl<T: Copy> ArgMinMax for VecDeque<T>
+where
+    for<'a> &'a [T]: ArgMinMax<Item = T>,
+{
+    type Item = T;
+
+    fn argminmax(&self) -> (usize, usize) {
+        self.argminmax_with_values().indices()
+    }
+
+    fn argmin(&self) -> usize {
+        _argminmax_indices_vecdeque(self, |s| {
+            let index = s.argmin();
+            Ok((index, index))
+        })
+        .unwrap()
+        .0
+    }
+
+    fn argmax(&self) -> usize {
+        _argminmax_indices_vecdeque(self, |s| {
+            let index = s.argmax();
+            Ok((index, index))
+        })
+        .unwrap()
+        .1
+    }
+
+    fn argminmax_with_values(&self) -> MinMaxResult<T> {
+        _argminmax_vecdeque(self, |s| Ok(s.argminmax_with_values())).unwrap()
+    }
+
+    fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+        _argminmax_indices_vecdeque(self, |s| s.try_argminmax())
+    }
+
+    fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+        _argminmax_indices_vecdeque(self, |s| s.try_argmin().map(|index| (index, index)))
+            .map(|(min_index, _)| min_index)
+    }
+
+    fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+        _argminmax_indices_vecdeque(self, |s| s.try_argmax().map(|index| (index, index)))
+            .map(|(_, max_index)| max_index)
+    }
+
+    fn argmin_k(&self, k: usize) -> Vec<usize> {
+        _topk_vecdeque(self, k, |s, k| s.argmin_k(k), |pair| pair.argmin())
+    }
+
+    fn argmax_k(&self, k: usize) -> Vec<usize> {
+        _topk_vecdeque(self, k, |s, k| s.argmax_k(k), |pair| pair.argmax())
+    }
+
+    fn argminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+        _argminmax_indices_vecdeque(self, |s| Ok(s.argminmax_with_tie(tie))).unwrap()
+    }
+
+    fn argmin_with_tie(&self, tie: TiePolicy) -> usize {
+        _argminmax_indices_vecdeque(self, |s| {
+            let index = s.argmin_with_tie(tie);
+            Ok((index, index))
+        })
+        .unwrap()
+        .0
+    }
+
+    fn argmax_with_tie(&self, tie: TiePolicy) -> usize {
+        _argminmax_indices_vecdeque(self, |s| {
+            let index = s.argmax_with_tie(tie);
+            Ok((index, index))
+        })
+        .unwrap()
+        .1
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T: Copy> NaNArgMinMax for VecDeque<T>
+where
+    for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+{
+    type Item = T;
+
+    fn nanargminmax(&self) -> (usize, usize) {
+        self.nanargminmax_with_values().indices()
+    }
+
+    fn nanargmin(&self) -> usize {
+        _argminmax_indices_vecdeque(self, |s| {
+            let index = s.nanargmin();
+            Ok((index, index))
+        })
+        .unwrap()
+        .0
+    }
+
+    fn nanargmax(&self) -> usize {
+        _argminmax_indices_vecdeque(self, |s| {
+            let index = s.nanargmax();
+            Ok((index, index))
+        })
+        .unwrap()
+        .1
+    }
+
+    fn nanargminmax_with_values(&self) -> MinMaxResult<T> {
+        _argminmax_vecdeque(self, |s| Ok(s.nanargminmax_with_values())).unwrap()
+    }
+
+    fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+        _argminmax_indices_vecdeque(self, |s| s.try_nanargminmax())
+    }
+
+    fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError> {
+        _argminmax_indices_vecdeque(self, |s| s.try_nanargmin().map(|index| (index, index)))
+            .map(|(min_index, _)| min_index)
+    }
+
+    fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError> {
+        _argminmax_indices_vecdeque(self, |s| s.try_nanargmax().map(|index| (index, index)))
+            .map(|(_, max_index)| max_index)
+    }
+
+    fn nanargmin_k(&self, k: usize) -> Vec<usize> {
+        _topk_vecdeque(self, k, |s, k| s.nanargmin_k(k), |pair| pair.nanargmin())
+    }
+
+    fn nanargmax_k(&self, k: usize) -> Vec<usize> {
+        _topk_vecdeque(self, k, |s, k| s.nanargmax_k(k), |pair| pair.nanargmax())
+    }
+
+    fn nanargminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+        _argminmax_indices_vecdeque(self, |s| Ok(s.nanargminmax_with_tie(tie))).unwrap()
+    }
+
+    fn nanargmin_with_tie(&self, tie: TiePolicy) -> usize {
+        _argminmax_indices_vecdeque(self, |s| {
+            let index = s.nanargmin_with_tie(tie);
+            Ok((index, index))
+        })
+        .unwrap()
+        .0
+    }
+
+    fn nanargmax_with_tie(&self, tie: TiePolicy) -> usize {
+        _argminmax_indices_vecdeque(self, |s| {
+            let index = s.nanargmax_with_tie(tie);
+            Ok((index, index))
+        })
+        .unwrap()
+        .1
+    }
+}
+
+// ---------------------------- Rolling ----------------------------
+
+impl<T: Copy + PartialOrd> RollingArgMinMax for &[T]
+where
+    for<'a> &'a [T]: ArgMinMax,
+{
+    fn rolling_argminmax(&self, window: usize, step: usize) -> (Vec<usize>, Vec<usize>) {
+        (
+            self.rolling_argmin(window, step),
+            self.rolling_argmax(window, step),
+        )
+    }
+
+    fn rolling_argmin(&self, window: usize, step: usize) -> Vec<usize> {
+        rolling_arg_best_generic::<_, false>(self, window, step, |s| s.argmin(), PartialOrd::lt)
+    }
+
+    fn rolling_argmax(&self, window: usize, step: usize) -> Vec<usize> {
+        rolling_arg_best_generic::<_, false>(self, window, step, |s| s.argmax(), PartialOrd::gt)
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T: Copy + PartialOrd> NaNRollingArgMinMax for &[T]
+where
+    for<'a> &'a [T]: NaNArgMinMax,
+{
+    fn rolling_nanargminmax(&self, window: usize, step: usize) -> (Vec<usize>, Vec<usize>) {
+        (
+            self.rolling_nanargmin(window, step),
+            self.rolling_nanargmax(window, step),
+        )
+    }
+
+    fn rolling_nanargmin(&self, window: usize, step: usize) -> Vec<usize> {
+        rolling_arg_best_generic::<_, true>(self, window, step, |s| s.nanargmin(), PartialOrd::lt)
+    }
+
+    fn rolling_nanargmax(&self, window: usize, step: usize) -> Vec<usize> {
+        rolling_arg_best_generic::<_, true>(self, window, step, |s| s.nanargmax(), PartialOrd::gt)
+    }
+}
+
+impl<T: Copy + PartialOrd> RollingArgMinMax for Vec<T>
+where
+    for<'a> &'a [T]: ArgMinMax,
+{
+    fn rolling_argminmax(&self, window: usize, step: usize) -> (Vec<usize>, Vec<usize>) {
+        self.as_slice().rolling_argminmax(window, step)
+    }
+
+    fn rolling_argmin(&self, window: usize, step: usize) -> Vec<usize> {
+        self.as_slice().rolling_argmin(window, step)
+    }
+
+    fn rolling_argmax(&self, window: usize, step: usize) -> Vec<usize> {
+        self.as_slice().rolling_argmax(window, step)
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T: Copy + PartialOrd> NaNRollingArgMinMax for Vec<T>
+where
+    for<'a> &'a [T]: NaNArgMinMax,
+{
+    fn rolling_nanargminmax(&self, window: usize, step: usize) -> (Vec<usize>, Vec<usize>) {
+        self.as_slice().rolling_nanargminmax(window, step)
+    }
+
+    fn rolling_nanargmin(&self, window: usize, step: usize) -> Vec<usize> {
+        self.as_slice().rolling_nanargmin(window, step)
+    }
+
+    fn rolling_nanargmax(&self, window: usize, step: usize) -> Vec<usize> {
+        self.as_slice().rolling_nanargmax(window, step)
+    }
+}
+
+impl<T: Copy> SegmentedArgMinMax for Vec<T>
+where
+    for<'a> &'a [T]: SegmentedArgMinMax,
+{
+    fn segmented_argminmax<O: AsPrimitive<usize>>(
+        &self,
+        offsets: &[O],
+    ) -> Vec<Option<(usize, usize)>> {
+        self.as_slice().segmented_argminmax(offsets)
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T: Copy> NaNSegmentedArgMinMax for Vec<T>
+where
+    for<'a> &'a [T]: NaNSegmentedArgMinMax,
+{
+    fn segmented_nanargminmax<O: AsPrimitive<usize>>(
+        &self,
+        offsets: &[O],
+    ) -> Vec<Option<(usize, usize)>> {
+        self.as_slice().segmented_nanargminmax(offsets)
+    }
+}
+
+// ----------------------------- Binned -----------------------------
+
+impl<T> BinnedArgMinMax for &[T]
+where
+    T: Copy + Send + Sync,
+    for<'a> &'a [T]: ArgMinMax,
+{
+    fn binned_argminmax(&self, n_bins: usize) -> Vec<usize> {
+        binned_argminmax_generic(self, n_bins, |s| s.argminmax())
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_binned_argminmax(&self, n_bins: usize) -> Vec<usize> {
+        par_binned_argminmax_generic(self, n_bins, |s| s.argminmax())
+    }
+
+    fn binned_argminmax_x<X>(&self, x: &[X], n_bins: usize) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let offsets = x_to_offsets(x, n_bins);
+        // The slice implementation (SIMD dispatch) is used for the short bins as well
+        segmented_argminmax_generic(self, &offsets, |s| s.argminmax(), |s| s.argminmax())
+    }
+
+    fn binned_argminmax_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let offsets = edges_to_offsets(x, edges);
+        segmented_argminmax_generic(self, &offsets, |s| s.argminmax(), |s| s.argminmax())
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T> NaNBinnedArgMinMax for &[T]
+where
+    T: Copy + Send + Sync,
+    for<'a> &'a [T]: NaNArgMinMax,
+{
+    fn binned_nanargminmax(&self, n_bins: usize) -> Vec<usize> {
+        binned_argminmax_generic(self, n_bins, |s| s.nanargminmax())
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_binned_nanargminmax(&self, n_bins: usize) -> Vec<usize> {
+        par_binned_argminmax_generic(self, n_bins, |s| s.nanargminmax())
+    }
+
+    fn binned_nanargminmax_x<X>(&self, x: &[X], n_bins: usize) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let offsets = x_to_offsets(x, n_bins);
+        segmented_argminmax_generic(self, &offsets, |s| s.nanargminmax(), |s| s.nanargminmax())
+    }
+
+    fn binned_nanargminmax_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let offsets = edges_to_offsets(x, edges);
+        segmented_argminmax_generic(self, &offsets, |s| s.nanargminmax(), |s| s.nanargminmax())
+    }
+}
+
+impl<T> BinnedArgMinMax for Vec<T>
+where
+    T: Copy + Send + Sync,
+    for<'a> &'a [T]: ArgMinMax,
+{
+    fn binned_argminmax(&self, n_bins: usize) -> Vec<usize> {
+        self.as_slice().binned_argminmax(n_bins)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_binned_argminmax(&self, n_bins: usize) -> Vec<usize> {
+        self.as_slice().par_binned_argminmax(n_bins)
+    }
+
+    fn binned_argminmax_x<X>(&self, x: &[X], n_bins: usize) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        self.as_slice().binned_argminmax_x(x, n_bins)
+    }
+
+    fn binned_argminmax_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd,
+    {
+        self.as_slice().binned_argminmax_edges(x, edges)
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T> NaNBinnedArgMinMax for Vec<T>
+where
+    T: Copy + Send + Sync,
+    for<'a> &'a [T]: NaNArgMinMax,
+{
+    fn binned_nanargminmax(&self, n_bins: usize) -> Vec<usize> {
+        self.as_slice().binned_nanargminmax(n_bins)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_binned_nanargminmax(&self, n_bins: usize) -> Vec<usize> {
+        self.as_slice().par_binned_nanargminmax(n_bins)
+    }
+
+    fn binned_nanargminmax_x<X>(&self, x: &[X], n_bins: usize) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        self.as_slice().binned_nanargminmax_x(x, n_bins)
+    }
+
+    fn binned_nanargminmax_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<Option<(usize, usize)>>
+    where
+        X: Copy + PartialOrd,
+    {
+        self.as_slice().binned_nanargminmax_edges(x, edges)
+    }
+}
+
+// ------------------------------- M4 -------------------------------
+
+impl<T> M4ArgMinMax for &[T]
+where
+    T: Copy,
+    for<'a> &'a [T]: ArgMinMax,
+{
+    fn m4(&self, n_bins: usize) -> Vec<usize> {
+        let offsets = index_to_offsets(self.len(), n_bins);
+        m4_generic(self, &offsets, |s| s.argminmax())
+    }
+
+    fn m4_x<X>(&self, x: &[X], n_bins: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let offsets = x_to_offsets(x, n_bins);
+        m4_generic(self, &offsets, |s| s.argminmax())
+    }
+
+    fn m4_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<usize>
+    where
+        X: Copy + PartialOrd,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let offsets = edges_to_offsets(x, edges);
+        m4_generic(self, &offsets, |s| s.argminmax())
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T> NaNM4ArgMinMax for &[T]
+where
+    T: Copy,
+    for<'a> &'a [T]: NaNArgMinMax,
+{
+    fn nan_m4(&self, n_bins: usize) -> Vec<usize> {
+        let offsets = index_to_offsets(self.len(), n_bins);
+        m4_generic(self, &offsets, |s| s.nanargminmax())
+    }
+
+    fn nan_m4_x<X>(&self, x: &[X], n_bins: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let offsets = x_to_offsets(x, n_bins);
+        m4_generic(self, &offsets, |s| s.nanargminmax())
+    }
+
+    fn nan_m4_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<usize>
+    where
+        X: Copy + PartialOrd,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let offsets = edges_to_offsets(x, edges);
+        m4_generic(self, &offsets, |s| s.nanargminmax())
+    }
+}
+
+impl<T> M4ArgMinMax for Vec<T>
+where
+    T: Copy,
+    for<'a> &'a [T]: ArgMinMax,
+{
+    fn m4(&self, n_bins: usize) -> Vec<usize> {
+        self.as_slice().m4(n_bins)
+    }
+
+    fn m4_x<X>(&self, x: &[X], n_bins: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        self.as_slice().m4_x(x, n_bins)
+    }
+
+    fn m4_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<usize>
+    where
+        X: Copy + PartialOrd,
+    {
+        self.as_slice().m4_edges(x, edges)
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<T> NaNM4ArgMinMax for Vec<T>
+where
+    T: Copy,
+    for<'a> &'a [T]: NaNArgMinMax,
+{
+    fn nan_m4(&self, n_bins: usize) -> Vec<usize> {
+        self.as_slice().nan_m4(n_bins)
+    }
+
+    fn nan_m4_x<X>(&self, x: &[X], n_bins: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        self.as_slice().nan_m4_x(x, n_bins)
+    }
+
+    fn nan_m4_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<usize>
+    where
+        X: Copy + PartialOrd,
+    {
+        self.as_slice().nan_m4_edges(x, edges)
+    }
+}
+
+// --------------------------- MinMaxLTTB ---------------------------
+
+impl<T> MinMaxLTTB for &[T]
+where
+    T: Copy + AsPrimitive<f64>,
+    for<'a> &'a [T]: ArgMinMax,
+{
+    fn minmaxlttb(&self, n_out: usize, minmax_ratio: usize) -> Vec<usize> {
+        let n = self.len();
+        let bin_offsets = |n_bins| {
+            let offsets = index_to_offsets(n - 2, n_bins);
+            offsets.into_iter().map(|offset| offset + 1).collect()
+        };
+        minmaxlttb_generic(
+            self,
+            |i| i as f64,
+            n_out,
+            minmax_ratio,
+            bin_offsets,
+            |s| s.argminmax(),
+        )
+    }
+
+    fn minmaxlttb_x<X>(&self, x: &[X], n_out: usize, minmax_ratio: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        assert_eq!(
+            x.len(),
+            self.len(),
+            "x and the array have a different length"
+        );
+        let n = self.len();
+        let bin_offsets = |n_bins| {
+            let offsets = x_to_offsets(&x[1..n - 1], n_bins);
+            offsets.into_iter().map(|offset| offset + 1).collect()
+        };
+        minmaxlttb_generic(
+            self,
+            |i| x[i].as_(),
+            n_out,
+            minmax_ratio,
+            bin_offsets,
+            |s| s.argminmax(),
+        )
+    }
+}
+
+impl<T> MinMaxLTTB for Vec<T>
+where
+    T: Copy + AsPrimitive<f64>,
+    for<'a> &'a [T]: ArgMinMax,
+{
+    fn minmaxlttb(&self, n_out: usize, minmax_ratio: usize) -> Vec<usize> {
+        self.as_slice().minmaxlttb(n_out, minmax_ratio)
+    }
+
+    fn minmaxlttb_x<X>(&self, x: &[X], n_out: usize, minmax_ratio: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>,
+    {
+        self.as_slice().minmaxlttb_x(x, n_out, minmax_ratio)
+    }
+}
+
+// -------------------------- Accumulator --------------------------
+
+/// Macro for implementing AccumulatorStrategy for the passed dtype strategy - the
+/// chunks are processed by the passed methods of the slice implementation (SIMD
+/// dispatch)
+macro_rules! impl_accumulator_strategy {
+    ($dtype_strategy:ty, $arg_minmax:ident, $arg_minmax_with_values:ident, $($data_type:ty),*) => {
+        $(
+            impl AccumulatorStrategy<$data_type> for $dtype_strategy {
+                fn argminmax(data: &[$data_type]) -> (usize, usize) {
+                    data.$arg_minmax()
+                }
+
+                fn argminmax_with_values(data: &[$data_type]) -> MinMaxResult<$data_type> {
+                    data.$arg_minmax_with_values()
+                }
+            }
+        )*
+    };
+}
+
+impl_accumulator_strategy!(
+    Int,
+    argminmax,
+    argminmax_with_values,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    isize,
+    usize
+);
+#[cfg(feature = "float")]
+impl_accumulator_strategy!(FloatIgnoreNaN, argminmax, argminmax_with_values, f32, f64);
+#[cfg(feature = "float")]
+impl_accumulator_strategy!(
+    FloatReturnNaN,
+    nanargminmax,
+    nanargminmax_with_values,
+    f32,
+    f64
+);
+#[cfg(feature = "half")]
+impl_accumulator_strategy!(FloatIgnoreNaN, argminmax, argminmax_with_values, f16, bf16);
+#[cfg(feature = "half")]
+impl_accumulator_strategy!(
+    FloatReturnNaN,
+    nanargminmax,
+    nanargminmax_with_values,
+    f16,
+    bf16
+);
+#[cfg(feature = "float8")]
+impl_accumulator_strategy!(
+    FloatIgnoreNaN,
+    argminmax,
+    argminmax_with_values,
+    F8E4M3,
+    F8E5M2
+);
+#[cfg(feature = "float8")]
+impl_accumulator_strategy!(
+    FloatReturnNaN,
+    nanargminmax,
+    nanargminmax_with_values,
+    F8E4M3,
+    F8E5M2
+);
+
+// ---------------------------- Iterator ----------------------------
+
+impl<I: Iterator> ArgMinMaxIterExt for I {
+    fn argminmax_iter(self) -> Option<(usize, usize)>
+    where
+        Self::Item: Copy,
+        for<'a> &'a [Self::Item]: ArgMinMax<Item = Self::Item>,
+    {
+        iter_argminmax_generic(self, |s| s.argminmax_with_values())
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    fn nanargminmax_iter(self) -> Option<(usize, usize)>
+    where
+        Self::Item: Copy,
+        for<'a> &'a [Self::Item]: NaNArgMinMax<Item = Self::Item>,
+    {
+        iter_argminmax_generic(self, |s| s.nanargminmax_with_values())
+    }
+}
+
+// ----------------------------- Reader -----------------------------
+
+/// Macro for implementing FromLeBytes for the passed data types (that have a
+/// `from_le_bytes` method)
+macro_rules! impl_from_le_bytes {
+    ($($data_type:ty),*) => {
+        $(
+            impl FromLeBytes for $data_type {
+                const NB_BYTES: usize = std::mem::size_of::<$data_type>();
+
+                #[inline(always)]
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    <$data_type>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_le_bytes!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, isize, usize);
+#[cfg(feature = "float")]
+impl_from_le_bytes!(f32, f64);
+#[cfg(feature = "half")]
+impl_from_le_bytes!(f16, bf16);
+
+/// Macro for implementing FromLeBytes for the FP8 formats (which are a single byte)
+#[cfg(feature = "float8")]
+macro_rules! impl_from_le_bytes_float8 {
+    ($($float8_type:ty),*) => {
+        $(
+            impl FromLeBytes for $float8_type {
+                const NB_BYTES: usize = 1;
+
+                #[inline(always)]
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    <$float8_type>::from_bits(bytes[0])
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "float8")]
+impl_from_le_bytes_float8!(F8E4M3, F8E5M2);
+
+// ----------------------- (optional) rayon -----------------------
+
+#[cfg(feature = "rayon")]
+mod rayon_impl {
+    use super::*;
+    use crate::parallel::par_argminmax_generic;
+
+    impl<T> ParArgMinMax for &[T]
+    where
+        T: Copy + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        fn par_argminmax(&self) -> (usize, usize) {
+            par_argminmax_generic(self, |s| s.argminmax_with_values())
+        }
+
+        fn par_argmin(&self) -> usize {
+            par_argminmax_generic(self, |s| {
+                let index = s.argmin();
+                MinMaxResult::from_index(s, index)
+            })
+            .0
+        }
+
+        fn par_argmax(&self) -> usize {
+            par_argminmax_generic(self, |s| {
+                let index = s.argmax();
+                MinMaxResult::from_index(s, index)
+            })
+            .1
+        }
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    impl<T> NaNParArgMinMax for &[T]
+    where
+        T: Copy + Send + Sync,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+    {
+        fn par_nanargminmax(&self) -> (usize, usize) {
+            par_argminmax_generic(self, |s| s.nanargminmax_with_values())
+        }
+
+        fn par_nanargmin(&self) -> usize {
+            par_argminmax_generic(self, |s| {
+                let index = s.nanargmin();
+                MinMaxResult::from_index(s, index)
+            })
+            .0
+        }
+
+        fn par_nanargmax(&self) -> usize {
+            par_argminmax_generic(self, |s| {
+                let index = s.nanargmax();
+                MinMaxResult::from_index(s, index)
+            })
+            .1
+        }
+    }
+
+    impl<T> ParArgMinMax for Vec<T>
+    where
+        T: Copy + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        fn par_argminmax(&self) -> (usize, usize) {
+            self.as_slice().par_argminmax()
+        }
+
+        fn par_argmin(&self) -> usize {
+            self.as_slice().par_argmin()
+        }
+
+        fn par_argmax(&self) -> usize {
+            self.as_slice().par_argmax()
+        }
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    impl<T> NaNParArgMinMax for Vec<T>
+    where
+        T: Copy + Send + Sync,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+    {
+        fn par_nanargminmax(&self) -> (usize, usize) {
+            self.as_slice().par_nanargminmax()
+        }
+
+        fn par_nanargmin(&self) -> usize {
+            self.as_slice().par_nanargmin()
+        }
+
+        fn par_nanargmax(&self) -> usize {
+            self.as_slice().par_nanargmax()
+        }
+    }
+}
+
+// ---------------------- (optional) futures ----------------------
+
+#[cfg(feature = "futures")]
+impl<S: futures_core::Stream> ArgMinMaxStreamExt for S {
+    fn argminmax_stream<T, Strategy>(self) -> ArgMinMaxStream<Self, T, Strategy>
+    where
+        Self: Unpin,
+        Self::Item: AsRef<[T]>,
+        T: Copy,
+        Strategy: AccumulatorStrategy<T>,
+    {
+        ArgMinMaxStream::new(self)
+    }
+
+    fn argminmax_bytes_stream<T, Strategy>(self) -> ArgMinMaxBytesStream<Self, T, Strategy>
+    where
+        Self: Unpin,
+        Self::Item: AsRef<[u8]>,
+        T: Copy + FromLeBytes,
+        Strategy: AccumulatorStrategy<T>,
+    {
+        ArgMinMaxBytesStream::new(self)
+    }
+}
+
+// ----------------------- (optional) ndarray ----------------------
+
+#[cfg(feature = "ndarray")]
+mod ndarray_impl {
+    use super::*;
+    use ndarray::{ArrayBase, Axis, Data, Ix1};
+
+    /// The number of elements that are gathered in a buffer when the array is not
+    /// contiguous in memory.
+    const GATHER_CHUNK_SIZE: usize = 1024;
+
+    /// Get the (min_index, max_index) of a 1D array with any layout (stride).
+    ///
+    /// The `slice_fn` computes the (min_index, max_index) of a slice with the given tie
+    /// policy. The returned indices are always in the logical order of the array:
+    /// - contiguous layout -> `slice_fn` is called on the slice
+    /// - reversed contiguous layout (negative stride) -> `slice_fn` is called on the
+    ///   memory slice (with the reversed tie policy) and the indices are mapped back
+    /// - any other layout -> the array is gathered in chunks (see `_argminmax_gather`)
+    ///
+    /// When `ignore_nan` is false, `slice_fn` should return both the min and max index
+    /// (to detect a returned NaN on a reversed array).
+    /// Chunks that return `ArgMinMaxError::AllNaN` are skipped; when all chunks do, this
+    /// error is returned.
+    ///
+    fn _argminmax_any_layout<S>(
+        arr: &ArrayBase<S, Ix1>,
+        tie: TiePolicy,
+        ignore_nan: bool,
+        slice_fn: impl Fn(&[S::Elem], TiePolicy) -> Result<(usize, usize), ArgMinMaxError>,
+    ) -> Result<(usize, usize), ArgMinMaxError>
+    where
+        S: Data,
+        S::Elem: Copy,
+    {
+        if let Some(slice) = arr.as_slice() {
+            return slice_fn(slice, tie);
+        }
+        if let Some(slice) = arr.as_slice_memory_order() {
+            // Negative stride -> the memory order is the reverse of the logical order
+            let (min_index, max_index) = slice_fn(slice, tie.reverse())?;
+            // When NaNs are returned and min_index == max_index, the value can be a NaN,
+            // in which case this is the last NaN (in logical order) instead of the first
+            // -> fall through to the gather implementation
+            if ignore_nan || min_index != max_index {
+                let last = arr.len() - 1;
+                return Ok((last - min_index, last - max_index));
+            }
+        }
+
+        _argminmax_gather(arr, |s| {
+            slice_fn(s, tie).map(|(min_index, max_index)| MinMaxResult {
+                min_index,
+                min_value: s[min_index],
+                max_index,
+                max_value: s[max_index],
+            })
+        })
+        .map(|result| result.indices())
+    }
+
+    /// Get the MinMaxResult of a 1D array by gathering it in chunks of
+    /// `GATHER_CHUNK_SIZE` elements (in a reused buffer).
+    ///
+    /// The `slice_fn` computes the MinMaxResult of a slice; the results of the chunks are
+    /// merged (using `slice_fn` on pairs of values).
+    /// Chunks that return `ArgMinMaxError::AllNaN` are skipped; when all chunks do, this
+    /// error is returned.
+    ///
+    fn _argminmax_gather<S>(
+        arr: &ArrayBase<S, Ix1>,
+        slice_fn: impl Fn(&[S::Elem]) -> Result<MinMaxResult<S::Elem>, ArgMinMaxError>,
+    ) -> Result<MinMaxResult<S::Elem>, ArgMinMaxError>
+    where
+        S: Data,
+        S::Elem: Copy,
+    {
+        let mut buffer = Vec::with_capacity(std::cmp::min(GATHER_CHUNK_SIZE, arr.len()));
+        let mut result: Option<MinMaxResult<S::Elem>> = None;
+        let mut start = 0;
+        for chunk in arr.axis_chunks_iter(Axis(0), GATHER_CHUNK_SIZE) {
+            buffer.clear();
+            buffer.extend(chunk.iter().copied());
+            match slice_fn(&buffer) {
+                Ok(chunk_result) => {
+                    let chunk_result = chunk_result.with_offset(start);
+                    result = Some(match result {
+                        // When both values of the pair are NaN, the earlier one is kept
+                        Some(result) => result.merge(chunk_result, |pair| {
+                            slice_fn(pair).map_or((0, 0), |r| r.indices())
+                        }),
+                        None => chunk_result,
+                    });
+                }
+                Err(ArgMinMaxError::AllNaN) => {}
+                Err(err) => return Err(err),
+            }
+            start += chunk.len();
+        }
+        result.ok_or(ArgMinMaxError::AllNaN)
+    }
+
+    /// Get the indices of the top-k values of a 1D array with any layout (stride).
+    ///
+    /// Non-contiguous arrays are gathered in chunks of `GATHER_CHUNK_SIZE` elements (in a
+    /// reused buffer). The values of the top-k candidates so far are put in front of each
+    /// chunk; as these candidates precede the chunk (in the logical order of the array),
+    /// the ranking of ties (lowest index first) is preserved.
+    ///
+    fn _topk_any_layout<S>(
+        arr: &ArrayBase<S, Ix1>,
+        slice_fn: impl Fn(&[S::Elem]) -> Vec<usize>,
+    ) -> Vec<usize>
+    where
+        S: Data,
+        S::Elem: Copy,
+    {
+        if let Some(slice) = arr.as_slice() {
+            return slice_fn(slice);
+        }
+        let mut buffer = Vec::with_capacity(std::cmp::min(GATHER_CHUNK_SIZE, arr.len()));
+        // The indices (in the array) and values of the top-k candidates so far
+        let mut indices: Vec<usize> = Vec::new();
+        let mut values: Vec<S::Elem> = Vec::new();
+        let mut start = 0;
+        for chunk in arr.axis_chunks_iter(Axis(0), GATHER_CHUNK_SIZE) {
+            buffer.clear();
+            buffer.extend(values.iter().copied());
+            buffer.extend(chunk.iter().copied());
+            let nb_candidates = indices.len();
+            (indices, values) = slice_fn(&buffer)
+                .into_iter()
+                .map(|i| {
+                    if i < nb_candidates {
+                        (indices[i], values[i])
+                    } else {
+                        (start + i - nb_candidates, buffer[i])
+                    }
+                })
+                .unzip();
+            start += chunk.len();
+        }
+        indices
+    }
+
+    // Use the slice implementation
+    // -> implement for S where slice implementation available for S::Elem
+    // ArrayBase instead of Array1 or ArrayView1 -> https://github.com/rust-ndarray/ndarray/issues/1059
+    // Arrays with any stride are supported (see `_argminmax_any_layout`)
+    impl<S> ArgMinMax for ArrayBase<S, Ix1>
+    where
+        S: Data,
+        S::Elem: Copy,
+        for<'a> &'a [S::Elem]: ArgMinMax,
+    {
+        type Item = S::Elem;
+
+        fn argminmax(&self) -> (usize, usize) {
+            self.argminmax_with_tie(TiePolicy::First)
+        }
+
+        fn argmin(&self) -> usize {
+            self.argmin_with_tie(TiePolicy::First)
+        }
+
+        fn argmax(&self) -> usize {
+            self.argmax_with_tie(TiePolicy::First)
+        }
+
+        fn argminmax_with_values(&self) -> MinMaxResult<S::Elem> {
+            let (min_index, max_index) = self.argminmax();
+            MinMaxResult {
+                min_index,
+                min_value: self[min_index],
+                max_index,
+                max_value: self[max_index],
+            }
+        }
+
+        fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+            _argminmax_any_layout(self, TiePolicy::First, true, |s, tie| match tie {
+                TiePolicy::First => s.try_argminmax(),
+                TiePolicy::Last => {
+                    let (min_index, max_index) = s.argminmax_with_tie(tie);
+                    // Only the returned values are checked for NaNs - the slice is only
+                    // processed again when it contains only NaNs and / or infinities
+                    [s[min_index], s[max_index]]
+                        .as_slice()
+                        .try_argminmax()
+                        .or_else(|_| s.try_argminmax())
+                        .map(|_| (min_index, max_index))
+                }
+            })
+        }
+
+        fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+            _argminmax_any_layout(self, TiePolicy::First, true, |s, tie| {
+                let index = match tie {
+                    TiePolicy::First => s.try_argmin()?,
+                    TiePolicy::Last => {
+                        let index = s.argmin_with_tie(tie);
+                        // Only the returned value is checked for NaN (see try_argminmax)
+                        std::slice::from_ref(&s[index])
+                            .try_argmin()
+                            .or_else(|_| s.try_argmin())?;
+                        index
+                    }
+                };
+                Ok((index, index))
+            })
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+            _argminmax_any_layout(self, TiePolicy::First, true, |s, tie| {
+                let index = match tie {
+                    TiePolicy::First => s.try_argmax()?,
+                    TiePolicy::Last => {
+                        let index = s.argmax_with_tie(tie);
+                        // Only the returned value is checked for NaN (see try_argminmax)
+                        std::slice::from_ref(&s[index])
+                            .try_argmax()
+                            .or_else(|_| s.try_argmax())?;
+                        index
+                    }
+                };
+                Ok((index, index))
+            })
+            .map(|(_, max_index)| max_index)
+        }
+
+        fn argmin_k(&self, k: usize) -> Vec<usize> {
+            _topk_any_layout(self, |s| s.argmin_k(k))
+        }
+
+        fn argmax_k(&self, k: usize) -> Vec<usize> {
+            _topk_any_layout(self, |s| s.argmax_k(k))
+        }
+
+        fn argminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            _argminmax_any_layout(self, tie, true, |s, tie| Ok(s.argminmax_with_tie(tie))).unwrap()
+        }
+
+        fn argmin_with_tie(&self, tie: TiePolicy) -> usize {
+            _argminmax_any_layout(self, tie, true, |s, tie| {
+                let index = s.argmin_with_tie(tie);
+                Ok((index, index))
+            })
+            .unwrap()
+            .0
+        }
+
+        fn argmax_with_tie(&self, tie: TiePolicy) -> usize {
+            _argminmax_any_layout(self, tie, true, |s, tie| {
+                let index = s.argmax_with_tie(tie);
+                Ok((index, index))
+            })
+            .unwrap()
+            .1
+        }
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    impl<S> NaNArgMinMax for ArrayBase<S, Ix1>
+    where
+        S: Data,
+        S::Elem: Copy,
+        for<'a> &'a [S::Elem]: NaNArgMinMax,
+    {
+        type Item = S::Elem;
+
+        fn nanargminmax(&self) -> (usize, usize) {
+            self.nanargminmax_with_tie(TiePolicy::First)
+        }
+
+        fn nanargmin(&self) -> usize {
+            self.nanargmin_with_tie(TiePolicy::First)
+        }
+
+        fn nanargmax(&self) -> usize {
+            self.nanargmax_with_tie(TiePolicy::First)
+        }
+
+        fn nanargminmax_with_values(&self) -> MinMaxResult<S::Elem> {
+            let (min_index, max_index) = self.nanargminmax();
+            MinMaxResult {
+                min_index,
+                min_value: self[min_index],
+                max_index,
+                max_value: self[max_index],
+            }
+        }
+
+        fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+            _argminmax_any_layout(self, TiePolicy::First, false, |s, tie| {
+                // The slice is not empty when the tie policy is reversed (NaNs are
+                // returned -> no need to check for an all-NaN slice)
+                match tie {
+                    TiePolicy::First => s.try_nanargminmax(),
+                    TiePolicy::Last => Ok(s.nanargminmax_with_tie(tie)),
+                }
+            })
+        }
+
+        fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError> {
+            if let Some(slice) = self.as_slice() {
+                return slice.try_nanargmin();
+            }
+            // Both indices are required to detect a returned NaN on a reversed array
+            _argminmax_any_layout(self, TiePolicy::First, false, |s, tie| {
+                // The slice is not empty when the tie policy is reversed (NaNs are
+                // returned -> no need to check for an all-NaN slice)
+                match tie {
+                    TiePolicy::First => s.try_nanargminmax(),
+                    TiePolicy::Last => Ok(s.nanargminmax_with_tie(tie)),
+                }
+            })
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError> {
+            if let Some(slice) = self.as_slice() {
+                return slice.try_nanargmax();
+            }
+            // Both indices are required to detect a returned NaN on a reversed array
+            _argminmax_any_layout(self, TiePolicy::First, false, |s, tie| {
+                // The slice is not empty when the tie policy is reversed (NaNs are
+                // returned -> no need to check for an all-NaN slice)
+                match tie {
+                    TiePolicy::First => s.try_nanargminmax(),
+                    TiePolicy::Last => Ok(s.nanargminmax_with_tie(tie)),
+                }
+            })
+            .map(|(_, max_index)| max_index)
+        }
+
+        fn nanargmin_k(&self, k: usize) -> Vec<usize> {
+            _topk_any_layout(self, |s| s.nanargmin_k(k))
+        }
+
+        fn nanargmax_k(&self, k: usize) -> Vec<usize> {
+            _topk_any_layout(self, |s| s.nanargmax_k(k))
+        }
+
+        fn nanargminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            _argminmax_any_layout(self, tie, false, |s, tie| Ok(s.nanargminmax_with_tie(tie)))
+                .unwrap()
+        }
+
+        fn nanargmin_with_tie(&self, tie: TiePolicy) -> usize {
+            if let Some(slice) = self.as_slice() {
+                return slice.nanargmin_with_tie(tie);
+            }
+            // Both indices are required to detect a returned NaN on a reversed array
+            _argminmax_any_layout(self, tie, false, |s, tie| Ok(s.nanargminmax_with_tie(tie)))
+                .unwrap()
+                .0
+        }
+
+        fn nanargmax_with_tie(&self, tie: TiePolicy) -> usize {
+            if let Some(slice) = self.as_slice() {
+                return slice.nanargmax_with_tie(tie);
+            }
+            // Both indices are required to detect a returned NaN on a reversed array
+            _argminmax_any_layout(self, tie, false, |s, tie| Ok(s.nanargminmax_with_tie(tie)))
+                .unwrap()
+                .1
+        }
+    }
+
+    // Contiguous arrays use the parallel slice implementation, arrays with any other
+    // layout fall back to the sequential implementation (see `_argminmax_any_layout`)
+    #[cfg(feature = "rayon")]
+    impl<S> ParArgMinMax for ArrayBase<S, Ix1>
+    where
+        S: Data,
+        S::Elem: Copy + Send + Sync,
+        for<'a> &'a [S::Elem]: ArgMinMax<Item = S::Elem>,
+    {
+        fn par_argminmax(&self) -> (usize, usize) {
+            match self.as_slice() {
+                Some(slice) => slice.par_argminmax(),
+                None => self.argminmax(),
+            }
+        }
+
+        fn par_argmin(&self) -> usize {
+            match self.as_slice() {
+                Some(slice) => slice.par_argmin(),
+                None => self.argmin(),
+            }
+        }
+
+        fn par_argmax(&self) -> usize {
+            match self.as_slice() {
+                Some(slice) => slice.par_argmax(),
+                None => self.argmax(),
+            }
+        }
+    }
+
+    #[cfg(all(
+        feature = "rayon",
+        any(feature = "float", feature = "half", feature = "float8")
+    ))]
+    impl<S> NaNParArgMinMax for ArrayBase<S, Ix1>
+    where
+        S: Data,
+        S::Elem: Copy + Send + Sync,
+        for<'a> &'a [S::Elem]: NaNArgMinMax<Item = S::Elem>,
+    {
+        fn par_nanargminmax(&self) -> (usize, usize) {
+            match self.as_slice() {
+                Some(slice) => slice.par_nanargminmax(),
+                None => self.nanargminmax(),
+            }
+        }
+
+        fn par_nanargmin(&self) -> usize {
+            match self.as_slice() {
+                Some(slice) => slice.par_nanargmin(),
+                None => self.nanargmin(),
+            }
+        }
+
+        fn par_nanargmax(&self) -> usize {
+            match self.as_slice() {
+                Some(slice) => slice.par_nanargmax(),
+                None => self.nanargmax(),
+            }
+        }
+    }
+}
+
+// ----------------------- (optional) arrow ----------------------
+
+#[cfg(feature = "arrow")]
+mod arrow_impl {
+    use super::*;
+    use crate::chunked::argminmax_chunked_generic;
+    use crate::nullable::argminmax_valid_runs;
+    use arrow::array::{Array, ArrayRef, AsArray, PrimitiveArray};
+    use arrow::buffer::NullBuffer;
+    use arrow::datatypes::{ArrowPrimitiveType, DataType};
+
+    /// Get the (min_index, max_index) of the non-null values (see `argminmax_valid_runs`)
+    #[inline(always)]
+    fn _argminmax_nullable<T: Copy + PartialOrd, const RETURN_NAN: bool>(
+        values: &[T],
+        nulls: Option<&NullBuffer>,
+        slice_fn: impl Fn(&[T]) -> MinMaxResult<T>,
+    ) -> Option<(usize, usize)> {
+        match nulls {
+            Some(nulls) if nulls.null_count() > 0 => {
+                argminmax_valid_runs::<T, RETURN_NAN>(values, nulls.valid_slices(), slice_fn)
+                    .map(|r| r.indices())
+            }
+            _ if values.is_empty() => None,
+            _ => Some(slice_fn(values).indices()),
+        }
+    }
+
+    // Use the slice implementation
+    // -> implement for T where slice implementation available for T::Native
+    impl<T> ArgMinMax for PrimitiveArray<T>
+    where
+        T: arrow::datatypes::ArrowNumericType,
+        for<'a> &'a [T::Native]: ArgMinMax,
+    {
+        type Item = T::Native;
+
+        fn argminmax(&self) -> (usize, usize) {
+            self.values().as_ref().argminmax()
+        }
+
+        fn argmin(&self) -> usize {
+            self.values().as_ref().argmin()
+        }
+
+        fn argmax(&self) -> usize {
+            self.values().as_ref().argmax()
+        }
+
+        fn argminmax_with_values(&self) -> MinMaxResult<T::Native> {
+            {
+                let (min_index, max_index) = self.argminmax();
+                MinMaxResult {
+                    min_index,
+                    min_value: self.value(min_index),
+                    max_index,
+                    max_value: self.value(max_index),
+                }
+            }
+        }
+
+        fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+            self.values().as_ref().try_argminmax()
+        }
+
+        fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+            self.values().as_ref().try_argmin()
+        }
+
+        fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+            self.values().as_ref().try_argmax()
+        }
+
+        fn argmin_k(&self, k: usize) -> Vec<usize> {
+            self.values().as_ref().argmin_k(k)
+        }
+
+        fn argmax_k(&self, k: usize) -> Vec<usize> {
+            self.values().as_ref().argmax_k(k)
+        }
+
+        fn argminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            self.values().as_ref().argminmax_with_tie(tie)
+        }
+
+        fn argmin_with_tie(&self, tie: TiePolicy) -> usize {
+            self.values().as_ref().argmin_with_tie(tie)
+        }
+
+        fn argmax_with_tie(&self, tie: TiePolicy) -> usize {
+            self.values().as_ref().argmax_with_tie(tie)
+        }
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    impl<T> NaNArgMinMax for PrimitiveArray<T>
+    where
+        T: arrow::datatypes::ArrowNumericType,
+        for<'a> &'a [T::Native]: NaNArgMinMax,
+    {
+        type Item = T::Native;
+
+        fn nanargminmax(&self) -> (usize, usize) {
+            self.values().as_ref().nanargminmax()
+        }
+
+        fn nanargmin(&self) -> usize {
+            self.values().as_ref().nanargmin()
+        }
+
+        fn nanargmax(&self) -> usize {
+            self.values().as_ref().nanargmax()
+        }
+
+        fn nanargminmax_with_values(&self) -> MinMaxResult<T::Native> {
+            {
+                let (min_index, max_index) = self.nanargminmax();
+                MinMaxResult {
+                    min_index,
+                    min_value: self.value(min_index),
+                    max_index,
+                    max_value: self.value(max_index),
+                }
+            }
+        }
+
+        fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+            self.values().as_ref().try_nanargminmax()
+        }
+
+        fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError> {
+            self.values().as_ref().try_nanargmin()
+        }
+
+        fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError> {
+            self.values().as_ref().try_nanargmax()
+        }
+
+        fn nanargmin_k(&self, k: usize) -> Vec<usize> {
+            self.values().as_ref().nanargmin_k(k)
+        }
+
+        fn nanargmax_k(&self, k: usize) -> Vec<usize> {
+            self.values().as_ref().nanargmax_k(k)
+        }
+
+        fn nanargminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            self.values().as_ref().nanargminmax_with_tie(tie)
+        }
+
+        fn nanargmin_with_tie(&self, tie: TiePolicy) -> usize {
+            self.values().as_ref().nanargmin_with_tie(tie)
+        }
+
+        fn nanargmax_with_tie(&self, tie: TiePolicy) -> usize {
+            self.values().as_ref().nanargmax_with_tie(tie)
+        }
+    }
+
+    impl<T> NullableArgMinMax for PrimitiveArray<T>
+    where
+        T: arrow::datatypes::ArrowNumericType,
+        for<'a> &'a [T::Native]: ArgMinMax<Item = T::Native>,
+    {
+        fn argminmax_nullable(&self) -> Option<(usize, usize)> {
+            _argminmax_nullable::<_, false>(self.values().as_ref(), self.nulls(), |s| {
+                s.argminmax_with_values()
+            })
+        }
+
+        fn argmin_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, false>(self.values().as_ref(), self.nulls(), |s| {
+                let index = s.argmin();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn argmax_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, false>(self.values().as_ref(), self.nulls(), |s| {
+                let index = s.argmax();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(_, max_index)| max_index)
+        }
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    impl<T> NaNNullableArgMinMax for PrimitiveArray<T>
+    where
+        T: arrow::datatypes::ArrowNumericType,
+        for<'a> &'a [T::Native]: NaNArgMinMax<Item = T::Native>,
+    {
+        fn nanargminmax_nullable(&self) -> Option<(usize, usize)> {
+            _argminmax_nullable::<_, true>(self.values().as_ref(), self.nulls(), |s| {
+                s.nanargminmax_with_values()
+            })
+        }
+
+        fn nanargmin_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, true>(self.values().as_ref(), self.nulls(), |s| {
+                let index = s.nanargmin();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn nanargmax_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, true>(self.values().as_ref(), self.nulls(), |s| {
+                let index = s.nanargmax();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(_, max_index)| max_index)
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    impl<T> ParArgMinMax for PrimitiveArray<T>
+    where
+        T: arrow::datatypes::ArrowNumericType,
+        for<'a> &'a [T::Native]: ArgMinMax<Item = T::Native>,
+    {
+        fn par_argminmax(&self) -> (usize, usize) {
+            self.values().as_ref().par_argminmax()
+        }
+
+        fn par_argmin(&self) -> usize {
+            self.values().as_ref().par_argmin()
+        }
+
+        fn par_argmax(&self) -> usize {
+            self.values().as_ref().par_argmax()
+        }
+    }
+
+    #[cfg(all(
+        feature = "rayon",
+        any(feature = "float", feature = "half", feature = "float8")
+    ))]
+    impl<T> NaNParArgMinMax for PrimitiveArray<T>
+    where
+        T: arrow::datatypes::ArrowNumericType,
+        for<'a> &'a [T::Native]: NaNArgMinMax<Item = T::Native>,
+    {
+        fn par_nanargminmax(&self) -> (usize, usize) {
+            self.values().as_ref().par_nanargminmax()
+        }
+
+        fn par_nanargmin(&self) -> usize {
+            self.values().as_ref().par_nanargmin()
+        }
+
+        fn par_nanargmax(&self) -> usize {
+            self.values().as_ref().par_nanargmax()
+        }
+    }
+
+    impl<T> ChunkedArgMinMax for [PrimitiveArray<T>]
+    where
+        T: arrow::datatypes::ArrowNumericType,
+        for<'a> &'a [T::Native]: ArgMinMax<Item = T::Native>,
+    {
+        fn argminmax_chunked(&self) -> Option<(ChunkedIndex, ChunkedIndex)> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.argminmax_nullable(),
+                |chunk, index| chunk.value(index),
+                |pair| pair.argminmax(),
+            )
+        }
+
+        fn argmin_chunked(&self) -> Option<ChunkedIndex> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.argmin_nullable().map(|index| (index, index)),
+                |chunk, index| chunk.value(index),
+                |pair| {
+                    let index = pair.argmin();
+                    (index, index)
+                },
+            )
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn argmax_chunked(&self) -> Option<ChunkedIndex> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.argmax_nullable().map(|index| (index, index)),
+                |chunk, index| chunk.value(index),
+                |pair| {
+                    let index = pair.argmax();
+                    (index, index)
+                },
+            )
+            .map(|(_, max_index)| max_index)
+        }
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    impl<T> NaNChunkedArgMinMax for [PrimitiveArray<T>]
+    where
+        T: arrow::datatypes::ArrowNumericType,
+        for<'a> &'a [T::Native]: NaNArgMinMax<Item = T::Native>,
+    {
+        fn nanargminmax_chunked(&self) -> Option<(ChunkedIndex, ChunkedIndex)> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.nanargminmax_nullable(),
+                |chunk, index| chunk.value(index),
+                |pair| pair.nanargminmax(),
+            )
+        }
+
+        fn nanargmin_chunked(&self) -> Option<ChunkedIndex> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.nanargmin_nullable().map(|index| (index, index)),
+                |chunk, index| chunk.value(index),
+                |pair| {
+                    let index = pair.nanargmin();
+                    (index, index)
+                },
+            )
+            .map(|(min_index, _)| min_index)
+        }
 
-    fn argmax(&self) -> usize {
-        self.as_slice().argmax()
+        fn nanargmax_chunked(&self) -> Option<ChunkedIndex> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.nanargmax_nullable().map(|index| (index, index)),
+                |chunk, index| chunk.value(index),
+                |pair| {
+                    let index = pair.nanargmax();
+                    (index, index)
+                },
+            )
+            .map(|(_, max_index)| max_index)
+        }
     }
-}
 
-#[cfg(any(feature = "float", feature = "half"))]
-impl<T> NaNArgMinMax for Vec<T>
-where
-    for<'a> &'a [T]: NaNArgMinMax,
-{
-    fn nanargminmax(&self) -> (usize, usize) {
-        self.as_slice().nanargminmax()
+    /// Downcast the chunks to `PrimitiveArray<T>` - this is cheap as the buffers are
+    /// reference counted.
+    ///
+    /// # Returns
+    /// `None` when a chunk is not a `PrimitiveArray<T>`.
+    ///
+    #[inline(always)]
+    fn _downcast_chunks<T: ArrowPrimitiveType>(
+        chunks: &[ArrayRef],
+    ) -> Option<Vec<PrimitiveArray<T>>> {
+        chunks
+            .iter()
+            .map(|chunk| chunk.as_primitive_opt::<T>().cloned())
+            .collect()
     }
 
-    fn nanargmin(&self) -> usize {
-        self.as_slice().nanargmin()
+    /// Call the method on the chunks, downcast to the primitive array type of the data
+    /// type of the first chunk - `None` when there are no chunks, when the data type is
+    /// not supported or when the chunks have different data types.
+    macro_rules! dispatch_chunks {
+        (
+            $chunks:expr,
+            $method:ident,
+            [$($(#[$cfg:meta])* $data_type:pat => $arrow_type:ty),*]
+        ) => {
+            match $chunks.first().map(|chunk| chunk.data_type()) {
+                $(
+                    $(#[$cfg])*
+                    Some($data_type) => {
+                        _downcast_chunks::<$arrow_type>($chunks).and_then(|chunks| chunks.$method())
+                    }
+                )*
+                _ => None,
+            }
+        };
     }
 
-    fn nanargmax(&self) -> usize {
-        self.as_slice().nanargmax()
+    #[cfg(any(feature = "float", feature = "half"))]
+    macro_rules! dispatch_float_chunks {
+        ($chunks:expr, $method:ident) => {
+            dispatch_chunks!($chunks, $method, [
+                #[cfg(feature = "half")]
+                DataType::Float16 => arrow::datatypes::Float16Type,
+                #[cfg(feature = "float")]
+                DataType::Float32 => arrow::datatypes::Float32Type,
+                #[cfg(feature = "float")]
+                DataType::Float64 => arrow::datatypes::Float64Type
+            ])
+        };
     }
-}
-
-// ----------------------- (optional) ndarray ----------------------
 
-#[cfg(feature = "ndarray")]
-mod ndarray_impl {
-    use super::*;
-    use ndarray::{ArrayBase, Data, Ix1};
+    macro_rules! dispatch_numeric_chunks {
+        ($chunks:expr, $method:ident) => {
+            dispatch_chunks!($chunks, $method, [
+                DataType::Int8 => arrow::datatypes::Int8Type,
+                DataType::Int16 => arrow::datatypes::Int16Type,
+                DataType::Int32 => arrow::datatypes::Int32Type,
+                DataType::Int64 => arrow::datatypes::Int64Type,
+                DataType::Decimal128(..) => arrow::datatypes::Decimal128Type,
+                DataType::UInt8 => arrow::datatypes::UInt8Type,
+                DataType::UInt16 => arrow::datatypes::UInt16Type,
+                DataType::UInt32 => arrow::datatypes::UInt32Type,
+                DataType::UInt64 => arrow::datatypes::UInt64Type,
+                #[cfg(feature = "half")]
+                DataType::Float16 => arrow::datatypes::Float16Type,
+                #[cfg(feature = "float")]
+                DataType::Float32 => arrow::datatypes::Float32Type,
+                #[cfg(feature = "float")]
+                DataType::Float64 => arrow::datatypes::Float64Type
+            ])
+        };
+    }
 
-    // Use the slice implementation
-    // -> implement for S where slice implementation available for S::Elem
-    // ArrayBase instead of Array1 or ArrayView1 -> https://github.com/rust-ndarray/ndarray/issues/1059
-    impl<S> ArgMinMax for ArrayBase<S, Ix1>
-    where
-        S: Data,
-        for<'a> &'a [S::Elem]: ArgMinMax,
-    {
-        fn argminmax(&self) -> (usize, usize) {
-            self.as_slice().unwrap().argminmax()
+    // Downcast the chunks based on their data type
+    // -> use the [PrimitiveArray<T>] implementation
+    impl ChunkedArgMinMax for [ArrayRef] {
+        fn argminmax_chunked(&self) -> Option<(ChunkedIndex, ChunkedIndex)> {
+            dispatch_numeric_chunks!(self, argminmax_chunked)
         }
 
-        fn argmin(&self) -> usize {
-            self.as_slice().unwrap().argmin()
+        fn argmin_chunked(&self) -> Option<ChunkedIndex> {
+            dispatch_numeric_chunks!(self, argmin_chunked)
         }
 
-        fn argmax(&self) -> usize {
-            self.as_slice().unwrap().argmax()
+        fn argmax_chunked(&self) -> Option<ChunkedIndex> {
+            dispatch_numeric_chunks!(self, argmax_chunked)
         }
     }
 
     #[cfg(any(feature = "float", feature = "half"))]
-    impl<S> NaNArgMinMax for ArrayBase<S, Ix1>
-    where
-        S: Data,
-        for<'a> &'a [S::Elem]: NaNArgMinMax,
-    {
-        fn nanargminmax(&self) -> (usize, usize) {
-            self.as_slice().unwrap().nanargminmax()
+    impl NaNChunkedArgMinMax for [ArrayRef] {
+        fn nanargminmax_chunked(&self) -> Option<(ChunkedIndex, ChunkedIndex)> {
+            dispatch_float_chunks!(self, nanargminmax_chunked)
         }
 
-        fn nanargmin(&self) -> usize {
-            self.as_slice().unwrap().nanargmin()
+        fn nanargmin_chunked(&self) -> Option<ChunkedIndex> {
+            dispatch_float_chunks!(self, nanargmin_chunked)
         }
 
-        fn nanargmax(&self) -> usize {
-            self.as_slice().unwrap().nanargmax()
+        fn nanargmax_chunked(&self) -> Option<ChunkedIndex> {
+            dispatch_float_chunks!(self, nanargmax_chunked)
         }
     }
 }
 
-// ----------------------- (optional) arrow ----------------------
+// ---------------------- (optional) arrow2 ----------------------
 
-#[cfg(feature = "arrow")]
-mod arrow_impl {
+#[cfg(feature = "arrow2")]
+mod arrow2_impl {
     use super::*;
-    use arrow::array::PrimitiveArray;
+    use crate::chunked::argminmax_chunked_generic;
+    use crate::nullable::argminmax_valid_runs;
+    use arrow2::array::PrimitiveArray;
+    use arrow2::bitmap::utils::SlicesIterator;
+    use arrow2::bitmap::Bitmap;
+
+    /// Get the (min_index, max_index) of the non-null values (see `argminmax_valid_runs`)
+    #[inline(always)]
+    fn _argminmax_nullable<T: Copy + PartialOrd, const RETURN_NAN: bool>(
+        values: &[T],
+        validity: Option<&Bitmap>,
+        slice_fn: impl Fn(&[T]) -> MinMaxResult<T>,
+    ) -> Option<(usize, usize)> {
+        match validity {
+            Some(validity) if validity.unset_bits() > 0 => {
+                // SlicesIterator returns the runs as (start, len)
+                let valid_runs =
+                    SlicesIterator::new(validity).map(|(start, len)| (start, start + len));
+                argminmax_valid_runs::<T, RETURN_NAN>(values, valid_runs, slice_fn)
+                    .map(|r| r.indices())
+            }
+            _ if values.is_empty() => None,
+            _ => Some(slice_fn(values).indices()),
+        }
+    }
 
-    // Use the slice implementation
-    // -> implement for T where slice implementation available for T::Native
     impl<T> ArgMinMax for PrimitiveArray<T>
     where
-        T: arrow::datatypes::ArrowNumericType,
-        for<'a> &'a [T::Native]: ArgMinMax,
+        T: arrow2::types::NativeType,
+        for<'a> &'a [T]: ArgMinMax,
     {
+        type Item = T;
+
         fn argminmax(&self) -> (usize, usize) {
             self.values().as_ref().argminmax()
         }
@@ -825,14 +3539,60 @@ mod arrow_impl {
         fn argmax(&self) -> usize {
             self.values().as_ref().argmax()
         }
+
+        fn argminmax_with_values(&self) -> MinMaxResult<T> {
+            {
+                let (min_index, max_index) = self.argminmax();
+                MinMaxResult {
+                    min_index,
+                    min_value: self.value(min_index),
+                    max_index,
+                    max_value: self.value(max_index),
+                }
+            }
+        }
+
+        fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+            self.values().as_ref().try_argminmax()
+        }
+
+        fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+            self.values().as_ref().try_argmin()
+        }
+
+        fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+            self.values().as_ref().try_argmax()
+        }
+
+        fn argmin_k(&self, k: usize) -> Vec<usize> {
+            self.values().as_ref().argmin_k(k)
+        }
+
+        fn argmax_k(&self, k: usize) -> Vec<usize> {
+            self.values().as_ref().argmax_k(k)
+        }
+
+        fn argminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            self.values().as_ref().argminmax_with_tie(tie)
+        }
+
+        fn argmin_with_tie(&self, tie: TiePolicy) -> usize {
+            self.values().as_ref().argmin_with_tie(tie)
+        }
+
+        fn argmax_with_tie(&self, tie: TiePolicy) -> usize {
+            self.values().as_ref().argmax_with_tie(tie)
+        }
     }
 
-    #[cfg(any(feature = "float", feature = "half"))]
+    #[cfg(feature = "float")]
     impl<T> NaNArgMinMax for PrimitiveArray<T>
     where
-        T: arrow::datatypes::ArrowNumericType,
-        for<'a> &'a [T::Native]: NaNArgMinMax,
+        T: arrow2::types::NativeType,
+        for<'a> &'a [T]: NaNArgMinMax,
     {
+        type Item = T;
+
         fn nanargminmax(&self) -> (usize, usize) {
             self.values().as_ref().nanargminmax()
         }
@@ -844,50 +3604,233 @@ mod arrow_impl {
         fn nanargmax(&self) -> usize {
             self.values().as_ref().nanargmax()
         }
+
+        fn nanargminmax_with_values(&self) -> MinMaxResult<T> {
+            {
+                let (min_index, max_index) = self.nanargminmax();
+                MinMaxResult {
+                    min_index,
+                    min_value: self.value(min_index),
+                    max_index,
+                    max_value: self.value(max_index),
+                }
+            }
+        }
+
+        fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+            self.values().as_ref().try_nanargminmax()
+        }
+
+        fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError> {
+            self.values().as_ref().try_nanargmin()
+        }
+
+        fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError> {
+            self.values().as_ref().try_nanargmax()
+        }
+
+        fn nanargmin_k(&self, k: usize) -> Vec<usize> {
+            self.values().as_ref().nanargmin_k(k)
+        }
+
+        fn nanargmax_k(&self, k: usize) -> Vec<usize> {
+            self.values().as_ref().nanargmax_k(k)
+        }
+
+        fn nanargminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            self.values().as_ref().nanargminmax_with_tie(tie)
+        }
+
+        fn nanargmin_with_tie(&self, tie: TiePolicy) -> usize {
+            self.values().as_ref().nanargmin_with_tie(tie)
+        }
+
+        fn nanargmax_with_tie(&self, tie: TiePolicy) -> usize {
+            self.values().as_ref().nanargmax_with_tie(tie)
+        }
     }
-}
 
-// ---------------------- (optional) arrow2 ----------------------
+    impl<T> NullableArgMinMax for PrimitiveArray<T>
+    where
+        T: arrow2::types::NativeType + PartialOrd,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        fn argminmax_nullable(&self) -> Option<(usize, usize)> {
+            _argminmax_nullable::<_, false>(self.values().as_ref(), self.validity(), |s| {
+                s.argminmax_with_values()
+            })
+        }
 
-#[cfg(feature = "arrow2")]
-mod arrow2_impl {
-    use super::*;
-    use arrow2::array::PrimitiveArray;
+        fn argmin_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, false>(self.values().as_ref(), self.validity(), |s| {
+                let index = s.argmin();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(min_index, _)| min_index)
+        }
 
-    impl<T> ArgMinMax for PrimitiveArray<T>
+        fn argmax_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, false>(self.values().as_ref(), self.validity(), |s| {
+                let index = s.argmax();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(_, max_index)| max_index)
+        }
+    }
+
+    #[cfg(feature = "float")]
+    impl<T> NaNNullableArgMinMax for PrimitiveArray<T>
+    where
+        T: arrow2::types::NativeType + PartialOrd,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+    {
+        fn nanargminmax_nullable(&self) -> Option<(usize, usize)> {
+            _argminmax_nullable::<_, true>(self.values().as_ref(), self.validity(), |s| {
+                s.nanargminmax_with_values()
+            })
+        }
+
+        fn nanargmin_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, true>(self.values().as_ref(), self.validity(), |s| {
+                let index = s.nanargmin();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn nanargmax_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, true>(self.values().as_ref(), self.validity(), |s| {
+                let index = s.nanargmax();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(_, max_index)| max_index)
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    impl<T> ParArgMinMax for PrimitiveArray<T>
     where
         T: arrow2::types::NativeType,
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
     {
-        fn argminmax(&self) -> (usize, usize) {
-            self.values().as_ref().argminmax()
+        fn par_argminmax(&self) -> (usize, usize) {
+            self.values().as_ref().par_argminmax()
         }
 
-        fn argmin(&self) -> usize {
-            self.values().as_ref().argmin()
+        fn par_argmin(&self) -> usize {
+            self.values().as_ref().par_argmin()
         }
 
-        fn argmax(&self) -> usize {
-            self.values().as_ref().argmax()
+        fn par_argmax(&self) -> usize {
+            self.values().as_ref().par_argmax()
         }
     }
 
-    #[cfg(feature = "float")]
-    impl<T> NaNArgMinMax for PrimitiveArray<T>
+    #[cfg(all(feature = "rayon", feature = "float"))]
+    impl<T> NaNParArgMinMax for PrimitiveArray<T>
     where
         T: arrow2::types::NativeType,
-        for<'a> &'a [T]: NaNArgMinMax,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
     {
-        fn nanargminmax(&self) -> (usize, usize) {
-            self.values().as_ref().nanargminmax()
+        fn par_nanargminmax(&self) -> (usize, usize) {
+            self.values().as_ref().par_nanargminmax()
         }
 
-        fn nanargmin(&self) -> usize {
-            self.values().as_ref().nanargmin()
+        fn par_nanargmin(&self) -> usize {
+            self.values().as_ref().par_nanargmin()
         }
 
-        fn nanargmax(&self) -> usize {
-            self.values().as_ref().nanargmax()
+        fn par_nanargmax(&self) -> usize {
+            self.values().as_ref().par_nanargmax()
+        }
+    }
+
+    impl<T> ChunkedArgMinMax for [PrimitiveArray<T>]
+    where
+        T: arrow2::types::NativeType + PartialOrd,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        fn argminmax_chunked(&self) -> Option<(ChunkedIndex, ChunkedIndex)> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.argminmax_nullable(),
+                |chunk, index| chunk.value(index),
+                |pair| pair.argminmax(),
+            )
+        }
+
+        fn argmin_chunked(&self) -> Option<ChunkedIndex> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.argmin_nullable().map(|index| (index, index)),
+                |chunk, index| chunk.value(index),
+                |pair| {
+                    let index = pair.argmin();
+                    (index, index)
+                },
+            )
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn argmax_chunked(&self) -> Option<ChunkedIndex> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.argmax_nullable().map(|index| (index, index)),
+                |chunk, index| chunk.value(index),
+                |pair| {
+                    let index = pair.argmax();
+                    (index, index)
+                },
+            )
+            .map(|(_, max_index)| max_index)
+        }
+    }
+
+    #[cfg(feature = "float")]
+    impl<T> NaNChunkedArgMinMax for [PrimitiveArray<T>]
+    where
+        T: arrow2::types::NativeType + PartialOrd,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+    {
+        fn nanargminmax_chunked(&self) -> Option<(ChunkedIndex, ChunkedIndex)> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.nanargminmax_nullable(),
+                |chunk, index| chunk.value(index),
+                |pair| pair.nanargminmax(),
+            )
+        }
+
+        fn nanargmin_chunked(&self) -> Option<ChunkedIndex> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.nanargmin_nullable().map(|index| (index, index)),
+                |chunk, index| chunk.value(index),
+                |pair| {
+                    let index = pair.nanargmin();
+                    (index, index)
+                },
+            )
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn nanargmax_chunked(&self) -> Option<ChunkedIndex> {
+            argminmax_chunked_generic(
+                self,
+                |chunk| chunk.len(),
+                |chunk| chunk.nanargmax_nullable().map(|index| (index, index)),
+                |chunk, index| chunk.value(index),
+                |pair| {
+                    let index = pair.nanargmax();
+                    (index, index)
+                },
+            )
+            .map(|(_, max_index)| max_index)
         }
     }
 
@@ -907,8 +3850,23 @@ mod arrow2_impl {
         }
     }
 
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    /// Convert a MinMaxResult<half::f16> to a MinMaxResult<arrow2::types::f16>
+    /// (both use u16 as their underlying type)
+    fn _to_arrow2_f16_result(result: MinMaxResult<half::f16>) -> MinMaxResult<arrow2::types::f16> {
+        MinMaxResult {
+            min_index: result.min_index,
+            min_value: arrow2::types::f16::from_bits(result.min_value.to_bits()),
+            max_index: result.max_index,
+            max_value: arrow2::types::f16::from_bits(result.max_value.to_bits()),
+        }
+    }
+
     #[cfg(feature = "half")]
     impl ArgMinMax for PrimitiveArray<arrow2::types::f16> {
+        type Item = arrow2::types::f16;
+
         fn argminmax(&self) -> (usize, usize) {
             _to_half_f16_slice(self).argminmax()
         }
@@ -920,10 +3878,48 @@ mod arrow2_impl {
         fn argmax(&self) -> usize {
             _to_half_f16_slice(self).argmax()
         }
+
+        fn argminmax_with_values(&self) -> MinMaxResult<arrow2::types::f16> {
+            _to_arrow2_f16_result(_to_half_f16_slice(self).argminmax_with_values())
+        }
+
+        fn try_argminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+            _to_half_f16_slice(self).try_argminmax()
+        }
+
+        fn try_argmin(&self) -> Result<usize, ArgMinMaxError> {
+            _to_half_f16_slice(self).try_argmin()
+        }
+
+        fn try_argmax(&self) -> Result<usize, ArgMinMaxError> {
+            _to_half_f16_slice(self).try_argmax()
+        }
+
+        fn argmin_k(&self, k: usize) -> Vec<usize> {
+            _to_half_f16_slice(self).argmin_k(k)
+        }
+
+        fn argmax_k(&self, k: usize) -> Vec<usize> {
+            _to_half_f16_slice(self).argmax_k(k)
+        }
+
+        fn argminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            _to_half_f16_slice(self).argminmax_with_tie(tie)
+        }
+
+        fn argmin_with_tie(&self, tie: TiePolicy) -> usize {
+            _to_half_f16_slice(self).argmin_with_tie(tie)
+        }
+
+        fn argmax_with_tie(&self, tie: TiePolicy) -> usize {
+            _to_half_f16_slice(self).argmax_with_tie(tie)
+        }
     }
 
     #[cfg(feature = "half")]
     impl NaNArgMinMax for PrimitiveArray<arrow2::types::f16> {
+        type Item = arrow2::types::f16;
+
         fn nanargminmax(&self) -> (usize, usize) {
             _to_half_f16_slice(self).nanargminmax()
         }
@@ -935,5 +3931,121 @@ mod arrow2_impl {
         fn nanargmax(&self) -> usize {
             _to_half_f16_slice(self).nanargmax()
         }
+
+        fn nanargminmax_with_values(&self) -> MinMaxResult<arrow2::types::f16> {
+            _to_arrow2_f16_result(_to_half_f16_slice(self).nanargminmax_with_values())
+        }
+
+        fn try_nanargminmax(&self) -> Result<(usize, usize), ArgMinMaxError> {
+            _to_half_f16_slice(self).try_nanargminmax()
+        }
+
+        fn try_nanargmin(&self) -> Result<usize, ArgMinMaxError> {
+            _to_half_f16_slice(self).try_nanargmin()
+        }
+
+        fn try_nanargmax(&self) -> Result<usize, ArgMinMaxError> {
+            _to_half_f16_slice(self).try_nanargmax()
+        }
+
+        fn nanargmin_k(&self, k: usize) -> Vec<usize> {
+            _to_half_f16_slice(self).nanargmin_k(k)
+        }
+
+        fn nanargmax_k(&self, k: usize) -> Vec<usize> {
+            _to_half_f16_slice(self).nanargmax_k(k)
+        }
+
+        fn nanargminmax_with_tie(&self, tie: TiePolicy) -> (usize, usize) {
+            _to_half_f16_slice(self).nanargminmax_with_tie(tie)
+        }
+
+        fn nanargmin_with_tie(&self, tie: TiePolicy) -> usize {
+            _to_half_f16_slice(self).nanargmin_with_tie(tie)
+        }
+
+        fn nanargmax_with_tie(&self, tie: TiePolicy) -> usize {
+            _to_half_f16_slice(self).nanargmax_with_tie(tie)
+        }
+    }
+
+    #[cfg(feature = "half")]
+    impl NullableArgMinMax for PrimitiveArray<arrow2::types::f16> {
+        fn argminmax_nullable(&self) -> Option<(usize, usize)> {
+            _argminmax_nullable::<_, false>(_to_half_f16_slice(self), self.validity(), |s| {
+                s.argminmax_with_values()
+            })
+        }
+
+        fn argmin_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, false>(_to_half_f16_slice(self), self.validity(), |s| {
+                let index = s.argmin();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn argmax_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, false>(_to_half_f16_slice(self), self.validity(), |s| {
+                let index = s.argmax();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(_, max_index)| max_index)
+        }
+    }
+
+    #[cfg(feature = "half")]
+    impl NaNNullableArgMinMax for PrimitiveArray<arrow2::types::f16> {
+        fn nanargminmax_nullable(&self) -> Option<(usize, usize)> {
+            _argminmax_nullable::<_, true>(_to_half_f16_slice(self), self.validity(), |s| {
+                s.nanargminmax_with_values()
+            })
+        }
+
+        fn nanargmin_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, true>(_to_half_f16_slice(self), self.validity(), |s| {
+                let index = s.nanargmin();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(min_index, _)| min_index)
+        }
+
+        fn nanargmax_nullable(&self) -> Option<usize> {
+            _argminmax_nullable::<_, true>(_to_half_f16_slice(self), self.validity(), |s| {
+                let index = s.nanargmax();
+                MinMaxResult::from_index(s, index)
+            })
+            .map(|(_, max_index)| max_index)
+        }
+    }
+
+    #[cfg(all(feature = "rayon", feature = "half"))]
+    impl ParArgMinMax for PrimitiveArray<arrow2::types::f16> {
+        fn par_argminmax(&self) -> (usize, usize) {
+            _to_half_f16_slice(self).par_argminmax()
+        }
+
+        fn par_argmin(&self) -> usize {
+            _to_half_f16_slice(self).par_argmin()
+        }
+
+        fn par_argmax(&self) -> usize {
+            _to_half_f16_slice(self).par_argmax()
+        }
+    }
+
+    #[cfg(all(feature = "rayon", feature = "half"))]
+    impl NaNParArgMinMax for PrimitiveArray<arrow2::types::f16> {
+        fn par_nanargminmax(&self) -> (usize, usize) {
+            _to_half_f16_slice(self).par_nanargminmax()
+        }
+
+        fn par_nanargmin(&self) -> usize {
+            _to_half_f16_slice(self).par_nanargmin()
+        }
+
+        fn par_nanargmax(&self) -> usize {
+            _to_half_f16_slice(self).par_nanargmax()
+        }
     }
 }
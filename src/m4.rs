@@ -0,0 +1,130 @@
+use num_traits::AsPrimitive;
+
+/// Trait for M4 downsampling - the first, last, minimum and maximum value of each bin.
+/// For floats, NaNs are ignored (for the minimum and maximum).
+///
+/// This trait is implemented for slices and `Vec`s of the same data types as
+/// [`ArgMinMax`](crate::ArgMinMax). The bins are the same as those of
+/// [`BinnedArgMinMax`](crate::BinnedArgMinMax): either `n_bins` equal-width bins of the
+/// indices, or bins on a sorted x-axis (equal-width or with explicit edges). The
+/// minimum and maximum of each bin are computed by the (SIMD) kernels.
+///
+/// All methods return a flat vector with the sorted (and deduplicated) indices of the
+/// first, minimum, maximum and last value of each bin - i.e., at most `4 * n_bins`
+/// indices. Empty bins are skipped.
+///
+pub trait M4ArgMinMax {
+    /// Get the indices of the first, minimum, maximum and last value of each of the
+    /// `n_bins` equal-width bins (see [`BinnedArgMinMax::binned_argminmax`](crate::BinnedArgMinMax::binned_argminmax)).
+    ///
+    /// When dealing with floats, NaNs are ignored for the minimum and maximum.
+    ///
+    /// # Panics
+    /// When `n_bins` is 0.
+    ///
+    fn m4(&self, n_bins: usize) -> Vec<usize>;
+
+    /// Get the indices of the first, minimum, maximum and last value of each of the
+    /// `n_bins` equal-width bins of the sorted x-axis `x` (see
+    /// [`BinnedArgMinMax::binned_argminmax_x`](crate::BinnedArgMinMax::binned_argminmax_x)).
+    ///
+    /// When dealing with floats, NaNs are ignored for the minimum and maximum.
+    ///
+    /// # Panics
+    /// When `n_bins` is 0 or when `x` and the array have a different length.
+    ///
+    fn m4_x<X>(&self, x: &[X], n_bins: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>;
+
+    /// Get the indices of the first, minimum, maximum and last value of each bin
+    /// defined by the sorted `edges` on the sorted x-axis `x` (see
+    /// [`BinnedArgMinMax::binned_argminmax_edges`](crate::BinnedArgMinMax::binned_argminmax_edges)).
+    ///
+    /// When dealing with floats, NaNs are ignored for the minimum and maximum.
+    ///
+    /// # Panics
+    /// When `x` and the array have a different length.
+    ///
+    fn m4_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<usize>
+    where
+        X: Copy + PartialOrd;
+}
+
+/// Trait for M4 downsampling - the first, last, minimum and maximum value of each bin.
+/// For floats, NaNs are propagated - index of the first NaN in the bin is returned as
+/// minimum and maximum.
+///
+/// See [`M4ArgMinMax`] for the supported types and the bins.
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub trait NaNM4ArgMinMax {
+    /// Get the indices of the first, minimum, maximum and last value of each of the
+    /// `n_bins` equal-width bins.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN in the
+    /// bin is returned as minimum and maximum.
+    ///
+    /// # Panics
+    /// When `n_bins` is 0.
+    ///
+    fn nan_m4(&self, n_bins: usize) -> Vec<usize>;
+
+    /// Get the indices of the first, minimum, maximum and last value of each of the
+    /// `n_bins` equal-width bins of the sorted x-axis `x`.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN in the
+    /// bin is returned as minimum and maximum.
+    ///
+    /// # Panics
+    /// When `n_bins` is 0 or when `x` and the array have a different length.
+    ///
+    fn nan_m4_x<X>(&self, x: &[X], n_bins: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>;
+
+    /// Get the indices of the first, minimum, maximum and last value of each bin
+    /// defined by the sorted `edges` on the sorted x-axis `x`.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN in the
+    /// bin is returned as minimum and maximum.
+    ///
+    /// # Panics
+    /// When `x` and the array have a different length.
+    ///
+    fn nan_m4_edges<X>(&self, x: &[X], edges: &[X]) -> Vec<usize>
+    where
+        X: Copy + PartialOrd;
+}
+
+/// Get the sorted (and deduplicated) indices of the first, minimum, maximum and last
+/// value of each bin.
+///
+/// - `offsets` are the offsets of the bins (see `segmented_argminmax_generic`)
+/// - `slice_fn` returns the (min_index, max_index) of a bin
+///
+pub(crate) fn m4_generic<T: Copy>(
+    data: &[T],
+    offsets: &[usize],
+    slice_fn: impl Fn(&[T]) -> (usize, usize),
+) -> Vec<usize> {
+    let mut result = Vec::with_capacity(4 * offsets.len().saturating_sub(1));
+    for bounds in offsets.windows(2) {
+        let (start, end) = (bounds[0], bounds[1]);
+        if start == end {
+            continue;
+        }
+        let (min_index, max_index) = slice_fn(&data[start..end]);
+        let mut indices = [start, start + min_index, start + max_index, end - 1];
+        indices.sort_unstable();
+        // The bins are disjoint -> only duplicates within the bin have to be removed
+        let mut previous = None;
+        for index in indices {
+            if previous != Some(index) {
+                result.push(index);
+                previous = Some(index);
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,156 @@
+use num_traits::AsPrimitive;
+
+/// Trait for MinMaxLTTB downsampling - Largest-Triangle-Three-Buckets (LTTB) on the
+/// candidates that are preselected with MinMax downsampling.
+///
+/// This trait is implemented for slices and `Vec`s of the same data types as
+/// [`ArgMinMax`](crate::ArgMinMax), the x-axis can be of any of these types as well.
+/// LTTB selects the points that span the largest triangles, which is expensive on huge
+/// series. MinMaxLTTB first keeps `minmax_ratio * n_out` candidates - the minimum and
+/// maximum of `minmax_ratio * n_out / 2` equal-width bins, computed by the (SIMD)
+/// kernels - and then runs LTTB on these candidates. The first and last point are
+/// always selected. A `minmax_ratio` of 4 is a good default.
+///
+/// The values (and x-axis) are converted to `f64` for the LTTB step.
+///
+pub trait MinMaxLTTB {
+    /// Get the indices of the `n_out` selected points, where the x-axis is the index.
+    ///
+    /// When dealing with floats, NaNs are ignored by the MinMax preselection.
+    ///
+    /// # Returns
+    /// The sorted indices of the selected points. These are `n_out` indices, unless
+    /// there are fewer candidates (e.g., when the array is shorter than `n_out`).
+    ///
+    /// # Caution
+    /// The LTTB step does not handle NaNs (the triangle areas become NaN) - the result
+    /// is only meaningful for arrays without NaNs.
+    ///
+    /// # Panics
+    /// When `n_out` is smaller than 3 or when `minmax_ratio` is 0.
+    ///
+    fn minmaxlttb(&self, n_out: usize, minmax_ratio: usize) -> Vec<usize>;
+
+    /// Get the indices of the `n_out` selected points, where `x` is the sorted x-axis.
+    ///
+    /// The MinMax preselection uses `minmax_ratio * n_out / 2` equal-width bins of `x`
+    /// (see [`BinnedArgMinMax::binned_argminmax_x`](crate::BinnedArgMinMax::binned_argminmax_x)).
+    ///
+    /// When dealing with floats, NaNs are ignored by the MinMax preselection.
+    ///
+    /// # Returns
+    /// The sorted indices of the selected points. These are `n_out` indices, unless
+    /// there are fewer candidates (e.g., when bins of the x-axis are empty).
+    ///
+    /// # Caution
+    /// The LTTB step does not handle NaNs (the triangle areas become NaN) - the result
+    /// is only meaningful for arrays without NaNs.
+    ///
+    /// # Panics
+    /// When `n_out` is smaller than 3, when `minmax_ratio` is 0 or when `x` and the
+    /// array have a different length.
+    ///
+    fn minmaxlttb_x<X>(&self, x: &[X], n_out: usize, minmax_ratio: usize) -> Vec<usize>
+    where
+        X: Copy + PartialOrd + AsPrimitive<f64>;
+}
+
+/// Get the indices of the `n_out` points that are selected by MinMaxLTTB.
+///
+/// - `x` returns the x-value of an index
+/// - `bin_offsets` returns the offsets (see `segmented_argminmax_generic`) of the given
+///   number of bins, which cover the values in `1..y.len() - 1` (the first and last
+///   point are always selected)
+/// - `slice_fn` returns the (min_index, max_index) of a bin
+///
+/// # Panics
+/// When `n_out` is smaller than 3 or when `minmax_ratio` is 0.
+///
+pub(crate) fn minmaxlttb_generic<T: Copy + AsPrimitive<f64>>(
+    y: &[T],
+    x: impl Fn(usize) -> f64,
+    n_out: usize,
+    minmax_ratio: usize,
+    bin_offsets: impl FnOnce(usize) -> Vec<usize>,
+    slice_fn: impl Fn(&[T]) -> (usize, usize),
+) -> Vec<usize> {
+    assert!(n_out >= 3, "n_out must be at least 3");
+    assert!(minmax_ratio > 0, "minmax_ratio must be larger than 0");
+    let n = y.len();
+    if n <= n_out {
+        return (0..n).collect();
+    }
+
+    let candidates: Vec<usize> = match minmax_ratio * n_out >= n {
+        // No preselection when it would keep (nearly) all points
+        true => (0..n).collect(),
+        false => {
+            let offsets = bin_offsets(minmax_ratio * n_out / 2);
+            let mut candidates = Vec::with_capacity(minmax_ratio * n_out + 2);
+            candidates.push(0);
+            for bounds in offsets.windows(2) {
+                let (start, end) = (bounds[0], bounds[1]);
+                if start == end {
+                    continue;
+                }
+                let (min_index, max_index) = slice_fn(&y[start..end]);
+                let (first, last) = (min_index.min(max_index), min_index.max(max_index));
+                candidates.push(start + first);
+                if first != last {
+                    candidates.push(start + last);
+                }
+            }
+            candidates.push(n - 1);
+            candidates
+        }
+    };
+
+    let xs: Vec<f64> = candidates.iter().map(|&i| x(i)).collect();
+    let ys: Vec<f64> = candidates.iter().map(|&i| y[i].as_()).collect();
+    _lttb(&xs, &ys, n_out)
+        .into_iter()
+        .map(|i| candidates[i])
+        .collect()
+}
+
+/// Get the indices of the `n_out` points that are selected by LTTB.
+///
+/// The first and last point are always selected. The other points are split into
+/// `n_out - 2` buckets, from each bucket the point that forms the largest triangle with
+/// the previously selected point and the average of the next bucket is selected.
+///
+fn _lttb(x: &[f64], y: &[f64], n_out: usize) -> Vec<usize> {
+    let n = x.len();
+    if n <= n_out {
+        return (0..n).collect();
+    }
+    let every = (n - 2) as f64 / (n_out - 2) as f64;
+    let mut result = Vec::with_capacity(n_out);
+    let mut a = 0; // the previously selected point
+    result.push(a);
+    for i in 0..n_out - 2 {
+        // The average of the next bucket (the last point for the last bucket)
+        let avg_start = (every * (i + 1) as f64) as usize + 1;
+        let avg_end = std::cmp::min((every * (i + 2) as f64) as usize + 1, n);
+        let avg_len = (avg_end - avg_start) as f64;
+        let avg_x = x[avg_start..avg_end].iter().sum::<f64>() / avg_len;
+        let avg_y = y[avg_start..avg_end].iter().sum::<f64>() / avg_len;
+
+        // The point of the current bucket that spans the largest triangle
+        let (a_x, a_y) = (x[a], y[a]);
+        let bucket_start = (every * i as f64) as usize + 1;
+        let mut max_area = -1.0;
+        let mut max_index = bucket_start;
+        for j in bucket_start..avg_start {
+            let area = ((a_x - avg_x) * (y[j] - a_y) - (a_x - x[j]) * (avg_y - a_y)).abs();
+            if area > max_area {
+                max_area = area;
+                max_index = j;
+            }
+        }
+        result.push(max_index);
+        a = max_index;
+    }
+    result.push(n - 1);
+    result
+}
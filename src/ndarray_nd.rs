@@ -0,0 +1,506 @@
+use ndarray::{Array, ArrayBase, ArrayView1, Axis, Data, Dimension, RemoveAxis, Zip};
+
+use crate::dtype_strategy::scalar_is_better;
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+use crate::NaNArgMinMax;
+use crate::{ArgMinMax, MinMaxResult};
+
+/// Trait for finding the minimum and maximum values in a multi-dimensional array. For
+/// floats, NaNs are ignored.
+///
+/// This trait is implemented for [`ndarray::ArrayBase`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html)
+/// of any dimension (when the `ndarray` feature is enabled).
+///
+pub trait ArgMinMaxNd {
+    /// The dimension of the arrays that are returned by the axis-wise methods.
+    type Smaller: Dimension;
+    /// The type of the (multi-dimensional) index of a value in the array.
+    type Pattern;
+
+    /// Get the index of the minimum and maximum values along the given axis - similar
+    /// to numpy its `argmin(axis=...)` and `argmax(axis=...)`.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// A tuple of arrays `(min_indices, max_indices)` that contain the index (along the
+    /// axis) of the minimum and maximum value of each lane. The arrays have the shape
+    /// of the input array with the axis removed.
+    ///
+    /// # Panics
+    /// When the axis is out of bounds or when the length of the axis is 0.
+    ///
+    fn argminmax_axis(
+        &self,
+        axis: Axis,
+    ) -> (Array<usize, Self::Smaller>, Array<usize, Self::Smaller>);
+
+    /// Get the index of the minimum value along the given axis.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// An array that contains the index (along the axis) of the minimum value of each
+    /// lane.
+    ///
+    /// # Panics
+    /// When the axis is out of bounds or when the length of the axis is 0.
+    ///
+    fn argmin_axis(&self, axis: Axis) -> Array<usize, Self::Smaller>;
+
+    /// Get the index of the maximum value along the given axis.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// An array that contains the index (along the axis) of the maximum value of each
+    /// lane.
+    ///
+    /// # Panics
+    /// When the axis is out of bounds or when the length of the axis is 0.
+    ///
+    fn argmax_axis(&self, axis: Axis) -> Array<usize, Self::Smaller>;
+
+    /// Get the (multi-dimensional) index of the global minimum and maximum values in
+    /// the array.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    /// For any layout, the first occurrence in logical (row-major) order is returned.
+    /// When the array has a standard (row-major) or Fortran (column-major) layout, the
+    /// SIMD kernel runs over the underlying memory.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the array
+    /// `(min_index, max_index)`, e.g., `((row, col), (row, col))` for a 2D array.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn argminmax_nd(&self) -> (Self::Pattern, Self::Pattern);
+
+    /// Get the (multi-dimensional) index of the global minimum value in the array.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    /// See [`argminmax_nd`](Self::argminmax_nd) for the layout handling.
+    ///
+    /// # Returns
+    /// The index of the minimum value in the array.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn argmin_nd(&self) -> Self::Pattern;
+
+    /// Get the (multi-dimensional) index of the global maximum value in the array.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    /// See [`argminmax_nd`](Self::argminmax_nd) for the layout handling.
+    ///
+    /// # Returns
+    /// The index of the maximum value in the array.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn argmax_nd(&self) -> Self::Pattern;
+}
+
+/// Trait for finding the minimum and maximum values in a multi-dimensional array. For
+/// floats, NaNs are propagated - index of the first NaN is returned.
+///
+/// This trait is implemented for [`ndarray::ArrayBase`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html)
+/// of floats of any dimension (when the `ndarray` feature is enabled).
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub trait NaNArgMinMaxNd {
+    /// The dimension of the arrays that are returned by the axis-wise methods.
+    type Smaller: Dimension;
+    /// The type of the (multi-dimensional) index of a value in the array.
+    type Pattern;
+
+    /// Get the index of the minimum and maximum values along the given axis.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN (of each
+    /// lane) is returned.
+    ///
+    /// # Returns
+    /// A tuple of arrays `(min_indices, max_indices)` that contain the index (along the
+    /// axis) of the minimum and maximum value of each lane. The arrays have the shape
+    /// of the input array with the axis removed.
+    ///
+    /// # Panics
+    /// When the axis is out of bounds or when the length of the axis is 0.
+    ///
+    fn nanargminmax_axis(
+        &self,
+        axis: Axis,
+    ) -> (Array<usize, Self::Smaller>, Array<usize, Self::Smaller>);
+
+    /// Get the index of the minimum value along the given axis.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN (of each
+    /// lane) is returned.
+    ///
+    /// # Returns
+    /// An array that contains the index (along the axis) of the minimum value of each
+    /// lane.
+    ///
+    /// # Panics
+    /// When the axis is out of bounds or when the length of the axis is 0.
+    ///
+    fn nanargmin_axis(&self, axis: Axis) -> Array<usize, Self::Smaller>;
+
+    /// Get the index of the maximum value along the given axis.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN (of each
+    /// lane) is returned.
+    ///
+    /// # Returns
+    /// An array that contains the index (along the axis) of the maximum value of each
+    /// lane.
+    ///
+    /// # Panics
+    /// When the axis is out of bounds or when the length of the axis is 0.
+    ///
+    fn nanargmax_axis(&self, axis: Axis) -> Array<usize, Self::Smaller>;
+
+    /// Get the (multi-dimensional) index of the global minimum and maximum values in
+    /// the array.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    /// For any layout, the first occurrence in logical (row-major) order is returned.
+    /// When the array has a standard (row-major) or Fortran (column-major) layout, the
+    /// SIMD kernel runs over the underlying memory.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the array
+    /// `(min_index, max_index)`, e.g., `((row, col), (row, col))` for a 2D array.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn nanargminmax_nd(&self) -> (Self::Pattern, Self::Pattern);
+
+    /// Get the (multi-dimensional) index of the global minimum value in the array.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    /// See [`nanargminmax_nd`](Self::nanargminmax_nd) for the layout handling.
+    ///
+    /// # Returns
+    /// The index of the minimum value in the array.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn nanargmin_nd(&self) -> Self::Pattern;
+
+    /// Get the (multi-dimensional) index of the global maximum value in the array.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    /// See [`nanargminmax_nd`](Self::nanargminmax_nd) for the layout handling.
+    ///
+    /// # Returns
+    /// The index of the maximum value in the array.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn nanargmax_nd(&self) -> Self::Pattern;
+}
+
+/// Split an array of `(min_index, max_index)` tuples into two arrays.
+#[inline(always)]
+fn _split_indices<D: Dimension>(
+    indices: Array<(usize, usize), D>,
+) -> (Array<usize, D>, Array<usize, D>) {
+    (
+        indices.map(|&(min_index, _)| min_index),
+        indices.map(|&(_, max_index)| max_index),
+    )
+}
+
+/// Unravel a flat index into the (multi-dimensional) index of an array with the given
+/// shape - in row-major order if `c_order`, otherwise in column-major order.
+#[inline(always)]
+fn _unravel_index<D: Dimension>(mut flat_index: usize, shape: &D, c_order: bool) -> D {
+    let mut index = shape.clone();
+    let ndim = shape.ndim();
+    for i in 0..ndim {
+        let axis = if c_order { ndim - 1 - i } else { i };
+        index[axis] = flat_index % shape[axis];
+        flat_index /= shape[axis];
+    }
+    index
+}
+
+/// Ravel a (multi-dimensional) index into the flat index of an array with the given
+/// shape in row-major order.
+#[inline(always)]
+fn _ravel_index<D: Dimension>(index: &D, shape: &D) -> usize {
+    (0..shape.ndim()).fold(0, |flat_index, axis| flat_index * shape[axis] + index[axis])
+}
+
+/// Whether the lanes along the axis are strided in memory, in which case walking the
+/// (contiguous) rows is cheaper than gathering each lane.
+#[inline(always)]
+fn _strided_lanes<S: Data, D: Dimension>(arr: &ArrayBase<S, D>, axis: Axis) -> bool {
+    arr.len_of(axis) > 1 && arr.stride_of(axis).unsigned_abs() != 1
+}
+
+/// Get the index of the best value along the axis by walking the rows (subviews along
+/// the axis) and updating a running per-column accumulator.
+fn _argbest_axis_rows<S, D, const RETURN_NAN: bool>(
+    arr: &ArrayBase<S, D>,
+    axis: Axis,
+    better: fn(&S::Elem, &S::Elem) -> bool,
+) -> Array<usize, D::Smaller>
+where
+    S: Data,
+    S::Elem: Copy + PartialOrd,
+    D: RemoveAxis,
+{
+    let first = arr.index_axis(axis, 0);
+    let mut values = first.to_owned();
+    let mut indices = Array::zeros(first.raw_dim());
+    for (i, row) in arr.axis_iter(axis).enumerate().skip(1) {
+        Zip::from(&mut values)
+            .and(&mut indices)
+            .and(&row)
+            .for_each(|best, index, &value| {
+                if scalar_is_better::<_, RETURN_NAN>(value, *best, better) {
+                    *best = value;
+                    *index = i;
+                }
+            });
+    }
+    indices
+}
+
+/// Get the index of the minimum and maximum values along the axis by walking the rows
+/// (subviews along the axis) and updating running per-column accumulators.
+fn _argminmax_axis_rows<S, D, const RETURN_NAN: bool>(
+    arr: &ArrayBase<S, D>,
+    axis: Axis,
+) -> (Array<usize, D::Smaller>, Array<usize, D::Smaller>)
+where
+    S: Data,
+    S::Elem: Copy + PartialOrd,
+    D: RemoveAxis,
+{
+    let first = arr.index_axis(axis, 0);
+    let mut min_values = first.to_owned();
+    let mut max_values = first.to_owned();
+    let mut min_indices = Array::zeros(first.raw_dim());
+    let mut max_indices = Array::zeros(first.raw_dim());
+    for (i, row) in arr.axis_iter(axis).enumerate().skip(1) {
+        Zip::from(&mut min_values)
+            .and(&mut min_indices)
+            .and(&mut max_values)
+            .and(&mut max_indices)
+            .and(&row)
+            .for_each(|min, min_index, max, max_index, &value| {
+                if scalar_is_better::<_, RETURN_NAN>(value, *min, PartialOrd::lt) {
+                    *min = value;
+                    *min_index = i;
+                }
+                if scalar_is_better::<_, RETURN_NAN>(value, *max, PartialOrd::gt) {
+                    *max = value;
+                    *max_index = i;
+                }
+            });
+    }
+    (min_indices, max_indices)
+}
+
+/// Get the row-major flat index of the first element (in logical order) that equals
+/// `value` (or that is NaN when `value` is NaN). The search stops at `flat_index`, the
+/// row-major flat index of a known occurrence of `value`.
+#[allow(clippy::eq_op)]
+fn _first_logical_occurrence<S, D>(
+    arr: &ArrayBase<S, D>,
+    value: S::Elem,
+    flat_index: usize,
+) -> usize
+where
+    S: Data,
+    S::Elem: Copy + PartialOrd,
+    D: Dimension,
+{
+    let value_is_nan = value != value;
+    arr.iter()
+        .take(flat_index)
+        .position(|&v| if value_is_nan { v != v } else { v == value })
+        .unwrap_or(flat_index)
+}
+
+/// Get the (multi-dimensional) index of the global minimum and maximum values - ties
+/// (and NaNs) are resolved to the first occurrence in logical (row-major) order.
+///
+/// The `lane_fn` computes the (min_index, max_index) of a 1D view:
+/// - standard layout -> `lane_fn` is called on the underlying memory and the flat
+///   index is unraveled
+/// - Fortran layout -> `lane_fn` is called on the underlying memory, the flat index
+///   is unraveled (in column-major order) and the first occurrence in row-major order
+///   of the found value is looked up
+/// - any other layout -> `lane_fn` is called on the lanes along the last axis and the
+///   results are merged in row-major order (where earlier lanes win ties)
+///
+fn _argminmax_nd<S, D>(
+    arr: &ArrayBase<S, D>,
+    lane_fn: impl Fn(ArrayView1<S::Elem>) -> (usize, usize),
+) -> (D::Pattern, D::Pattern)
+where
+    S: Data,
+    S::Elem: Copy + PartialOrd,
+    D: RemoveAxis,
+{
+    assert!(!arr.is_empty());
+    let shape = arr.raw_dim();
+    let (min_index, max_index) = if let Some(slice) = arr.as_slice() {
+        lane_fn(ArrayView1::from(slice))
+    } else if let Some(slice) = arr.t().to_slice() {
+        let (min_index, max_index) = lane_fn(ArrayView1::from(slice));
+        let c_index = |i: usize| _ravel_index(&_unravel_index(i, &shape, false), &shape);
+        (
+            _first_logical_occurrence(arr, slice[min_index], c_index(min_index)),
+            _first_logical_occurrence(arr, slice[max_index], c_index(max_index)),
+        )
+    } else {
+        let last_axis = Axis(arr.ndim() - 1);
+        let lane_len = arr.len_of(last_axis);
+        let mut result: Option<MinMaxResult<S::Elem>> = None;
+        for (i, lane) in arr.lanes(last_axis).into_iter().enumerate() {
+            let (min_index, max_index) = lane_fn(lane);
+            let lane_result = MinMaxResult {
+                min_index,
+                min_value: lane[min_index],
+                max_index,
+                max_value: lane[max_index],
+            }
+            .with_offset(i * lane_len);
+            result = Some(match result {
+                Some(result) => result.merge(lane_result, |pair| lane_fn(ArrayView1::from(pair))),
+                None => lane_result,
+            });
+        }
+        result.unwrap().indices()
+    };
+    (
+        _unravel_index(min_index, &shape, true).into_pattern(),
+        _unravel_index(max_index, &shape, true).into_pattern(),
+    )
+}
+
+// Contiguous lanes (1D views along the axis) are passed to the SIMD kernels as a slice
+// (see ndarray_impl), strided lanes (e.g., axis 0 of a row-major array) are reduced by
+// walking the rows and updating running per-column accumulators
+impl<S, D> ArgMinMaxNd for ArrayBase<S, D>
+where
+    S: Data,
+    S::Elem: Copy + PartialOrd,
+    D: RemoveAxis,
+    for<'a> &'a [S::Elem]: ArgMinMax,
+{
+    type Smaller = D::Smaller;
+    type Pattern = D::Pattern;
+
+    fn argminmax_axis(&self, axis: Axis) -> (Array<usize, D::Smaller>, Array<usize, D::Smaller>) {
+        if _strided_lanes(self, axis) {
+            return _argminmax_axis_rows::<_, _, false>(self, axis);
+        }
+        _split_indices(Zip::from(self.lanes(axis)).map_collect(|lane| lane.argminmax()))
+    }
+
+    fn argmin_axis(&self, axis: Axis) -> Array<usize, D::Smaller> {
+        if _strided_lanes(self, axis) {
+            return _argbest_axis_rows::<_, _, false>(self, axis, PartialOrd::lt);
+        }
+        Zip::from(self.lanes(axis)).map_collect(|lane| lane.argmin())
+    }
+
+    fn argmax_axis(&self, axis: Axis) -> Array<usize, D::Smaller> {
+        if _strided_lanes(self, axis) {
+            return _argbest_axis_rows::<_, _, false>(self, axis, PartialOrd::gt);
+        }
+        Zip::from(self.lanes(axis)).map_collect(|lane| lane.argmax())
+    }
+
+    fn argminmax_nd(&self) -> (D::Pattern, D::Pattern) {
+        _argminmax_nd(self, |lane| lane.argminmax())
+    }
+
+    fn argmin_nd(&self) -> D::Pattern {
+        _argminmax_nd(self, |lane| {
+            let index = lane.argmin();
+            (index, index)
+        })
+        .0
+    }
+
+    fn argmax_nd(&self) -> D::Pattern {
+        _argminmax_nd(self, |lane| {
+            let index = lane.argmax();
+            (index, index)
+        })
+        .1
+    }
+}
+
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+impl<S, D> NaNArgMinMaxNd for ArrayBase<S, D>
+where
+    S: Data,
+    S::Elem: Copy + PartialOrd,
+    D: RemoveAxis,
+    for<'a> &'a [S::Elem]: NaNArgMinMax,
+{
+    type Smaller = D::Smaller;
+    type Pattern = D::Pattern;
+
+    fn nanargminmax_axis(
+        &self,
+        axis: Axis,
+    ) -> (Array<usize, D::Smaller>, Array<usize, D::Smaller>) {
+        if _strided_lanes(self, axis) {
+            return _argminmax_axis_rows::<_, _, true>(self, axis);
+        }
+        _split_indices(Zip::from(self.lanes(axis)).map_collect(|lane| lane.nanargminmax()))
+    }
+
+    fn nanargmin_axis(&self, axis: Axis) -> Array<usize, D::Smaller> {
+        if _strided_lanes(self, axis) {
+            return _argbest_axis_rows::<_, _, true>(self, axis, PartialOrd::lt);
+        }
+        Zip::from(self.lanes(axis)).map_collect(|lane| lane.nanargmin())
+    }
+
+    fn nanargmax_axis(&self, axis: Axis) -> Array<usize, D::Smaller> {
+        if _strided_lanes(self, axis) {
+            return _argbest_axis_rows::<_, _, true>(self, axis, PartialOrd::gt);
+        }
+        Zip::from(self.lanes(axis)).map_collect(|lane| lane.nanargmax())
+    }
+
+    fn nanargminmax_nd(&self) -> (D::Pattern, D::Pattern) {
+        _argminmax_nd(self, |lane| lane.nanargminmax())
+    }
+
+    fn nanargmin_nd(&self) -> D::Pattern {
+        _argminmax_nd(self, |lane| {
+            let index = lane.nanargmin();
+            (index, index)
+        })
+        .0
+    }
+
+    fn nanargmax_nd(&self) -> D::Pattern {
+        _argminmax_nd(self, |lane| {
+            let index = lane.nanargmax();
+            (index, index)
+        })
+        .1
+    }
+}
@@ -0,0 +1,149 @@
+use crate::dtype_strategy::scalar_is_better;
+use crate::MinMaxResult;
+
+/// Trait for finding the minimum and maximum values in an array with a validity
+/// (null) bitmap. Null slots are skipped. For floats, NaNs are ignored.
+///
+/// This trait is implemented for apache [`arrow::PrimitiveArray`](https://docs.rs/arrow/latest/arrow/array/struct.PrimitiveArray.html)
+/// and [`arrow2::PrimitiveArray`](https://docs.rs/arrow2/latest/arrow2/array/struct.PrimitiveArray.html)
+/// (when the `arrow` or `arrow2` feature is enabled).
+///
+/// Note that the [`ArgMinMax`](crate::ArgMinMax) trait operates on the (physical)
+/// values of these arrays and thus does not take the validity bitmap into account.
+///
+pub trait NullableArgMinMax {
+    /// Get the index of the minimum and maximum values in the array, skipping the null
+    /// slots.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// `Some((min_index, max_index))`, or `None` when the array is empty or all slots
+    /// are null.
+    ///
+    fn argminmax_nullable(&self) -> Option<(usize, usize)>;
+
+    /// Get the index of the minimum value in the array, skipping the null slots.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// `Some(min_index)`, or `None` when the array is empty or all slots are null.
+    ///
+    fn argmin_nullable(&self) -> Option<usize>;
+
+    /// Get the index of the maximum value in the array, skipping the null slots.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// `Some(max_index)`, or `None` when the array is empty or all slots are null.
+    ///
+    fn argmax_nullable(&self) -> Option<usize>;
+}
+
+/// Trait for finding the minimum and maximum values in an array with a validity
+/// (null) bitmap. Null slots are skipped. For floats, NaNs are propagated - index of
+/// the first (non-null) NaN is returned.
+///
+/// This trait is implemented for apache [`arrow::PrimitiveArray`](https://docs.rs/arrow/latest/arrow/array/struct.PrimitiveArray.html)
+/// and [`arrow2::PrimitiveArray`](https://docs.rs/arrow2/latest/arrow2/array/struct.PrimitiveArray.html)
+/// of floats (when the `arrow` or `arrow2` feature is enabled).
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub trait NaNNullableArgMinMax {
+    /// Get the index of the minimum and maximum values in the array, skipping the null
+    /// slots.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    ///
+    /// # Returns
+    /// `Some((min_index, max_index))`, or `None` when the array is empty or all slots
+    /// are null.
+    ///
+    fn nanargminmax_nullable(&self) -> Option<(usize, usize)>;
+
+    /// Get the index of the minimum value in the array, skipping the null slots.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    ///
+    /// # Returns
+    /// `Some(min_index)`, or `None` when the array is empty or all slots are null.
+    ///
+    fn nanargmin_nullable(&self) -> Option<usize>;
+
+    /// Get the index of the maximum value in the array, skipping the null slots.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    ///
+    /// # Returns
+    /// `Some(max_index)`, or `None` when the array is empty or all slots are null.
+    ///
+    fn nanargmax_nullable(&self) -> Option<usize>;
+}
+
+/// The minimum average length of the valid runs for which the (SIMD) kernel is called
+/// on each run - for shorter runs, the per-run overhead of the kernel dominates.
+const MIN_AVG_RUN_LEN: usize = 64;
+
+/// Get the MinMaxResult of the valid values in the array.
+///
+/// The `valid_runs` are the contiguous runs `(start, end)` of valid (non-null) slots,
+/// as obtained from the validity bitmap:
+/// - long runs (on average) -> the `slice_fn` (SIMD) kernel is called on each run
+///   (without copying the values) and the results of the runs are merged (where
+///   earlier runs win ties)
+/// - short runs (on average) -> a single scalar pass over the valid slots is done,
+///   with the NaN handling of the strategy (see `scalar_is_better`)
+///
+/// # Returns
+/// `None` when there are no valid values.
+///
+#[inline(always)]
+pub(crate) fn argminmax_valid_runs<T: Copy + PartialOrd, const RETURN_NAN: bool>(
+    values: &[T],
+    valid_runs: impl Iterator<Item = (usize, usize)>,
+    slice_fn: impl Fn(&[T]) -> MinMaxResult<T>,
+) -> Option<MinMaxResult<T>> {
+    let valid_runs: Vec<(usize, usize)> = valid_runs.filter(|(start, end)| start < end).collect();
+    let nb_valid: usize = valid_runs.iter().map(|(start, end)| end - start).sum();
+    if nb_valid < MIN_AVG_RUN_LEN * valid_runs.len() {
+        return _argminmax_valid_runs_scalar::<T, RETURN_NAN>(values, &valid_runs);
+    }
+    let mut result: Option<MinMaxResult<T>> = None;
+    for &(start, end) in valid_runs.iter() {
+        let run_result = slice_fn(&values[start..end]).with_offset(start);
+        result = Some(match result {
+            Some(result) => result.merge(run_result, |pair| slice_fn(pair).indices()),
+            None => run_result,
+        });
+    }
+    result
+}
+
+/// Get the MinMaxResult of the valid values in the array with a single scalar pass
+/// over the (non-empty) `valid_runs`.
+#[inline(always)]
+fn _argminmax_valid_runs_scalar<T: Copy + PartialOrd, const RETURN_NAN: bool>(
+    values: &[T],
+    valid_runs: &[(usize, usize)],
+) -> Option<MinMaxResult<T>> {
+    let mut indices = valid_runs.iter().flat_map(|&(start, end)| start..end);
+    let first = indices.next()?;
+    let mut result = MinMaxResult::from((first, values[first], first, values[first]));
+    for index in indices {
+        let value = values[index];
+        if scalar_is_better::<T, RETURN_NAN>(value, result.min_value, PartialOrd::lt) {
+            result.min_index = index;
+            result.min_value = value;
+        }
+        if scalar_is_better::<T, RETURN_NAN>(value, result.max_value, PartialOrd::gt) {
+            result.max_index = index;
+            result.max_value = value;
+        }
+    }
+    Some(result)
+}
@@ -0,0 +1,120 @@
+use crate::MinMaxResult;
+use rayon::prelude::*;
+
+/// The size (in bytes) of the chunks that are processed in parallel.
+///
+/// Large enough to amortize the scheduling overhead, small enough for a chunk to fit
+/// in the L2 cache of a core. As this is a power of two, the chunk length is a
+/// multiple of the SIMD lane size for all data types.
+const PAR_CHUNK_SIZE_BYTES: usize = 1 << 18; // 256 KiB
+
+/// Trait for finding the minimum and maximum values in an array in parallel (using
+/// [`rayon`](https://docs.rs/rayon)). For floats, NaNs are ignored.
+///
+/// This trait is implemented for slices (or other array-like types) of the same data
+/// types as [`ArgMinMax`](crate::ArgMinMax) (when the `rayon` feature is enabled).
+/// The array is split into cache-friendly chunks, the (SIMD) kernels run on each chunk
+/// and the partial results are merged. The result is the same as that of the
+/// corresponding [`ArgMinMax`](crate::ArgMinMax) method.
+///
+pub trait ParArgMinMax {
+    /// Get the index of the minimum and maximum values in the array (in parallel).
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the array
+    /// `(min_index, max_index)`.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn par_argminmax(&self) -> (usize, usize);
+
+    /// Get the index of the minimum value in the array (in parallel).
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn par_argmin(&self) -> usize;
+
+    /// Get the index of the maximum value in the array (in parallel).
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn par_argmax(&self) -> usize;
+}
+
+/// Trait for finding the minimum and maximum values in an array in parallel (using
+/// [`rayon`](https://docs.rs/rayon)). For floats, NaNs are propagated - index of the
+/// first NaN is returned.
+///
+/// See [`ParArgMinMax`] for the supported types.
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub trait NaNParArgMinMax {
+    /// Get the index of the minimum and maximum values in the array (in parallel).
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the array
+    /// `(min_index, max_index)`.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn par_nanargminmax(&self) -> (usize, usize);
+
+    /// Get the index of the minimum value in the array (in parallel).
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn par_nanargmin(&self) -> usize;
+
+    /// Get the index of the maximum value in the array (in parallel).
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN is
+    /// returned.
+    ///
+    /// # Panics
+    /// When the array is empty.
+    ///
+    fn par_nanargmax(&self) -> usize;
+}
+
+/// Get the (min_index, max_index) of the slice in parallel.
+///
+/// The slice is split into chunks of `PAR_CHUNK_SIZE_BYTES`, `slice_fn` returns the
+/// MinMaxResult of a chunk. The partial results are merged in order (rayon
+/// preserves the order of an indexed iterator in `reduce`), using `slice_fn` on pairs
+/// of values - this way the NaN handling and tie policy are the same as those of
+/// `slice_fn` on the whole slice.
+///
+/// Slices that fit in a single chunk are passed to `slice_fn` directly.
+///
+pub(crate) fn par_argminmax_generic<T: Copy + Send + Sync>(
+    data: &[T],
+    slice_fn: impl Fn(&[T]) -> MinMaxResult<T> + Sync,
+) -> (usize, usize) {
+    let chunk_len = std::cmp::max(PAR_CHUNK_SIZE_BYTES / std::mem::size_of::<T>(), 1);
+    if data.len() <= chunk_len {
+        return slice_fn(data).indices();
+    }
+    data.par_chunks(chunk_len)
+        .enumerate()
+        .map(|(i, chunk)| slice_fn(chunk).with_offset(i * chunk_len))
+        .reduce_with(|earlier, later| earlier.merge(later, |pair| slice_fn(pair).indices()))
+        .unwrap() // the slice is not empty
+        .indices()
+}
@@ -0,0 +1,130 @@
+use crate::{ArgMinMaxReadError, MinMaxResult};
+use std::io::{ErrorKind, Read};
+
+/// Trait for decoding a value from its (fixed-width) little-endian byte representation.
+///
+/// This trait is implemented for the same data types as [`ArgMinMax`](crate::ArgMinMax)
+/// (`isize` and `usize` have the width of the target platform).
+///
+pub trait FromLeBytes: Sized {
+    /// The number of bytes of a value.
+    const NB_BYTES: usize;
+
+    /// Decode a value from exactly `NB_BYTES` little-endian bytes.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+}
+
+/// Get the index and value of the minimum and maximum values in a stream of raw
+/// little-endian values (e.g., a sample file that is larger than the memory).
+///
+/// The stream is read in blocks of `buf_size` values, which are decoded into an
+/// (aligned) buffer that is processed by the (SIMD) kernels. The result is the same as
+/// that of [`ArgMinMax::argminmax_with_values`](crate::ArgMinMax::argminmax_with_values)
+/// on all the values of the stream - the indices are the positions of the values in
+/// the stream.
+///
+/// When dealing with floats, NaNs are ignored.
+///
+/// # Errors
+/// - [`ArgMinMaxReadError::Io`] when reading the stream fails.
+/// - [`ArgMinMaxReadError::TrailingBytes`] when the stream ends with a partial value.
+/// - [`ArgMinMaxReadError::EmptyInput`] when the stream is empty.
+///
+/// # Panics
+/// When `buf_size` is 0.
+///
+pub fn argminmax_reader<T, R: Read>(
+    reader: R,
+    buf_size: usize,
+) -> Result<MinMaxResult<T>, ArgMinMaxReadError>
+where
+    T: Copy + FromLeBytes,
+    for<'a> &'a [T]: crate::ArgMinMax<Item = T>,
+{
+    use crate::ArgMinMax;
+    reader_argminmax_generic(reader, buf_size, |s: &[T]| s.argminmax_with_values())
+}
+
+/// Get the index and value of the minimum and maximum values in a stream of raw
+/// little-endian values (e.g., a sample file that is larger than the memory).
+///
+/// See [`argminmax_reader`] for the blocks and errors. The result is the same as that
+/// of [`NaNArgMinMax::nanargminmax_with_values`](crate::NaNArgMinMax::nanargminmax_with_values)
+/// on all the values of the stream.
+///
+/// When dealing with floats, NaNs are propagated - index of the first NaN is returned.
+///
+/// # Panics
+/// When `buf_size` is 0.
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub fn nanargminmax_reader<T, R: Read>(
+    reader: R,
+    buf_size: usize,
+) -> Result<MinMaxResult<T>, ArgMinMaxReadError>
+where
+    T: Copy + FromLeBytes,
+    for<'a> &'a [T]: crate::NaNArgMinMax<Item = T>,
+{
+    use crate::NaNArgMinMax;
+    reader_argminmax_generic(reader, buf_size, |s: &[T]| s.nanargminmax_with_values())
+}
+
+/// Get the index and value of the minimum and maximum values in the stream.
+///
+/// The stream is read in blocks of `buf_size` values, `slice_fn` returns the
+/// MinMaxResult of a (decoded) block. The partial results are merged in
+/// order, using `slice_fn` on pairs of values - this way the NaN handling and tie
+/// policy are the same as those of `slice_fn` on all the values.
+///
+/// # Panics
+/// When `buf_size` is 0.
+///
+fn reader_argminmax_generic<T: Copy + FromLeBytes, R: Read>(
+    mut reader: R,
+    buf_size: usize,
+    slice_fn: impl Fn(&[T]) -> MinMaxResult<T>,
+) -> Result<MinMaxResult<T>, ArgMinMaxReadError> {
+    assert!(buf_size > 0, "buf_size must be larger than 0");
+    let mut bytes = vec![0u8; buf_size * T::NB_BYTES];
+    let mut values: Vec<T> = Vec::with_capacity(buf_size);
+    let mut offset = 0;
+    let mut result: Option<MinMaxResult<T>> = None;
+    loop {
+        let len = _read_block(&mut reader, &mut bytes)?;
+        let trailing = len % T::NB_BYTES;
+        if trailing != 0 {
+            // A full block is a multiple of the value size -> this is the end of the stream
+            return Err(ArgMinMaxReadError::TrailingBytes(trailing));
+        }
+        values.clear();
+        values.extend(bytes[..len].chunks_exact(T::NB_BYTES).map(T::from_le_slice));
+        if !values.is_empty() {
+            let block = slice_fn(&values).with_offset(offset);
+            result = Some(match result {
+                Some(result) => result.merge(block, |pair| slice_fn(pair).indices()),
+                None => block,
+            });
+            offset += values.len();
+        }
+        if len < bytes.len() {
+            break; // the end of the stream
+        }
+    }
+    result.ok_or(ArgMinMaxReadError::EmptyInput)
+}
+
+/// Read until the buffer is full or the end of the stream is reached - returns the
+/// number of bytes that were read.
+fn _read_block(reader: &mut impl Read, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(len)
+}
@@ -0,0 +1,94 @@
+/// The index and value of the minimum and maximum values in an array.
+///
+/// This struct is returned by the `*_with_values` methods of the
+/// [`ArgMinMax`](crate::ArgMinMax) and [`NaNArgMinMax`](crate::NaNArgMinMax) traits.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinMaxResult<T> {
+    /// The index of the minimum value
+    pub min_index: usize,
+    /// The minimum value
+    pub min_value: T,
+    /// The index of the maximum value
+    pub max_index: usize,
+    /// The maximum value
+    pub max_value: T,
+}
+
+impl<T> From<(usize, T, usize, T)> for MinMaxResult<T> {
+    /// Create a MinMaxResult from a `(min_index, min_value, max_index, max_value)`
+    /// tuple (as returned by the SIMD and scalar implementations)
+    #[inline(always)]
+    fn from((min_index, min_value, max_index, max_value): (usize, T, usize, T)) -> Self {
+        MinMaxResult {
+            min_index,
+            min_value,
+            max_index,
+            max_value,
+        }
+    }
+}
+
+impl<T: Copy> MinMaxResult<T> {
+    /// Get the `(min_value, max_value)` tuple
+    #[inline(always)]
+    pub fn values(&self) -> (T, T) {
+        (self.min_value, self.max_value)
+    }
+
+    /// Get the `(min_index, max_index)` tuple
+    #[inline(always)]
+    pub fn indices(&self) -> (usize, usize) {
+        (self.min_index, self.max_index)
+    }
+}
+
+impl<T: Copy> MinMaxResult<T> {
+    /// Create a MinMaxResult with both the min and max at the given index (for the
+    /// argmin / argmax only implementations, which do not return the value)
+    #[cfg(any(feature = "rayon", feature = "arrow", feature = "arrow2"))]
+    #[inline(always)]
+    pub(crate) fn from_index(data: &[T], index: usize) -> Self {
+        MinMaxResult {
+            min_index: index,
+            min_value: data[index],
+            max_index: index,
+            max_value: data[index],
+        }
+    }
+
+    /// Shift the indices by the given offset (i.e., the start of the part of the array
+    /// this result was computed on)
+    #[inline(always)]
+    pub(crate) fn with_offset(self, offset: usize) -> Self {
+        MinMaxResult {
+            min_index: self.min_index + offset,
+            max_index: self.max_index + offset,
+            ..self
+        }
+    }
+
+    /// Merge this result with the result of a later part of the array.
+    ///
+    /// The `arg_minmax` function returns the (min_index, max_index) of a slice of two
+    /// values - this way the NaN handling and tie policy of the merge are the same as
+    /// those of the implementation that computed the results.
+    ///
+    #[inline(always)]
+    pub(crate) fn merge(self, later: Self, arg_minmax: impl Fn(&[T]) -> (usize, usize)) -> Self {
+        let (min_index, min_value) = match arg_minmax(&[self.min_value, later.min_value]).0 {
+            0 => (self.min_index, self.min_value),
+            _ => (later.min_index, later.min_value),
+        };
+        let (max_index, max_value) = match arg_minmax(&[self.max_value, later.max_value]).1 {
+            0 => (self.max_index, self.max_value),
+            _ => (later.max_index, later.max_value),
+        };
+        MinMaxResult {
+            min_index,
+            min_value,
+            max_index,
+            max_value,
+        }
+    }
+}
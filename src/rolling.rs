@@ -0,0 +1,168 @@
+use crate::dtype_strategy::scalar_is_better;
+
+/// Trait for finding the minimum and maximum values in each (sliding) window of an
+/// array. For floats, NaNs are ignored.
+///
+/// This trait is implemented for slices and `Vec`s of the same data types as
+/// [`ArgMinMax`](crate::ArgMinMax). The windows start at `0, step, 2 * step, ...` and
+/// only complete windows (of `window` elements) are considered. The returned indices
+/// are indices in the array (not in the window).
+///
+/// The result for each window is the same as that of the corresponding
+/// [`ArgMinMax`](crate::ArgMinMax) method on the window, but the (amortized) cost is
+/// O(n) instead of O(n * window) - overlapping windows are handled with block-wise
+/// prefix and suffix scans (van Herk/Gil-Werman), non-overlapping windows
+/// (`step >= window`) with the (SIMD) kernels.
+///
+pub trait RollingArgMinMax {
+    /// Get the index of the minimum and maximum values in each window.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// A tuple of the indices of the minimum and maximum values of the windows
+    /// `(min_indices, max_indices)`. When the array is shorter than the window, both
+    /// are empty.
+    ///
+    /// # Caution
+    /// When a window contains *only* NaNs, the index of its first value is returned.
+    ///
+    /// # Panics
+    /// When `window` or `step` is 0.
+    ///
+    fn rolling_argminmax(&self, window: usize, step: usize) -> (Vec<usize>, Vec<usize>);
+
+    /// Get the index of the minimum value in each window.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Panics
+    /// When `window` or `step` is 0.
+    ///
+    fn rolling_argmin(&self, window: usize, step: usize) -> Vec<usize>;
+
+    /// Get the index of the maximum value in each window.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Panics
+    /// When `window` or `step` is 0.
+    ///
+    fn rolling_argmax(&self, window: usize, step: usize) -> Vec<usize>;
+}
+
+/// Trait for finding the minimum and maximum values in each (sliding) window of an
+/// array. For floats, NaNs are propagated - index of the first NaN in the window is
+/// returned.
+///
+/// See [`RollingArgMinMax`] for the supported types and the windows.
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub trait NaNRollingArgMinMax {
+    /// Get the index of the minimum and maximum values in each window.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN in the
+    /// window is returned.
+    ///
+    /// # Returns
+    /// A tuple of the indices of the minimum and maximum values of the windows
+    /// `(min_indices, max_indices)`. When the array is shorter than the window, both
+    /// are empty.
+    ///
+    /// # Panics
+    /// When `window` or `step` is 0.
+    ///
+    fn rolling_nanargminmax(&self, window: usize, step: usize) -> (Vec<usize>, Vec<usize>);
+
+    /// Get the index of the minimum value in each window.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN in the
+    /// window is returned.
+    ///
+    /// # Panics
+    /// When `window` or `step` is 0.
+    ///
+    fn rolling_nanargmin(&self, window: usize, step: usize) -> Vec<usize>;
+
+    /// Get the index of the maximum value in each window.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN in the
+    /// window is returned.
+    ///
+    /// # Panics
+    /// When `window` or `step` is 0.
+    ///
+    fn rolling_nanargmax(&self, window: usize, step: usize) -> Vec<usize>;
+}
+
+/// Get the index of the best (i.e., minimum or maximum) value in each window.
+///
+/// `arg_best` returns the index of the best value of a slice (e.g., argmin). It is
+/// used as is on non-overlapping windows. Overlapping windows use the van
+/// Herk/Gil-Werman algorithm: the data is split in blocks of `window` values and the
+/// index of the best value of each prefix and each suffix of a block is precomputed.
+/// A window spans the suffix of one block and the prefix of the next block, so its
+/// best value is the better of the two. The values are compared with `better` (`<`
+/// for the minimum, `>` for the maximum) and the NaN handling of the strategy (see
+/// `scalar_is_better`), and earlier values win ties - as in `arg_best`.
+///
+/// # Panics
+/// When `window` or `step` is 0.
+///
+pub(crate) fn rolling_arg_best_generic<T: Copy + PartialOrd, const RETURN_NAN: bool>(
+    data: &[T],
+    window: usize,
+    step: usize,
+    arg_best: impl Fn(&[T]) -> usize,
+    better: fn(&T, &T) -> bool,
+) -> Vec<usize> {
+    assert!(window > 0, "window must be larger than 0");
+    assert!(step > 0, "step must be larger than 0");
+    if data.len() < window {
+        return Vec::new();
+    }
+    let nb_windows = (data.len() - window) / step + 1;
+    let starts = (0..nb_windows).map(|i| i * step);
+
+    if step >= window {
+        // Non-overlapping windows -> run the (SIMD) kernel on each window
+        return starts
+            .map(|start| start + arg_best(&data[start..start + window]))
+            .collect();
+    }
+
+    // Only the data that is covered by the windows
+    let data = &data[..(nb_windows - 1) * step + window];
+    let is_better = |value: T, best: T| scalar_is_better::<T, RETURN_NAN>(value, best, better);
+    let mut prefix: Vec<usize> = vec![0; data.len()];
+    let mut suffix: Vec<usize> = vec![0; data.len()];
+    for block_start in (0..data.len()).step_by(window) {
+        let block_end = (block_start + window).min(data.len());
+        // Prefix scan -> a later value must be strictly better
+        let (mut best, mut best_value) = (block_start, data[block_start]);
+        for index in block_start..block_end {
+            if is_better(data[index], best_value) {
+                (best, best_value) = (index, data[index]);
+            }
+            prefix[index] = best;
+        }
+        // Suffix scan -> an earlier value wins ties
+        let (mut best, mut best_value) = (block_end - 1, data[block_end - 1]);
+        for index in (block_start..block_end).rev() {
+            if !is_better(best_value, data[index]) {
+                (best, best_value) = (index, data[index]);
+            }
+            suffix[index] = best;
+        }
+    }
+    starts
+        .map(|start| {
+            let (first, second) = (suffix[start], prefix[start + window - 1]);
+            if is_better(data[second], data[first]) {
+                second
+            } else {
+                first
+            }
+        })
+        .collect()
+}
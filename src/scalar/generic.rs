@@ -4,7 +4,7 @@ use num_traits::PrimInt;
 
 use super::super::dtype_strategy::Int;
 /// The DTypeStrategy for which we implement the ScalarArgMinMax trait
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 use super::super::dtype_strategy::{FloatIgnoreNaN, FloatReturnNaN};
 
 /// Helper trait to initialize the min and max values & check if we should return
@@ -47,6 +47,20 @@ pub trait ScalarArgMinMax<ScalarDType: Copy + PartialOrd> {
     ///
     fn argminmax(data: &[ScalarDType]) -> (usize, usize);
 
+    /// Get the index and value of the minimum and maximum values in the slice.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// A tuple of the index and value of the minimum and maximum values in the slice
+    /// `(min_index, min_value, max_index, max_value)`.
+    ///
+    fn argminmax_with_values(data: &[ScalarDType]) -> (usize, ScalarDType, usize, ScalarDType) {
+        let (min_index, max_index) = Self::argminmax(data);
+        (min_index, data[min_index], max_index, data[max_index])
+    }
+
     /// Get the index of the minimum value in the slice.
     ///
     /// # Arguments
@@ -66,6 +80,57 @@ pub trait ScalarArgMinMax<ScalarDType: Copy + PartialOrd> {
     /// The index of the maximum value in the slice.
     ///
     fn argmax(data: &[ScalarDType]) -> usize;
+
+    /// Get the index of the minimum and maximum values in the slice - returning the
+    /// index of the last occurrence on ties.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the slice
+    /// `(min_index, max_index)`.
+    ///
+    fn argminmax_last(data: &[ScalarDType]) -> (usize, usize);
+
+    /// Get the index and value of the minimum and maximum values in the slice -
+    /// returning the index of the last occurrence on ties.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// A tuple of the index and value of the minimum and maximum values in the slice
+    /// `(min_index, min_value, max_index, max_value)`.
+    ///
+    fn argminmax_last_with_values(
+        data: &[ScalarDType],
+    ) -> (usize, ScalarDType, usize, ScalarDType) {
+        let (min_index, max_index) = Self::argminmax_last(data);
+        (min_index, data[min_index], max_index, data[max_index])
+    }
+
+    /// Get the index of the minimum value in the slice - returning the index of the
+    /// last occurrence on ties.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// The index of the minimum value in the slice.
+    ///
+    fn argmin_last(data: &[ScalarDType]) -> usize;
+
+    /// Get the index of the maximum value in the slice - returning the index of the
+    /// last occurrence on ties.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// The index of the maximum value in the slice.
+    ///
+    fn argmax_last(data: &[ScalarDType]) -> usize;
 }
 
 /// Type that implements the [ScalarArgMinMax](crate::ScalarArgMinMax) trait.
@@ -179,6 +244,12 @@ macro_rules! impl_scalar {
             {
                 #[inline(always)]
                 fn argminmax(arr: &[$dtype]) -> (usize, usize) {
+                    let (low_index, _, high_index, _) = Self::argminmax_with_values(arr);
+                    (low_index, high_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_with_values(arr: &[$dtype]) -> (usize, $dtype, usize, $dtype) {
                     assert!(!arr.is_empty());
                     let mut low_index: usize = 0;
                     let mut high_index: usize = 0;
@@ -192,7 +263,7 @@ macro_rules! impl_scalar {
                         let v: $dtype = unsafe { *arr.get_unchecked(i) };
                         if <Self as SCALARInit<$dtype>>::_RETURN_AT_NAN && Self::_nan_check(v) {
                             // When _RETURN_AT_NAN is true and we encounter a NaN
-                            return (i, i); // -> return the index
+                            return (i, v, i, v); // -> return the index
                         }
                         if first_non_nan_update {
                             // If we allow the first non-nan update (only for FloatIgnoreNaN)
@@ -213,7 +284,7 @@ macro_rules! impl_scalar {
                             high_index = i;
                         }
                     }
-                    (low_index, high_index)
+                    (low_index, low, high_index, high)
                 }
 
                 #[inline(always)]
@@ -279,12 +350,128 @@ macro_rules! impl_scalar {
                     }
                     high_index
                 }
+
+                #[inline(always)]
+                fn argminmax_last(arr: &[$dtype]) -> (usize, usize) {
+                    let (low_index, _, high_index, _) = Self::argminmax_last_with_values(arr);
+                    (low_index, high_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_last_with_values(arr: &[$dtype]) -> (usize, $dtype, usize, $dtype) {
+                    assert!(!arr.is_empty());
+                    let mut low_index: usize = 0;
+                    let mut high_index: usize = 0;
+                    // It is remarkably faster to iterate over the index and use get_unchecked
+                    // than using .iter().enumerate() (with a fold).
+                    let start_value: $dtype = unsafe { *arr.get_unchecked(0) };
+                    let mut low: $dtype = Self::_init_min(start_value);
+                    let mut high: $dtype = Self::_init_max(start_value);
+                    let mut first_non_nan_update: bool = Self::_allow_first_non_nan_update(start_value);
+                    for i in 0..arr.len() {
+                        let v: $dtype = unsafe { *arr.get_unchecked(i) };
+                        if <Self as SCALARInit<$dtype>>::_RETURN_AT_NAN && Self::_nan_check(v) {
+                            // When _RETURN_AT_NAN is true and we encounter a NaN
+                            return (i, v, i, v); // -> return the index
+                        }
+                        if first_non_nan_update {
+                            // If we allow the first non-nan update (only for FloatIgnoreNaN)
+                            if !Self::_nan_check(v) {
+                                // Update the low and high
+                                low = v;
+                                low_index = i;
+                                high = v;
+                                high_index = i;
+                                // And disable the first_non_nan_update update
+                                first_non_nan_update = false;
+                            }
+                        } else {
+                            // Non-strict comparisons -> the last index is kept on ties
+                            // (no else if, as v can be equal to both low and high)
+                            if v <= low {
+                                low = v;
+                                low_index = i;
+                            }
+                            if v >= high {
+                                high = v;
+                                high_index = i;
+                            }
+                        }
+                    }
+                    (low_index, low, high_index, high)
+                }
+
+                #[inline(always)]
+                fn argmin_last(arr: &[$dtype]) -> usize {
+                    assert!(!arr.is_empty());
+                    let mut low_index: usize = 0;
+                    // It is remarkably faster to iterate over the index and use get_unchecked
+                    // than using .iter().enumerate() (with a fold).
+                    let start_value: $dtype = unsafe { *arr.get_unchecked(0) };
+                    let mut low: $dtype = Self::_init_min(start_value);
+                    let mut first_non_nan_update: bool = Self::_allow_first_non_nan_update(start_value);
+                    for i in 0..arr.len() {
+                        let v: $dtype = unsafe { *arr.get_unchecked(i) };
+                        if <Self as SCALARInit<$dtype>>::_RETURN_AT_NAN && Self::_nan_check(v) {
+                            // When _RETURN_AT_NAN is true and we encounter a NaN
+                            return i; // -> return the index
+                        }
+                        if first_non_nan_update {
+                            // If we allow the first non-nan update (only for FloatIgnoreNaN)
+                            if !Self::_nan_check(v) {
+                                // Update the low
+                                low = v;
+                                low_index = i;
+                                // And disable the first_non_nan_update update
+                                first_non_nan_update = false;
+                            }
+                        } else if v <= low {
+                            // Non-strict comparison -> the last index is kept on ties
+                            low = v;
+                            low_index = i;
+                        }
+                    }
+                    low_index
+                }
+
+                #[inline(always)]
+                fn argmax_last(arr: &[$dtype]) -> usize {
+                    assert!(!arr.is_empty());
+                    let mut high_index: usize = 0;
+                    // It is remarkably faster to iterate over the index and use get_unchecked
+                    // than using .iter().enumerate() (with a fold).
+                    let start_value: $dtype = unsafe { *arr.get_unchecked(0) };
+                    let mut high: $dtype = Self::_init_max(start_value);
+                    let mut first_non_nan_update: bool = Self::_allow_first_non_nan_update(start_value);
+                    for i in 0..arr.len() {
+                        let v: $dtype = unsafe { *arr.get_unchecked(i) };
+                        if <Self as SCALARInit<$dtype>>::_RETURN_AT_NAN && Self::_nan_check(v) {
+                            // When _RETURN_AT_NAN is true and we encounter a NaN
+                            return i; // -> return the index
+                        }
+                        if first_non_nan_update {
+                            // If we allow the first non-nan update (only for FloatIgnoreNaN)
+                            if !Self::_nan_check(v) {
+                                // Update the high
+                                high = v;
+                                high_index = i;
+                                // And disable the first_non_nan_update update
+                                first_non_nan_update = false;
+                            }
+                        } else if v >= high {
+                            // Non-strict comparison -> the last index is kept on ties
+                            high = v;
+                            high_index = i;
+                        }
+                    }
+                    high_index
+                }
             }
         )*
     };
 }
 
-impl_scalar!(Int, i8, i16, i32, i64, u8, u16, u32, u64);
+impl_scalar!(Int, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
 #[cfg(feature = "float")]
 impl_scalar!(FloatReturnNaN, f32, f64);
 #[cfg(feature = "float")]
@@ -294,48 +481,244 @@ impl_scalar!(FloatIgnoreNaN, f32, f64);
 
 #[cfg(feature = "half")]
 use super::scalar_f16::{
-    scalar_argmax_f16_ignore_nan, scalar_argmin_f16_ignore_nan, scalar_argminmax_f16_ignore_nan,
+    scalar_argmax_f16_ignore_nan, scalar_argmax_f16_ignore_nan_last, scalar_argmin_f16_ignore_nan,
+    scalar_argmin_f16_ignore_nan_last, scalar_argminmax_f16_ignore_nan,
+    scalar_argminmax_f16_ignore_nan_last,
 };
 #[cfg(feature = "half")]
 use super::scalar_f16::{
-    scalar_argmax_f16_return_nan, scalar_argmin_f16_return_nan, scalar_argminmax_f16_return_nan,
+    scalar_argmax_f16_return_nan, scalar_argmax_f16_return_nan_last, scalar_argmin_f16_return_nan,
+    scalar_argmin_f16_return_nan_last, scalar_argminmax_f16_return_nan,
+    scalar_argminmax_f16_return_nan_last,
 };
 
 #[cfg(feature = "half")]
-use half::f16;
+use half::{bf16, f16};
 
+/// Macro for implementing ScalarArgMinMax for the 16-bit float data types (the scalar
+/// implementations are generic over the `Float16` trait)
 #[cfg(feature = "half")]
-impl ScalarArgMinMax<f16> for SCALAR<FloatReturnNaN> {
-    #[inline(always)]
-    fn argminmax(arr: &[f16]) -> (usize, usize) {
-        scalar_argminmax_f16_return_nan(arr)
-    }
+macro_rules! impl_scalar_f16 {
+    ($($f16_type:ty),*) => {
+        $(
+            impl ScalarArgMinMax<$f16_type> for SCALAR<FloatReturnNaN> {
+                #[inline(always)]
+                fn argminmax(arr: &[$f16_type]) -> (usize, usize) {
+                    let (min_index, _, max_index, _) = scalar_argminmax_f16_return_nan(arr);
+                    (min_index, max_index)
+                }
 
-    #[inline(always)]
-    fn argmin(arr: &[f16]) -> usize {
-        scalar_argmin_f16_return_nan(arr)
-    }
+                #[inline(always)]
+                fn argminmax_with_values(
+                    arr: &[$f16_type],
+                ) -> (usize, $f16_type, usize, $f16_type) {
+                    scalar_argminmax_f16_return_nan(arr)
+                }
 
-    #[inline(always)]
-    fn argmax(arr: &[f16]) -> usize {
-        scalar_argmax_f16_return_nan(arr)
-    }
+                #[inline(always)]
+                fn argmin(arr: &[$f16_type]) -> usize {
+                    scalar_argmin_f16_return_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argmax(arr: &[$f16_type]) -> usize {
+                    scalar_argmax_f16_return_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argminmax_last(arr: &[$f16_type]) -> (usize, usize) {
+                    let (min_index, _, max_index, _) = scalar_argminmax_f16_return_nan_last(arr);
+                    (min_index, max_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_last_with_values(
+                    arr: &[$f16_type],
+                ) -> (usize, $f16_type, usize, $f16_type) {
+                    scalar_argminmax_f16_return_nan_last(arr)
+                }
+
+                #[inline(always)]
+                fn argmin_last(arr: &[$f16_type]) -> usize {
+                    scalar_argmin_f16_return_nan_last(arr)
+                }
+
+                #[inline(always)]
+                fn argmax_last(arr: &[$f16_type]) -> usize {
+                    scalar_argmax_f16_return_nan_last(arr)
+                }
+            }
+
+            impl ScalarArgMinMax<$f16_type> for SCALAR<FloatIgnoreNaN> {
+                #[inline(always)]
+                fn argminmax(arr: &[$f16_type]) -> (usize, usize) {
+                    let (min_index, _, max_index, _) = scalar_argminmax_f16_ignore_nan(arr);
+                    (min_index, max_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_with_values(
+                    arr: &[$f16_type],
+                ) -> (usize, $f16_type, usize, $f16_type) {
+                    scalar_argminmax_f16_ignore_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argmin(arr: &[$f16_type]) -> usize {
+                    scalar_argmin_f16_ignore_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argmax(arr: &[$f16_type]) -> usize {
+                    scalar_argmax_f16_ignore_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argminmax_last(arr: &[$f16_type]) -> (usize, usize) {
+                    let (min_index, _, max_index, _) = scalar_argminmax_f16_ignore_nan_last(arr);
+                    (min_index, max_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_last_with_values(
+                    arr: &[$f16_type],
+                ) -> (usize, $f16_type, usize, $f16_type) {
+                    scalar_argminmax_f16_ignore_nan_last(arr)
+                }
+
+                #[inline(always)]
+                fn argmin_last(arr: &[$f16_type]) -> usize {
+                    scalar_argmin_f16_ignore_nan_last(arr)
+                }
+
+                #[inline(always)]
+                fn argmax_last(arr: &[$f16_type]) -> usize {
+                    scalar_argmax_f16_ignore_nan_last(arr)
+                }
+            }
+        )*
+    };
 }
 
 #[cfg(feature = "half")]
-impl ScalarArgMinMax<f16> for SCALAR<FloatIgnoreNaN> {
-    #[inline(always)]
-    fn argminmax(arr: &[f16]) -> (usize, usize) {
-        scalar_argminmax_f16_ignore_nan(arr)
-    }
+impl_scalar_f16!(f16, bf16);
 
-    #[inline(always)]
-    fn argmin(arr: &[f16]) -> usize {
-        scalar_argmin_f16_ignore_nan(arr)
-    }
+#[cfg(feature = "float8")]
+use super::scalar_f8::{
+    scalar_argmax_f8_ignore_nan, scalar_argmax_f8_ignore_nan_last, scalar_argmin_f8_ignore_nan,
+    scalar_argmin_f8_ignore_nan_last, scalar_argminmax_f8_ignore_nan,
+    scalar_argminmax_f8_ignore_nan_last,
+};
+#[cfg(feature = "float8")]
+use super::scalar_f8::{
+    scalar_argmax_f8_return_nan, scalar_argmax_f8_return_nan_last, scalar_argmin_f8_return_nan,
+    scalar_argmin_f8_return_nan_last, scalar_argminmax_f8_return_nan,
+    scalar_argminmax_f8_return_nan_last,
+};
 
-    #[inline(always)]
-    fn argmax(arr: &[f16]) -> usize {
-        scalar_argmax_f16_ignore_nan(arr)
-    }
+#[cfg(feature = "float8")]
+use float8::{F8E4M3, F8E5M2};
+
+/// Macro for implementing ScalarArgMinMax for the FP8 data types (the scalar
+/// implementations are generic over the `Float8` trait)
+#[cfg(feature = "float8")]
+macro_rules! impl_scalar_f8 {
+    ($($f8_type:ty),*) => {
+        $(
+            impl ScalarArgMinMax<$f8_type> for SCALAR<FloatReturnNaN> {
+                #[inline(always)]
+                fn argminmax(arr: &[$f8_type]) -> (usize, usize) {
+                    let (min_index, _, max_index, _) = scalar_argminmax_f8_return_nan(arr);
+                    (min_index, max_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_with_values(arr: &[$f8_type]) -> (usize, $f8_type, usize, $f8_type) {
+                    scalar_argminmax_f8_return_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argmin(arr: &[$f8_type]) -> usize {
+                    scalar_argmin_f8_return_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argmax(arr: &[$f8_type]) -> usize {
+                    scalar_argmax_f8_return_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argminmax_last(arr: &[$f8_type]) -> (usize, usize) {
+                    let (min_index, _, max_index, _) = scalar_argminmax_f8_return_nan_last(arr);
+                    (min_index, max_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_last_with_values(
+                    arr: &[$f8_type],
+                ) -> (usize, $f8_type, usize, $f8_type) {
+                    scalar_argminmax_f8_return_nan_last(arr)
+                }
+
+                #[inline(always)]
+                fn argmin_last(arr: &[$f8_type]) -> usize {
+                    scalar_argmin_f8_return_nan_last(arr)
+                }
+
+                #[inline(always)]
+                fn argmax_last(arr: &[$f8_type]) -> usize {
+                    scalar_argmax_f8_return_nan_last(arr)
+                }
+            }
+
+            impl ScalarArgMinMax<$f8_type> for SCALAR<FloatIgnoreNaN> {
+                #[inline(always)]
+                fn argminmax(arr: &[$f8_type]) -> (usize, usize) {
+                    let (min_index, _, max_index, _) = scalar_argminmax_f8_ignore_nan(arr);
+                    (min_index, max_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_with_values(arr: &[$f8_type]) -> (usize, $f8_type, usize, $f8_type) {
+                    scalar_argminmax_f8_ignore_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argmin(arr: &[$f8_type]) -> usize {
+                    scalar_argmin_f8_ignore_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argmax(arr: &[$f8_type]) -> usize {
+                    scalar_argmax_f8_ignore_nan(arr)
+                }
+
+                #[inline(always)]
+                fn argminmax_last(arr: &[$f8_type]) -> (usize, usize) {
+                    let (min_index, _, max_index, _) = scalar_argminmax_f8_ignore_nan_last(arr);
+                    (min_index, max_index)
+                }
+
+                #[inline(always)]
+                fn argminmax_last_with_values(
+                    arr: &[$f8_type],
+                ) -> (usize, $f8_type, usize, $f8_type) {
+                    scalar_argminmax_f8_ignore_nan_last(arr)
+                }
+
+                #[inline(always)]
+                fn argmin_last(arr: &[$f8_type]) -> usize {
+                    scalar_argmin_f8_ignore_nan_last(arr)
+                }
+
+                #[inline(always)]
+                fn argmax_last(arr: &[$f8_type]) -> usize {
+                    scalar_argmax_f8_ignore_nan_last(arr)
+                }
+            }
+        )*
+    };
 }
+
+#[cfg(feature = "float8")]
+impl_scalar_f8!(F8E4M3, F8E5M2);
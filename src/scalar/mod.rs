@@ -5,3 +5,9 @@ pub use generic::{ScalarArgMinMax, SCALAR};
 // Data type specific modules
 #[cfg(feature = "half")]
 mod scalar_f16;
+#[cfg(feature = "half")]
+pub(crate) use scalar_f16::Float16;
+#[cfg(feature = "float8")]
+mod scalar_f8;
+#[cfg(feature = "float8")]
+pub(crate) use scalar_f8::Float8;
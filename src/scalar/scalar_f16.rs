@@ -1,15 +1,77 @@
-/// Implementation of the scalar argminmax operations for f16.
+/// Implementation of the scalar argminmax operations for the 16-bit floats (f16 and bf16).
 ///
-/// As f16 is not hardware supported on most x86 CPUs, we aim to facilitate efficient
-/// implementation of argminmax operations on f16 arrays through transforming the f16
-/// values to i16ord. (more details in simd/simd_f16_return_nan.rs)
+/// As f16 and bf16 are not hardware supported on most x86 CPUs, we aim to facilitate
+/// efficient implementation of argminmax operations on these arrays through transforming
+/// the values to i16ord. (more details in simd/simd_f16_return_nan.rs)
 ///
-use half::f16;
+/// bf16 has the same layout as the upper 16 bits of an f32 (1 sign bit, 8 exponent bits
+/// and 7 mantissa bits), so the same transformation as for f16 can be used - only the
+/// NaN threshold differs.
+///
+use half::{bf16, f16};
+
+/// Helper trait for the 16-bit float data types, exposing the bit-level information
+/// that is required to view the values as ordinal i16 values.
+pub(crate) trait Float16: Copy {
+    /// Absolute (bit) values above this are NaN
+    const NAN_VALUE: u16;
+    /// The largest (non-NaN) value - i.e., +inf
+    const MAX_VALUE: Self;
+    /// The smallest (non-NaN) value - i.e., -inf
+    const MIN_VALUE: Self;
+
+    fn to_u16(self) -> u16;
 
-#[inline(always)]
-fn f16_to_i16ord(x: f16) -> i16 {
-    let x = unsafe { std::mem::transmute::<f16, i16>(x) };
-    ((x >> 15) & 0x7FFF) ^ x
+    fn from_u16(bits: u16) -> Self;
+
+    #[inline(always)]
+    fn is_nan_bits(self) -> bool {
+        (self.to_u16() & 0x7FFF) > Self::NAN_VALUE
+    }
+
+    #[inline(always)]
+    fn to_i16ord(self) -> i16 {
+        let x = self.to_u16() as i16;
+        ((x >> 15) & 0x7FFF) ^ x
+    }
+
+    #[inline(always)]
+    fn from_i16ord(ord_i16: i16) -> Self {
+        let v = ((ord_i16 >> 15) & 0x7FFF) ^ ord_i16;
+        Self::from_u16(v as u16)
+    }
+}
+
+impl Float16 for f16 {
+    const NAN_VALUE: u16 = 0x7C00;
+    const MAX_VALUE: Self = f16::INFINITY;
+    const MIN_VALUE: Self = f16::NEG_INFINITY;
+
+    #[inline(always)]
+    fn to_u16(self) -> u16 {
+        self.to_bits()
+    }
+
+    #[inline(always)]
+    fn from_u16(bits: u16) -> Self {
+        f16::from_bits(bits)
+    }
+}
+
+impl Float16 for bf16 {
+    const NAN_VALUE: u16 = 0x7F80;
+    const MAX_VALUE: Self = bf16::INFINITY;
+    const MIN_VALUE: Self = bf16::NEG_INFINITY;
+
+    #[inline(always)]
+    fn to_u16(self) -> u16 {
+        self.to_bits()
+    }
+
+    #[inline(always)]
+    fn from_u16(bits: u16) -> Self {
+        bf16::from_bits(bits)
+    }
 }
 
 // ------- Float Return NaN -------
@@ -17,7 +79,7 @@ fn f16_to_i16ord(x: f16) -> i16 {
 // TODO: commented this (see the TODO below)
 // #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 // #[inline(never)]
-pub(crate) fn scalar_argminmax_f16_return_nan(arr: &[f16]) -> (usize, usize) {
+pub(crate) fn scalar_argminmax_f16_return_nan<T: Float16>(arr: &[T]) -> (usize, T, usize, T) {
     // f16 is transformed to i16ord
     //   benchmarks  show:
     //     1. this is 7-10x faster than using raw f16
@@ -27,15 +89,15 @@ pub(crate) fn scalar_argminmax_f16_return_nan(arr: &[f16]) -> (usize, usize) {
     let mut high_index: usize = 0;
     // It is remarkably faster to iterate over the index and use get_unchecked
     // than using .iter().enumerate() (with a fold).
-    let mut low: i16 = f16_to_i16ord(unsafe { *arr.get_unchecked(low_index) });
-    let mut high: i16 = f16_to_i16ord(unsafe { *arr.get_unchecked(high_index) });
+    let mut low: i16 = unsafe { *arr.get_unchecked(low_index) }.to_i16ord();
+    let mut high: i16 = unsafe { *arr.get_unchecked(high_index) }.to_i16ord();
     for i in 0..arr.len() {
-        let v: f16 = unsafe { *arr.get_unchecked(i) };
-        if v.is_nan() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
             // Return the index of the first NaN value
-            return (i, i);
+            return (i, v, i, v);
         }
-        let v: i16 = f16_to_i16ord(v);
+        let v: i16 = v.to_i16ord();
         if v < low {
             low = v;
             low_index = i;
@@ -44,10 +106,15 @@ pub(crate) fn scalar_argminmax_f16_return_nan(arr: &[f16]) -> (usize, usize) {
             high_index = i;
         }
     }
-    (low_index, high_index)
+    (
+        low_index,
+        T::from_i16ord(low),
+        high_index,
+        T::from_i16ord(high),
+    )
 }
 
-pub(crate) fn scalar_argmin_f16_return_nan(arr: &[f16]) -> usize {
+pub(crate) fn scalar_argmin_f16_return_nan<T: Float16>(arr: &[T]) -> usize {
     // f16 is transformed to i16ord
     //   benchmarks  show:
     //     1. this is 7-10x faster than using raw f16
@@ -56,14 +123,14 @@ pub(crate) fn scalar_argmin_f16_return_nan(arr: &[f16]) -> usize {
     let mut low_index: usize = 0;
     // It is remarkably faster to iterate over the index and use get_unchecked
     // than using .iter().enumerate() (with a fold).
-    let mut low: i16 = f16_to_i16ord(unsafe { *arr.get_unchecked(low_index) });
+    let mut low: i16 = unsafe { *arr.get_unchecked(low_index) }.to_i16ord();
     for i in 0..arr.len() {
-        let v: f16 = unsafe { *arr.get_unchecked(i) };
-        if v.is_nan() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
             // Return the index of the first NaN value
             return i;
         }
-        let v: i16 = f16_to_i16ord(v);
+        let v: i16 = v.to_i16ord();
         if v < low {
             low = v;
             low_index = i;
@@ -72,7 +139,7 @@ pub(crate) fn scalar_argmin_f16_return_nan(arr: &[f16]) -> usize {
     low_index
 }
 
-pub(crate) fn scalar_argmax_f16_return_nan(arr: &[f16]) -> usize {
+pub(crate) fn scalar_argmax_f16_return_nan<T: Float16>(arr: &[T]) -> usize {
     // f16 is transformed to i16ord
     //   benchmarks  show:
     //     1. this is 7-10x faster than using raw f16
@@ -81,14 +148,14 @@ pub(crate) fn scalar_argmax_f16_return_nan(arr: &[f16]) -> usize {
     let mut high_index: usize = 0;
     // It is remarkably faster to iterate over the index and use get_unchecked
     // than using .iter().enumerate() (with a fold).
-    let mut high: i16 = f16_to_i16ord(unsafe { *arr.get_unchecked(high_index) });
+    let mut high: i16 = unsafe { *arr.get_unchecked(high_index) }.to_i16ord();
     for i in 0..arr.len() {
-        let v: f16 = unsafe { *arr.get_unchecked(i) };
-        if v.is_nan() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
             // Return the index of the first NaN value
             return i;
         }
-        let v: i16 = f16_to_i16ord(v);
+        let v: i16 = v.to_i16ord();
         if v > high {
             high = v;
             high_index = i;
@@ -97,7 +164,7 @@ pub(crate) fn scalar_argmax_f16_return_nan(arr: &[f16]) -> usize {
     high_index
 }
 
-pub(crate) fn scalar_argminmax_f16_ignore_nan(arr: &[f16]) -> (usize, usize) {
+pub(crate) fn scalar_argminmax_f16_ignore_nan<T: Float16>(arr: &[T]) -> (usize, T, usize, T) {
     // f16 is transformed to i16ord
     //   benchmarks  show:
     //     1. this is 7-10x faster than using raw f16
@@ -107,16 +174,16 @@ pub(crate) fn scalar_argminmax_f16_ignore_nan(arr: &[f16]) -> (usize, usize) {
     let mut high_index: usize = 0;
     // It is remarkably faster to iterate over the index and use get_unchecked
     // than using .iter().enumerate() (with a fold).
-    let mut low: i16 = f16_to_i16ord(f16::INFINITY);
-    let mut high: i16 = f16_to_i16ord(f16::NEG_INFINITY);
+    let mut low: i16 = T::MAX_VALUE.to_i16ord();
+    let mut high: i16 = T::MIN_VALUE.to_i16ord();
     let mut first_non_nan_update = true;
     for i in 0..arr.len() {
-        let v: f16 = unsafe { *arr.get_unchecked(i) };
-        if v.is_nan() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
             // v is NaN, ignore it (do nothing)
         } else {
             // v is not NaN
-            let v: i16 = f16_to_i16ord(v);
+            let v: i16 = v.to_i16ord();
             if first_non_nan_update {
                 low = v;
                 high = v;
@@ -132,10 +199,15 @@ pub(crate) fn scalar_argminmax_f16_ignore_nan(arr: &[f16]) -> (usize, usize) {
             }
         }
     }
-    (low_index, high_index)
+    (
+        low_index,
+        T::from_i16ord(low),
+        high_index,
+        T::from_i16ord(high),
+    )
 }
 
-pub(crate) fn scalar_argmin_f16_ignore_nan(arr: &[f16]) -> usize {
+pub(crate) fn scalar_argmin_f16_ignore_nan<T: Float16>(arr: &[T]) -> usize {
     // f16 is transformed to i16ord
     //   benchmarks  show:
     //     1. this is 7-10x faster than using raw f16
@@ -144,14 +216,14 @@ pub(crate) fn scalar_argmin_f16_ignore_nan(arr: &[f16]) -> usize {
     let mut low_index: usize = 0;
     // It is remarkably faster to iterate over the index and use get_unchecked
     // than using .iter().enumerate() (with a fold).
-    let mut low: i16 = f16_to_i16ord(f16::INFINITY);
+    let mut low: i16 = T::MAX_VALUE.to_i16ord();
     for i in 0..arr.len() {
-        let v: f16 = unsafe { *arr.get_unchecked(i) };
-        if v.is_nan() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
             // v is NaN, ignore it (do nothing)
         } else {
             // v is not NaN
-            let v: i16 = f16_to_i16ord(v);
+            let v: i16 = v.to_i16ord();
             if v < low {
                 low = v;
                 low_index = i;
@@ -161,7 +233,7 @@ pub(crate) fn scalar_argmin_f16_ignore_nan(arr: &[f16]) -> usize {
     low_index
 }
 
-pub(crate) fn scalar_argmax_f16_ignore_nan(arr: &[f16]) -> usize {
+pub(crate) fn scalar_argmax_f16_ignore_nan<T: Float16>(arr: &[T]) -> usize {
     // f16 is transformed to i16ord
     //   benchmarks  show:
     //     1. this is 7-10x faster than using raw f16
@@ -170,14 +242,14 @@ pub(crate) fn scalar_argmax_f16_ignore_nan(arr: &[f16]) -> usize {
     let mut high_index: usize = 0;
     // It is remarkably faster to iterate over the index and use get_unchecked
     // than using .iter().enumerate() (with a fold).
-    let mut high: i16 = f16_to_i16ord(f16::NEG_INFINITY);
+    let mut high: i16 = T::MIN_VALUE.to_i16ord();
     for i in 0..arr.len() {
-        let v: f16 = unsafe { *arr.get_unchecked(i) };
-        if v.is_nan() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
             // v is NaN, ignore it (do nothing)
         } else {
             // v is not NaN
-            let v: i16 = f16_to_i16ord(v);
+            let v: i16 = v.to_i16ord();
             if v > high {
                 high = v;
                 high_index = i;
@@ -187,11 +259,154 @@ pub(crate) fn scalar_argmax_f16_ignore_nan(arr: &[f16]) -> usize {
     high_index
 }
 
+// ------- Last occurrence on ties -------
+// Same as the implementations above, but with non-strict comparisons (so that the last
+// index is returned when the min / max value occurs multiple times).
+
+pub(crate) fn scalar_argminmax_f16_return_nan_last<T: Float16>(arr: &[T]) -> (usize, T, usize, T) {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut high_index: usize = 0;
+    let mut low: i16 = unsafe { *arr.get_unchecked(low_index) }.to_i16ord();
+    let mut high: i16 = unsafe { *arr.get_unchecked(high_index) }.to_i16ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return (i, v, i, v);
+        }
+        let v: i16 = v.to_i16ord();
+        if v <= low {
+            low = v;
+            low_index = i;
+        }
+        if v >= high {
+            high = v;
+            high_index = i;
+        }
+    }
+    (
+        low_index,
+        T::from_i16ord(low),
+        high_index,
+        T::from_i16ord(high),
+    )
+}
+
+pub(crate) fn scalar_argmin_f16_return_nan_last<T: Float16>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut low: i16 = unsafe { *arr.get_unchecked(low_index) }.to_i16ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return i;
+        }
+        let v: i16 = v.to_i16ord();
+        if v <= low {
+            low = v;
+            low_index = i;
+        }
+    }
+    low_index
+}
+
+pub(crate) fn scalar_argmax_f16_return_nan_last<T: Float16>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut high_index: usize = 0;
+    let mut high: i16 = unsafe { *arr.get_unchecked(high_index) }.to_i16ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return i;
+        }
+        let v: i16 = v.to_i16ord();
+        if v >= high {
+            high = v;
+            high_index = i;
+        }
+    }
+    high_index
+}
+
+pub(crate) fn scalar_argminmax_f16_ignore_nan_last<T: Float16>(arr: &[T]) -> (usize, T, usize, T) {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut high_index: usize = 0;
+    let mut low: i16 = T::MAX_VALUE.to_i16ord();
+    let mut high: i16 = T::MIN_VALUE.to_i16ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i16 = v.to_i16ord();
+            if v <= low {
+                low = v;
+                low_index = i;
+            }
+            if v >= high {
+                high = v;
+                high_index = i;
+            }
+        }
+    }
+    (
+        low_index,
+        T::from_i16ord(low),
+        high_index,
+        T::from_i16ord(high),
+    )
+}
+
+pub(crate) fn scalar_argmin_f16_ignore_nan_last<T: Float16>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut low: i16 = T::MAX_VALUE.to_i16ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i16 = v.to_i16ord();
+            if v <= low {
+                low = v;
+                low_index = i;
+            }
+        }
+    }
+    low_index
+}
+
+pub(crate) fn scalar_argmax_f16_ignore_nan_last<T: Float16>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut high_index: usize = 0;
+    let mut high: i16 = T::MIN_VALUE.to_i16ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i16 = v.to_i16ord();
+            if v >= high {
+                high = v;
+                high_index = i;
+            }
+        }
+    }
+    high_index
+}
+
 // TODO: previously we had dedicated non x86_64 code for f16 (see below)
 
 // #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 // // #[inline(never)]
-// pub(crate) fn scalar_argminmax_f16(arr: &[f16]) -> (usize, usize) {
+// pub(crate) fn scalar_argminmax_f16<T: Float16>(arr: &[T]) -> (usize, usize) {
 //     // f16 is transformed to i16ord
 //     //   benchmarks  show:
 //     //     1. this is 7-10x faster than using raw f16
@@ -223,6 +438,7 @@ pub(crate) fn scalar_argmax_f16_ignore_nan(arr: &[f16]) -> usize {
 #[cfg(all(feature = "float", feature = "half"))]
 #[cfg(test)]
 mod tests {
+    use super::{bf16, f16, Float16};
     use super::{
         scalar_argmax_f16_ignore_nan, scalar_argmin_f16_ignore_nan, scalar_argminmax_f16_ignore_nan,
     };
@@ -231,29 +447,32 @@ mod tests {
     };
     use crate::{FloatIgnoreNaN, FloatReturnNaN, ScalarArgMinMax, SCALAR};
 
-    use half::f16;
-
     use dev_utils::utils;
 
     const ARR_LEN: usize = 1025;
 
-    fn get_arrays(len: usize) -> (Vec<f32>, Vec<f16>) {
+    /// Get the (min_index, max_index) of a (min_index, min_value, max_index, max_value)
+    fn indices<T>((min_index, _, max_index, _): (usize, T, usize, T)) -> (usize, usize) {
+        (min_index, max_index)
+    }
+
+    fn get_arrays<T: Float16>(len: usize, from_f32: fn(f32) -> T) -> (Vec<f32>, Vec<T>) {
         // we use i8 its to make sure we have correct representation in float
         let v = utils::get_random_array(len, i8::MIN, i8::MAX);
         let vec_f32: Vec<f32> = v.iter().map(|x| *x as f32).collect();
-        let vec_f16: Vec<f16> = vec_f32.iter().map(|x| f16::from_f32(*x)).collect();
+        let vec_f16: Vec<T> = vec_f32.iter().map(|x| from_f32(*x)).collect();
         (vec_f32, vec_f16)
     }
 
-    #[test]
-    fn test_generic_and_specific_impl_return_the_same_results() {
+    fn _test_return_the_same_results<T: Float16>(from_f32: fn(f32) -> T) {
         for _ in 0..100 {
-            let (vec_f32, vec_f16) = get_arrays(ARR_LEN);
+            let (vec_f32, vec_f16) = get_arrays(ARR_LEN, from_f32);
             let data_f32: &[f32] = &vec_f32;
-            let data_f16: &[f16] = &vec_f16;
+            let data_f16: &[T] = &vec_f16;
             // Return NaN
             let (argmin_index, argmax_index) = SCALAR::<FloatReturnNaN>::argminmax(data_f32);
-            let (argmin_index_f16, argmax_index_f16) = scalar_argminmax_f16_return_nan(data_f16);
+            let (argmin_index_f16, argmax_index_f16) =
+                indices(scalar_argminmax_f16_return_nan(data_f16));
             let argmin_index_f16_single = scalar_argmin_f16_return_nan(data_f16);
             let argmax_index_f16_single = scalar_argmax_f16_return_nan(data_f16);
             assert_eq!(argmin_index, argmin_index_f16);
@@ -262,7 +481,8 @@ mod tests {
             assert_eq!(argmin_index, argmin_index_f16_single);
             // Ignore NaN
             let (argmin_index, argmax_index) = SCALAR::<FloatIgnoreNaN>::argminmax(data_f32);
-            let (argmin_index_f16, argmax_index_f16) = scalar_argminmax_f16_ignore_nan(data_f16);
+            let (argmin_index_f16, argmax_index_f16) =
+                indices(scalar_argminmax_f16_ignore_nan(data_f16));
             let argmin_index_f16_single = scalar_argmin_f16_ignore_nan(data_f16);
             let argmax_index_f16_single = scalar_argmax_f16_ignore_nan(data_f16);
             assert_eq!(argmin_index, argmin_index_f16);
@@ -272,18 +492,18 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_generic_and_specific_impl_return_nans() {
+    fn _test_return_nans<T: Float16>(from_f32: fn(f32) -> T) {
         // first, middle, last element
         let nan_pos: [usize; 3] = [0, ARR_LEN / 2, ARR_LEN - 1];
         for pos in nan_pos.iter() {
-            let (vec_f32, vec_f16) = get_arrays(ARR_LEN);
+            let (vec_f32, vec_f16) = get_arrays(ARR_LEN, from_f32);
             let mut data_f32: Vec<f32> = vec_f32;
-            let mut data_f16: Vec<f16> = vec_f16;
+            let mut data_f16: Vec<T> = vec_f16;
             data_f32[*pos] = f32::NAN;
-            data_f16[*pos] = f16::NAN;
+            data_f16[*pos] = from_f32(f32::NAN);
             let (argmin_index, argmax_index) = SCALAR::<FloatReturnNaN>::argminmax(&data_f32);
-            let (argmin_index_f16, argmax_index_f16) = scalar_argminmax_f16_return_nan(&data_f16);
+            let (argmin_index_f16, argmax_index_f16) =
+                indices(scalar_argminmax_f16_return_nan(&data_f16));
             let argmin_index_f16_single = scalar_argmin_f16_return_nan(&data_f16);
             let argmax_index_f16_single = scalar_argmax_f16_return_nan(&data_f16);
             assert_eq!(argmin_index, argmin_index_f16);
@@ -293,15 +513,13 @@ mod tests {
         }
 
         // all elements are NaN
-        let (mut vec_f32, mut vec_f16) = get_arrays(ARR_LEN);
-        vec_f32.iter_mut().for_each(|x| *x = f32::NAN);
-        vec_f16.iter_mut().for_each(|x| *x = f16::NAN);
-        let data_f32: &[f32] = &vec_f32;
-        let data_f16: &[f16] = &vec_f16;
-        let (argmin_index, argmax_index) = SCALAR::<FloatReturnNaN>::argminmax(data_f32);
-        let (argmin_index_f16, argmax_index_f16) = scalar_argminmax_f16_return_nan(data_f16);
-        let argmin_index_f16_single = scalar_argmin_f16_return_nan(data_f16);
-        let argmax_index_f16_single = scalar_argmax_f16_return_nan(data_f16);
+        let vec_f32: Vec<f32> = vec![f32::NAN; ARR_LEN];
+        let vec_f16: Vec<T> = vec![from_f32(f32::NAN); ARR_LEN];
+        let (argmin_index, argmax_index) = SCALAR::<FloatReturnNaN>::argminmax(&vec_f32);
+        let (argmin_index_f16, argmax_index_f16) =
+            indices(scalar_argminmax_f16_return_nan(&vec_f16));
+        let argmin_index_f16_single = scalar_argmin_f16_return_nan(&vec_f16);
+        let argmax_index_f16_single = scalar_argmax_f16_return_nan(&vec_f16);
         assert_eq!(argmin_index, argmin_index_f16);
         assert_eq!(argmin_index, argmin_index_f16_single);
         assert_eq!(argmax_index, argmax_index_f16);
@@ -310,18 +528,18 @@ mod tests {
         assert_eq!(argmax_index, 0);
     }
 
-    #[test]
-    fn test_generic_and_specific_impl_ignore_nans() {
+    fn _test_ignore_nans<T: Float16>(from_f32: fn(f32) -> T) {
         // first, middle, last element
         let nan_pos: [usize; 3] = [0, ARR_LEN / 2, ARR_LEN - 1];
         for pos in nan_pos.iter() {
-            let (vec_f32, vec_f16) = get_arrays(ARR_LEN);
+            let (vec_f32, vec_f16) = get_arrays(ARR_LEN, from_f32);
             let mut data_f32: Vec<f32> = vec_f32;
-            let mut data_f16: Vec<f16> = vec_f16;
+            let mut data_f16: Vec<T> = vec_f16;
             data_f32[*pos] = f32::NAN;
-            data_f16[*pos] = f16::NAN;
+            data_f16[*pos] = from_f32(f32::NAN);
             let (argmin_index, argmax_index) = SCALAR::<FloatIgnoreNaN>::argminmax(&data_f32);
-            let (argmin_index_f16, argmax_index_f16) = scalar_argminmax_f16_ignore_nan(&data_f16);
+            let (argmin_index_f16, argmax_index_f16) =
+                indices(scalar_argminmax_f16_ignore_nan(&data_f16));
             let argmin_index_f16_single = scalar_argmin_f16_ignore_nan(&data_f16);
             let argmax_index_f16_single = scalar_argmax_f16_ignore_nan(&data_f16);
             assert_eq!(argmin_index, argmin_index_f16);
@@ -331,15 +549,13 @@ mod tests {
         }
 
         // all elements are NaN
-        let (mut vec_f32, mut vec_f16) = get_arrays(ARR_LEN);
-        vec_f32.iter_mut().for_each(|x| *x = f32::NAN);
-        vec_f16.iter_mut().for_each(|x| *x = f16::NAN);
-        let data_f32: &[f32] = &vec_f32;
-        let data_f16: &[f16] = &vec_f16;
-        let (argmin_index, argmax_index) = SCALAR::<FloatIgnoreNaN>::argminmax(data_f32);
-        let (argmin_index_f16, argmax_index_f16) = scalar_argminmax_f16_ignore_nan(data_f16);
-        let argmin_index_f16_single = scalar_argmin_f16_ignore_nan(data_f16);
-        let argmax_index_f16_single = scalar_argmax_f16_ignore_nan(data_f16);
+        let vec_f32: Vec<f32> = vec![f32::NAN; ARR_LEN];
+        let vec_f16: Vec<T> = vec![from_f32(f32::NAN); ARR_LEN];
+        let (argmin_index, argmax_index) = SCALAR::<FloatIgnoreNaN>::argminmax(&vec_f32);
+        let (argmin_index_f16, argmax_index_f16) =
+            indices(scalar_argminmax_f16_ignore_nan(&vec_f16));
+        let argmin_index_f16_single = scalar_argmin_f16_ignore_nan(&vec_f16);
+        let argmax_index_f16_single = scalar_argmax_f16_ignore_nan(&vec_f16);
         assert_eq!(argmin_index, argmin_index_f16);
         assert_eq!(argmin_index, argmin_index_f16_single);
         assert_eq!(argmax_index, argmax_index_f16);
@@ -347,4 +563,33 @@ mod tests {
         assert_eq!(argmin_index, 0);
         assert_eq!(argmax_index, 0);
     }
+
+    #[test]
+    fn test_generic_and_specific_impl_return_the_same_results() {
+        _test_return_the_same_results(f16::from_f32);
+        _test_return_the_same_results(bf16::from_f32);
+    }
+
+    #[test]
+    fn test_generic_and_specific_impl_return_nans() {
+        _test_return_nans(f16::from_f32);
+        _test_return_nans(bf16::from_f32);
+    }
+
+    #[test]
+    fn test_generic_and_specific_impl_ignore_nans() {
+        _test_ignore_nans(f16::from_f32);
+        _test_ignore_nans(bf16::from_f32);
+    }
+
+    #[test]
+    fn test_nan_bits() {
+        assert!(!f16::INFINITY.is_nan_bits());
+        assert!(f16::NAN.is_nan_bits());
+        assert!(!bf16::INFINITY.is_nan_bits());
+        assert!(bf16::NAN.is_nan_bits());
+        // The largest f16 NaN threshold is a normal bf16 value
+        assert!(!bf16::from_bits(0x7E00).is_nan_bits());
+        assert!(f16::from_bits(0x7E00).is_nan_bits());
+    }
 }
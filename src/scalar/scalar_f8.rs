@@ -0,0 +1,512 @@
+/// Implementation of the scalar argminmax operations for 8-bit floats (FP8).
+///
+/// Two FP8 formats are supported (through the `float8` crate):
+/// - `F8E4M3` (the "FN" variant): no infinities, NaN is 0x7F / 0xFF
+/// - `F8E5M2`: infinities are 0x7C / 0xFC, NaN when the absolute value > 0x7C
+///
+/// As FP8 is not hardware supported on most CPUs, we aim to facilitate efficient
+/// implementation of argminmax operations on FP8 arrays through transforming the FP8
+/// values to i8ord. (more details in simd/simd_f8_return_nan.rs)
+///
+/// Note that the NaN check is performed on the bit representation (as defined in the
+/// OCP 8-bit floating point specification), so that all NaN encodings are detected.
+///
+use float8::{F8E4M3, F8E5M2};
+
+/// Helper trait for the FP8 data types, exposing the bit-level information that is
+/// required to view the FP8 values as ordinal i8 values.
+pub(crate) trait Float8: Copy {
+    /// Absolute (bit) values above this are NaN
+    const NAN_VALUE: u8;
+    /// The largest (non-NaN) value - i.e., +inf for E5M2 and the max value for E4M3
+    const MAX_VALUE: Self;
+    /// The smallest (non-NaN) value - i.e., -inf for E5M2 and the min value for E4M3
+    const MIN_VALUE: Self;
+
+    fn to_u8(self) -> u8;
+
+    fn from_u8(bits: u8) -> Self;
+
+    #[inline(always)]
+    fn is_nan_bits(self) -> bool {
+        (self.to_u8() & 0x7F) > Self::NAN_VALUE
+    }
+
+    #[inline(always)]
+    fn to_i8ord(self) -> i8 {
+        let x = self.to_u8() as i8;
+        ((x >> 7) & 0x7F) ^ x
+    }
+
+    #[inline(always)]
+    fn from_i8ord(ord_i8: i8) -> Self {
+        let v = ((ord_i8 >> 7) & 0x7F) ^ ord_i8;
+        Self::from_u8(v as u8)
+    }
+}
+
+impl Float8 for F8E4M3 {
+    const NAN_VALUE: u8 = 0x7E;
+    const MAX_VALUE: Self = F8E4M3::from_bits(0x7E);
+    const MIN_VALUE: Self = F8E4M3::from_bits(0xFE);
+
+    #[inline(always)]
+    fn to_u8(self) -> u8 {
+        self.to_bits()
+    }
+
+    #[inline(always)]
+    fn from_u8(bits: u8) -> Self {
+        F8E4M3::from_bits(bits)
+    }
+}
+
+impl Float8 for F8E5M2 {
+    const NAN_VALUE: u8 = 0x7C;
+    const MAX_VALUE: Self = F8E5M2::from_bits(0x7C);
+    const MIN_VALUE: Self = F8E5M2::from_bits(0xFC);
+
+    #[inline(always)]
+    fn to_u8(self) -> u8 {
+        self.to_bits()
+    }
+
+    #[inline(always)]
+    fn from_u8(bits: u8) -> Self {
+        F8E5M2::from_bits(bits)
+    }
+}
+
+// ------- Float Return NaN -------
+
+pub(crate) fn scalar_argminmax_f8_return_nan<T: Float8>(arr: &[T]) -> (usize, T, usize, T) {
+    // FP8 is transformed to i8ord
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut high_index: usize = 0;
+    // It is remarkably faster to iterate over the index and use get_unchecked
+    // than using .iter().enumerate() (with a fold).
+    let mut low: i8 = unsafe { *arr.get_unchecked(low_index) }.to_i8ord();
+    let mut high: i8 = unsafe { *arr.get_unchecked(high_index) }.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return (i, v, i, v);
+        }
+        let v: i8 = v.to_i8ord();
+        if v < low {
+            low = v;
+            low_index = i;
+        } else if v > high {
+            high = v;
+            high_index = i;
+        }
+    }
+    (
+        low_index,
+        T::from_i8ord(low),
+        high_index,
+        T::from_i8ord(high),
+    )
+}
+
+pub(crate) fn scalar_argmin_f8_return_nan<T: Float8>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut low: i8 = unsafe { *arr.get_unchecked(low_index) }.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return i;
+        }
+        let v: i8 = v.to_i8ord();
+        if v < low {
+            low = v;
+            low_index = i;
+        }
+    }
+    low_index
+}
+
+pub(crate) fn scalar_argmax_f8_return_nan<T: Float8>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut high_index: usize = 0;
+    let mut high: i8 = unsafe { *arr.get_unchecked(high_index) }.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return i;
+        }
+        let v: i8 = v.to_i8ord();
+        if v > high {
+            high = v;
+            high_index = i;
+        }
+    }
+    high_index
+}
+
+// ------- Float Ignore NaN -------
+
+pub(crate) fn scalar_argminmax_f8_ignore_nan<T: Float8>(arr: &[T]) -> (usize, T, usize, T) {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut high_index: usize = 0;
+    let mut low: i8 = T::MAX_VALUE.to_i8ord();
+    let mut high: i8 = T::MIN_VALUE.to_i8ord();
+    let mut first_non_nan_update = true;
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i8 = v.to_i8ord();
+            if first_non_nan_update {
+                low = v;
+                high = v;
+                low_index = i;
+                high_index = i;
+                first_non_nan_update = false;
+            } else if v < low {
+                low = v;
+                low_index = i;
+            } else if v > high {
+                high = v;
+                high_index = i;
+            }
+        }
+    }
+    (
+        low_index,
+        T::from_i8ord(low),
+        high_index,
+        T::from_i8ord(high),
+    )
+}
+
+pub(crate) fn scalar_argmin_f8_ignore_nan<T: Float8>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut low: i8 = T::MAX_VALUE.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i8 = v.to_i8ord();
+            if v < low {
+                low = v;
+                low_index = i;
+            }
+        }
+    }
+    low_index
+}
+
+pub(crate) fn scalar_argmax_f8_ignore_nan<T: Float8>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut high_index: usize = 0;
+    let mut high: i8 = T::MIN_VALUE.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i8 = v.to_i8ord();
+            if v > high {
+                high = v;
+                high_index = i;
+            }
+        }
+    }
+    high_index
+}
+
+// ------- Last occurrence on ties -------
+// Same as the implementations above, but with non-strict comparisons (so that the last
+// index is returned when the min / max value occurs multiple times).
+
+pub(crate) fn scalar_argminmax_f8_return_nan_last<T: Float8>(arr: &[T]) -> (usize, T, usize, T) {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut high_index: usize = 0;
+    let mut low: i8 = unsafe { *arr.get_unchecked(low_index) }.to_i8ord();
+    let mut high: i8 = unsafe { *arr.get_unchecked(high_index) }.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return (i, v, i, v);
+        }
+        let v: i8 = v.to_i8ord();
+        if v <= low {
+            low = v;
+            low_index = i;
+        }
+        if v >= high {
+            high = v;
+            high_index = i;
+        }
+    }
+    (
+        low_index,
+        T::from_i8ord(low),
+        high_index,
+        T::from_i8ord(high),
+    )
+}
+
+pub(crate) fn scalar_argmin_f8_return_nan_last<T: Float8>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut low: i8 = unsafe { *arr.get_unchecked(low_index) }.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return i;
+        }
+        let v: i8 = v.to_i8ord();
+        if v <= low {
+            low = v;
+            low_index = i;
+        }
+    }
+    low_index
+}
+
+pub(crate) fn scalar_argmax_f8_return_nan_last<T: Float8>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut high_index: usize = 0;
+    let mut high: i8 = unsafe { *arr.get_unchecked(high_index) }.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // Return the index of the first NaN value
+            return i;
+        }
+        let v: i8 = v.to_i8ord();
+        if v >= high {
+            high = v;
+            high_index = i;
+        }
+    }
+    high_index
+}
+
+pub(crate) fn scalar_argminmax_f8_ignore_nan_last<T: Float8>(arr: &[T]) -> (usize, T, usize, T) {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut high_index: usize = 0;
+    let mut low: i8 = T::MAX_VALUE.to_i8ord();
+    let mut high: i8 = T::MIN_VALUE.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i8 = v.to_i8ord();
+            if v <= low {
+                low = v;
+                low_index = i;
+            }
+            if v >= high {
+                high = v;
+                high_index = i;
+            }
+        }
+    }
+    (
+        low_index,
+        T::from_i8ord(low),
+        high_index,
+        T::from_i8ord(high),
+    )
+}
+
+pub(crate) fn scalar_argmin_f8_ignore_nan_last<T: Float8>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut low_index: usize = 0;
+    let mut low: i8 = T::MAX_VALUE.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i8 = v.to_i8ord();
+            if v <= low {
+                low = v;
+                low_index = i;
+            }
+        }
+    }
+    low_index
+}
+
+pub(crate) fn scalar_argmax_f8_ignore_nan_last<T: Float8>(arr: &[T]) -> usize {
+    assert!(!arr.is_empty());
+    let mut high_index: usize = 0;
+    let mut high: i8 = T::MIN_VALUE.to_i8ord();
+    for i in 0..arr.len() {
+        let v: T = unsafe { *arr.get_unchecked(i) };
+        if v.is_nan_bits() {
+            // v is NaN, ignore it (do nothing)
+        } else {
+            // v is not NaN
+            let v: i8 = v.to_i8ord();
+            if v >= high {
+                high = v;
+                high_index = i;
+            }
+        }
+    }
+    high_index
+}
+
+// ======================================= TESTS =======================================
+
+#[cfg(all(feature = "float", feature = "float8"))]
+#[cfg(test)]
+mod tests {
+    use super::{
+        scalar_argmax_f8_ignore_nan, scalar_argmin_f8_ignore_nan, scalar_argminmax_f8_ignore_nan,
+    };
+    use super::{
+        scalar_argmax_f8_return_nan, scalar_argmin_f8_return_nan, scalar_argminmax_f8_return_nan,
+    };
+    use super::{Float8, F8E4M3, F8E5M2};
+    use crate::{FloatIgnoreNaN, FloatReturnNaN, ScalarArgMinMax, SCALAR};
+
+    use dev_utils::utils;
+
+    const ARR_LEN: usize = 1025;
+
+    /// Get the (min_index, max_index) of a (min_index, min_value, max_index, max_value)
+    fn indices<T>((min_index, _, max_index, _): (usize, T, usize, T)) -> (usize, usize) {
+        (min_index, max_index)
+    }
+
+    fn get_arrays<T: Float8>(len: usize, to_f32: fn(T) -> f32) -> (Vec<f32>, Vec<T>) {
+        // random bit patterns (NaNs are replaced by 0) so that all values are covered
+        let v = utils::get_random_array(len, u8::MIN, u8::MAX);
+        let vec_f8: Vec<T> = v
+            .iter()
+            .map(|x| T::from_u8(*x))
+            .map(|x| if x.is_nan_bits() { T::from_u8(0) } else { x })
+            .collect();
+        let vec_f32: Vec<f32> = vec_f8.iter().map(|x| to_f32(*x)).collect();
+        (vec_f32, vec_f8)
+    }
+
+    fn _test_return_the_same_results<T: Float8>(to_f32: fn(T) -> f32) {
+        for _ in 0..100 {
+            let (vec_f32, vec_f8) = get_arrays::<T>(ARR_LEN, to_f32);
+            let data_f32: &[f32] = &vec_f32;
+            let data_f8: &[T] = &vec_f8;
+            // Return NaN
+            let (argmin_index, argmax_index) = SCALAR::<FloatReturnNaN>::argminmax(data_f32);
+            let (argmin_index_f8, argmax_index_f8) =
+                indices(scalar_argminmax_f8_return_nan(data_f8));
+            // Note: -0.0 == 0.0 in f32, but not in the ordinal representation
+            assert_eq!(data_f32[argmin_index], data_f32[argmin_index_f8]);
+            assert_eq!(data_f32[argmax_index], data_f32[argmax_index_f8]);
+            assert_eq!(argmin_index_f8, scalar_argmin_f8_return_nan(data_f8));
+            assert_eq!(argmax_index_f8, scalar_argmax_f8_return_nan(data_f8));
+            // Ignore NaN
+            let (argmin_index, argmax_index) = SCALAR::<FloatIgnoreNaN>::argminmax(data_f32);
+            let (argmin_index_f8, argmax_index_f8) =
+                indices(scalar_argminmax_f8_ignore_nan(data_f8));
+            assert_eq!(data_f32[argmin_index], data_f32[argmin_index_f8]);
+            assert_eq!(data_f32[argmax_index], data_f32[argmax_index_f8]);
+            assert_eq!(argmin_index_f8, scalar_argmin_f8_ignore_nan(data_f8));
+            assert_eq!(argmax_index_f8, scalar_argmax_f8_ignore_nan(data_f8));
+        }
+    }
+
+    fn _test_nans<T: Float8>(to_f32: fn(T) -> f32, nan_bits: &[u8]) {
+        // first, middle, last element
+        let nan_pos: [usize; 3] = [0, ARR_LEN / 2, ARR_LEN - 1];
+        for pos in nan_pos.iter() {
+            for nan in nan_bits.iter() {
+                let (vec_f32, vec_f8) = get_arrays::<T>(ARR_LEN, to_f32);
+                let mut data_f32: Vec<f32> = vec_f32;
+                let mut data_f8: Vec<T> = vec_f8;
+                data_f32[*pos] = f32::NAN;
+                data_f8[*pos] = T::from_u8(*nan);
+                // Return NaN
+                assert_eq!(
+                    indices(scalar_argminmax_f8_return_nan(&data_f8)),
+                    (*pos, *pos)
+                );
+                assert_eq!(scalar_argmin_f8_return_nan(&data_f8), *pos);
+                assert_eq!(scalar_argmax_f8_return_nan(&data_f8), *pos);
+                // Ignore NaN
+                let (argmin_index, argmax_index) = SCALAR::<FloatIgnoreNaN>::argminmax(&data_f32);
+                let (argmin_index_f8, argmax_index_f8) =
+                    indices(scalar_argminmax_f8_ignore_nan(&data_f8));
+                assert_eq!(data_f32[argmin_index], data_f32[argmin_index_f8]);
+                assert_eq!(data_f32[argmax_index], data_f32[argmax_index_f8]);
+                assert_ne!(argmin_index_f8, *pos);
+                assert_ne!(argmax_index_f8, *pos);
+            }
+        }
+
+        // all elements are NaN
+        let data_f8: Vec<T> = vec![T::from_u8(nan_bits[0]); ARR_LEN];
+        assert_eq!(indices(scalar_argminmax_f8_return_nan(&data_f8)), (0, 0));
+        assert_eq!(indices(scalar_argminmax_f8_ignore_nan(&data_f8)), (0, 0));
+    }
+
+    #[test]
+    fn test_generic_and_specific_impl_return_the_same_results() {
+        _test_return_the_same_results(|x: F8E4M3| x.to_f32());
+        _test_return_the_same_results(|x: F8E5M2| x.to_f32());
+    }
+
+    #[test]
+    fn test_generic_and_specific_impl_nans() {
+        _test_nans(|x: F8E4M3| x.to_f32(), &[0x7F, 0xFF]);
+        _test_nans(
+            |x: F8E5M2| x.to_f32(),
+            &[0x7D, 0x7E, 0x7F, 0xFD, 0xFE, 0xFF],
+        );
+    }
+
+    #[test]
+    fn test_infinities_are_not_nan() {
+        // E5M2 has infinities, which are the largest / smallest non-NaN values
+        let data: Vec<F8E5M2> = [0x00, 0x7C, 0x3C, 0xFC, 0x7B]
+            .iter()
+            .map(|x| F8E5M2::from_bits(*x))
+            .collect();
+        assert_eq!(
+            scalar_argminmax_f8_return_nan(&data),
+            (3, data[3], 1, data[1])
+        );
+        assert_eq!(
+            scalar_argminmax_f8_ignore_nan(&data),
+            (3, data[3], 1, data[1])
+        );
+        // E4M3 has no infinities, 0x7E / 0xFE are the largest / smallest values
+        let data: Vec<F8E4M3> = [0x00, 0x7E, 0x3C, 0xFE, 0x7D]
+            .iter()
+            .map(|x| F8E4M3::from_bits(*x))
+            .collect();
+        assert_eq!(
+            scalar_argminmax_f8_return_nan(&data),
+            (3, data[3], 1, data[1])
+        );
+        assert_eq!(
+            scalar_argminmax_f8_ignore_nan(&data),
+            (3, data[3], 1, data[1])
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use num_traits::AsPrimitive;
+
+/// Segments shorter than this (number of elements) are processed with the scalar
+/// implementation - the SIMD kernels barely help for these, while the scalar
+/// implementation can be inlined in the loop over the (many) short segments.
+const SHORT_SEGMENT_LEN: usize = 64;
+
+/// Trait for finding the minimum and maximum values of each segment in an array,
+/// where the segments are described by an offsets array (as in apache arrow its
+/// `ListArray`). For floats, NaNs are ignored.
+///
+/// This trait is implemented for slices and `Vec`s of the same data types as
+/// [`ArgMinMax`](crate::ArgMinMax). Segment `i` consists of the values in
+/// `offsets[i]..offsets[i + 1]`, the offsets can be of any integer type (e.g., the
+/// `i32` or `i64` offsets of arrow).
+///
+pub trait SegmentedArgMinMax {
+    /// Get the index of the minimum and maximum values of each segment.
+    ///
+    /// When dealing with floats, NaNs are ignored.
+    ///
+    /// # Returns
+    /// For each segment, a tuple of the index (in the array, not in the segment) of
+    /// the minimum and maximum values `(min_index, max_index)`, or `None` when the
+    /// segment is empty.
+    ///
+    /// # Caution
+    /// When a segment contains *only* NaNs, the index of its first value is returned.
+    ///
+    /// # Panics
+    /// When the offsets are decreasing or out of bounds.
+    ///
+    fn segmented_argminmax<O: AsPrimitive<usize>>(
+        &self,
+        offsets: &[O],
+    ) -> Vec<Option<(usize, usize)>>;
+}
+
+/// Trait for finding the minimum and maximum values of each segment in an array,
+/// where the segments are described by an offsets array. For floats, NaNs are
+/// propagated - index of the first NaN in the segment is returned.
+///
+/// See [`SegmentedArgMinMax`] for the supported types and the segments.
+///
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+pub trait NaNSegmentedArgMinMax {
+    /// Get the index of the minimum and maximum values of each segment.
+    ///
+    /// When dealing with floats, NaNs are propagated - index of the first NaN in the
+    /// segment is returned.
+    ///
+    /// # Returns
+    /// For each segment, a tuple of the index (in the array, not in the segment) of
+    /// the minimum and maximum values `(min_index, max_index)`, or `None` when the
+    /// segment is empty.
+    ///
+    /// # Panics
+    /// When the offsets are decreasing or out of bounds.
+    ///
+    fn segmented_nanargminmax<O: AsPrimitive<usize>>(
+        &self,
+        offsets: &[O],
+    ) -> Vec<Option<(usize, usize)>>;
+}
+
+/// Get the (min_index, max_index) of each segment.
+///
+/// - `slice_fn` returns the (min_index, max_index) of a long segment (e.g., the SIMD
+///   kernel that is selected once for all the segments)
+/// - `scalar_fn` returns the (min_index, max_index) of a short segment (shorter than
+///   `SHORT_SEGMENT_LEN`), without any runtime feature detection
+///
+pub(crate) fn segmented_argminmax_generic<T: Copy, O: AsPrimitive<usize>>(
+    values: &[T],
+    offsets: &[O],
+    slice_fn: impl Fn(&[T]) -> (usize, usize),
+    scalar_fn: impl Fn(&[T]) -> (usize, usize),
+) -> Vec<Option<(usize, usize)>> {
+    offsets
+        .windows(2)
+        .map(|bounds| {
+            let (start, end): (usize, usize) = (bounds[0].as_(), bounds[1].as_());
+            let segment = &values[start..end];
+            let (min_index, max_index) = match segment.len() {
+                0 => return None,
+                len if len < SHORT_SEGMENT_LEN => scalar_fn(segment),
+                _ => slice_fn(segment),
+            };
+            Some((start + min_index, start + max_index))
+        })
+        .collect()
+}
@@ -18,6 +18,7 @@ pub(crate) trait SIMDInstructionSet {
     const LANE_SIZE_16: usize = Self::REGISTER_SIZE / (std::mem::size_of::<u16>() * 8);
     const LANE_SIZE_32: usize = Self::REGISTER_SIZE / (std::mem::size_of::<u32>() * 8);
     const LANE_SIZE_64: usize = Self::REGISTER_SIZE / (std::mem::size_of::<u64>() * 8);
+    const LANE_SIZE_128: usize = Self::REGISTER_SIZE / (std::mem::size_of::<u128>() * 8);
 
     fn get_lane_size<DType>() -> usize {
         Self::REGISTER_SIZE / (std::mem::size_of::<DType>() * 8)
@@ -136,7 +137,7 @@ mod tests {
     use half::f16;
 
     // The DTypeStrategy should not influence the lane size
-    #[cfg(any(feature = "float", feature = "half"))]
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
     #[template]
     #[rstest]
     #[case::int(Int)]
@@ -144,7 +145,7 @@ mod tests {
     #[case::float_ignore_nan(FloatReturnNaN)]
     fn dtype_strategies<DTypeStrategy>(#[case] _dtype_strategy: DTypeStrategy) {}
 
-    #[cfg(not(any(feature = "float", feature = "half")))]
+    #[cfg(not(any(feature = "float", feature = "half", feature = "float8")))]
     #[template]
     #[rstest]
     #[case::int(Int)]
@@ -214,6 +215,15 @@ mod tests {
         assert_eq!(NEON::<DTypeStrategy>::get_lane_size::<i64>(), 2);
     }
 
+    #[apply(dtype_strategies)]
+    fn test_lane_size_i128<DTypeStrategy>(#[case] _dtype_strategy: DTypeStrategy) {
+        assert_eq!(SSE::<DTypeStrategy>::get_lane_size::<i128>(), 1);
+        assert_eq!(AVX2::<DTypeStrategy>::get_lane_size::<i128>(), 2);
+        #[cfg(feature = "nightly_simd")]
+        assert_eq!(AVX512::<DTypeStrategy>::get_lane_size::<i128>(), 4);
+        assert_eq!(NEON::<DTypeStrategy>::get_lane_size::<i128>(), 1);
+    }
+
     #[apply(dtype_strategies)]
     fn test_lane_size_u8<DTypeStrategy>(#[case] _dtype_strategy: DTypeStrategy) {
         assert_eq!(SSE::<DTypeStrategy>::get_lane_size::<u8>(), 16);
@@ -249,4 +259,13 @@ mod tests {
         assert_eq!(AVX512::<DTypeStrategy>::get_lane_size::<u64>(), 8);
         assert_eq!(NEON::<DTypeStrategy>::get_lane_size::<u64>(), 2);
     }
+
+    #[apply(dtype_strategies)]
+    fn test_lane_size_u128<DTypeStrategy>(#[case] _dtype_strategy: DTypeStrategy) {
+        assert_eq!(SSE::<DTypeStrategy>::get_lane_size::<u128>(), 1);
+        assert_eq!(AVX2::<DTypeStrategy>::get_lane_size::<u128>(), 2);
+        #[cfg(feature = "nightly_simd")]
+        assert_eq!(AVX512::<DTypeStrategy>::get_lane_size::<u128>(), 4);
+        assert_eq!(NEON::<DTypeStrategy>::get_lane_size::<u128>(), 1);
+    }
 }
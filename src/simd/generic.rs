@@ -53,7 +53,10 @@ where
     /// Horizontal min: get the minimum value from the value SIMD register and its
     /// corresponding index from the index SIMD register
     #[inline(always)]
-    unsafe fn _horiz_min(index: SIMDVecDtype, value: SIMDVecDtype) -> (usize, ScalarDType) {
+    unsafe fn _horiz_min<const LAST: bool>(
+        index: SIMDVecDtype,
+        value: SIMDVecDtype,
+    ) -> (usize, ScalarDType) {
         // This becomes the bottleneck when using 8-bit data types, as for  every 2**7
         // or 2**8 elements, the SIMD inner loop is executed (& thus also terminated)
         // to avoid overflow.
@@ -64,14 +67,17 @@ where
         // the SIMD inner loop is 2**8 times less frequent.
         let index_arr = Self::_reg_to_arr(index);
         let value_arr = Self::_reg_to_arr(value);
-        let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+        let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
         (min_index.as_(), min_value)
     }
 
     /// Horizontal max: get the maximum value from the value SIMD register and its
     /// corresponding index from the index SIMD register
     #[inline(always)]
-    unsafe fn _horiz_max(index: SIMDVecDtype, value: SIMDVecDtype) -> (usize, ScalarDType) {
+    unsafe fn _horiz_max<const LAST: bool>(
+        index: SIMDVecDtype,
+        value: SIMDVecDtype,
+    ) -> (usize, ScalarDType) {
         // This becomes the bottleneck when using 8-bit data types, as for  every 2**7
         // or 2**8 elements, the SIMD inner loop is executed (& thus also terminated)
         // to avoid overflow.
@@ -82,7 +88,7 @@ where
         // the SIMD inner loop is 2**8 times less frequent.
         let index_arr = Self::_reg_to_arr(index);
         let value_arr = Self::_reg_to_arr(value);
-        let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+        let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
         (max_index.as_(), max_value)
     }
 
@@ -152,6 +158,20 @@ where
         (Self::INITIAL_INDEX, Self::_mm_loadu(arr_ptr))
     }
 
+    // Value preparation for the non-strict comparisons of _core_argminmax (when LAST)
+
+    #[inline(always)]
+    unsafe fn _replace_nan_low(values: SIMDVecDtype) -> SIMDVecDtype {
+        // No NaNs that need to be ignored
+        values
+    }
+
+    #[inline(always)]
+    unsafe fn _replace_nan_high(values: SIMDVecDtype) -> SIMDVecDtype {
+        // No NaNs that need to be ignored
+        values
+    }
+
     /// Initialization for _overflow_safe_core_argminmax
 
     #[inline(always)]
@@ -197,10 +217,23 @@ pub(crate) use impl_SIMDInit_Int; // Now classic paths Just Work™
 
 // --------------- Float Return NaNs
 
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
 macro_rules! impl_SIMDInit_FloatReturnNaN {
     ($scalar_dtype:ty, $simd_vec_dtype:ty, $simd_mask_dtype:ty, $lane_size:expr, $simd_struct:ty) => {
+        impl_SIMDInit_FloatReturnNaN!(
+            $scalar_dtype,
+            $simd_vec_dtype,
+            $simd_mask_dtype,
+            $lane_size,
+            $simd_struct,
+            <$scalar_dtype>::is_nan
+        );
+    };
+    // $nan_check is the function that checks whether a scalar value is NaN - this
+    // should be passed when the `is_nan` method of the data type is not suitable (e.g.,
+    // for FP8, where we check for NaN on the bit representation)
+    ($scalar_dtype:ty, $simd_vec_dtype:ty, $simd_mask_dtype:ty, $lane_size:expr, $simd_struct:ty, $nan_check:expr) => {
         impl SIMDInit<$scalar_dtype, $simd_vec_dtype, $simd_mask_dtype, $lane_size>
             for $simd_struct
         {
@@ -209,24 +242,24 @@ macro_rules! impl_SIMDInit_FloatReturnNaN {
             /// Return when a NaN is found
             #[inline(always)]
             fn _return_check(v: $scalar_dtype) -> bool {
-                v.is_nan()
+                ($nan_check)(v)
             }
 
             #[inline(always)]
             fn _nan_check(v: $scalar_dtype) -> bool {
-                v.is_nan()
+                ($nan_check)(v)
             }
         }
     };
 }
 
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
 pub(crate) use impl_SIMDInit_FloatReturnNaN; // Now classic paths Just Work™
 
 // --------------- Float Ignore NaNs
 
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[cfg(any(
     target_arch = "x86",
     target_arch = "x86_64",
@@ -235,6 +268,22 @@ pub(crate) use impl_SIMDInit_FloatReturnNaN; // Now classic paths Just Work™
 ))]
 macro_rules! impl_SIMDInit_FloatIgnoreNaN {
     ($scalar_dtype:ty, $simd_vec_dtype:ty, $simd_mask_dtype:ty, $lane_size:expr, $simd_struct:ty) => {
+        impl_SIMDInit_FloatIgnoreNaN!(
+            $scalar_dtype,
+            $simd_vec_dtype,
+            $simd_mask_dtype,
+            $lane_size,
+            $simd_struct,
+            <$scalar_dtype>::is_nan,
+            <$scalar_dtype>::INFINITY,
+            <$scalar_dtype>::NEG_INFINITY
+        );
+    };
+    // $nan_check is the function that checks whether a scalar value is NaN, $infinity
+    // and $neg_infinity are the largest and smallest non-NaN values - these should be
+    // passed when the data type has no (or non-standard) NaN / infinity methods (e.g.,
+    // FP8 E4M3 has no infinities)
+    ($scalar_dtype:ty, $simd_vec_dtype:ty, $simd_mask_dtype:ty, $lane_size:expr, $simd_struct:ty, $nan_check:expr, $infinity:expr, $neg_infinity:expr) => {
         impl SIMDInit<$scalar_dtype, $simd_vec_dtype, $simd_mask_dtype, $lane_size>
             for $simd_struct
         {
@@ -242,22 +291,68 @@ macro_rules! impl_SIMDInit_FloatIgnoreNaN {
 
             const IGNORE_NAN: bool = true;
 
+            // Note: the SIMDOps methods are called through the fully qualified path, as
+            // the same SIMD struct can implement SIMDOps for multiple scalar data types
+            // with the same SIMD vector type (e.g., f16 and bf16).
+
             #[inline(always)]
             unsafe fn _initialize_index_values_low(
                 arr_ptr: *const $scalar_dtype,
             ) -> ($simd_vec_dtype, $simd_vec_dtype) {
                 // Initialize the index and value SIMD registers
-                let new_values = Self::_mm_loadu(arr_ptr);
-                let mask_low =
-                    Self::_mm_cmplt(new_values, Self::_mm_set1(<$scalar_dtype>::INFINITY));
-                let values_low = Self::_mm_blendv(
-                    Self::_mm_set1(<$scalar_dtype>::INFINITY),
+                let new_values = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_loadu(arr_ptr);
+                let mask_low = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_cmplt(
+                    new_values,
+                    <Self as SIMDOps<
+                        $scalar_dtype,
+                        $simd_vec_dtype,
+                        $simd_mask_dtype,
+                        $lane_size,
+                    >>::_mm_set1($infinity),
+                );
+                let values_low = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_blendv(
+                    <Self as SIMDOps<
+                        $scalar_dtype,
+                        $simd_vec_dtype,
+                        $simd_mask_dtype,
+                        $lane_size,
+                    >>::_mm_set1($infinity),
                     new_values,
                     mask_low,
                 );
-                let index_low = Self::_mm_blendv(
-                    Self::_mm_set1(<$scalar_dtype>::zero()),
-                    Self::INITIAL_INDEX,
+                let index_low = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_blendv(
+                    <Self as SIMDOps<
+                        $scalar_dtype,
+                        $simd_vec_dtype,
+                        $simd_mask_dtype,
+                        $lane_size,
+                    >>::_mm_set1(<$scalar_dtype>::zero()),
+                    <Self as SIMDOps<
+                        $scalar_dtype,
+                        $simd_vec_dtype,
+                        $simd_mask_dtype,
+                        $lane_size,
+                    >>::INITIAL_INDEX,
                     mask_low,
                 );
                 (index_low, values_low)
@@ -268,41 +363,133 @@ macro_rules! impl_SIMDInit_FloatIgnoreNaN {
                 arr_ptr: *const $scalar_dtype,
             ) -> ($simd_vec_dtype, $simd_vec_dtype) {
                 // Initialize the index and value SIMD registers
-                let new_values = Self::_mm_loadu(arr_ptr);
-                let mask_high =
-                    Self::_mm_cmpgt(new_values, Self::_mm_set1(<$scalar_dtype>::NEG_INFINITY));
-                let values_high = Self::_mm_blendv(
-                    Self::_mm_set1(<$scalar_dtype>::NEG_INFINITY),
+                let new_values = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_loadu(arr_ptr);
+                let mask_high = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_cmpgt(
+                    new_values,
+                    <Self as SIMDOps<
+                        $scalar_dtype,
+                        $simd_vec_dtype,
+                        $simd_mask_dtype,
+                        $lane_size,
+                    >>::_mm_set1($neg_infinity),
+                );
+                let values_high = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_blendv(
+                    <Self as SIMDOps<
+                        $scalar_dtype,
+                        $simd_vec_dtype,
+                        $simd_mask_dtype,
+                        $lane_size,
+                    >>::_mm_set1($neg_infinity),
                     new_values,
                     mask_high,
                 );
-                let index_high = Self::_mm_blendv(
-                    Self::_mm_set1(<$scalar_dtype>::zero()),
-                    Self::INITIAL_INDEX,
+                let index_high = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_blendv(
+                    <Self as SIMDOps<
+                        $scalar_dtype,
+                        $simd_vec_dtype,
+                        $simd_mask_dtype,
+                        $lane_size,
+                    >>::_mm_set1(<$scalar_dtype>::zero()),
+                    <Self as SIMDOps<
+                        $scalar_dtype,
+                        $simd_vec_dtype,
+                        $simd_mask_dtype,
+                        $lane_size,
+                    >>::INITIAL_INDEX,
                     mask_high,
                 );
                 (index_high, values_high)
             }
 
+            #[inline(always)]
+            unsafe fn _replace_nan_low(values: $simd_vec_dtype) -> $simd_vec_dtype {
+                // Replace the NaNs by +infinity (as the non-strict comparisons
+                // would otherwise select them)
+                let infinity = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_set1($infinity);
+                let mask = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_cmplt(values, infinity);
+                let values = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_blendv(infinity, values, mask);
+                values
+            }
+
+            #[inline(always)]
+            unsafe fn _replace_nan_high(values: $simd_vec_dtype) -> $simd_vec_dtype {
+                // Replace the NaNs by -infinity (as the non-strict comparisons
+                // would otherwise select them)
+                let infinity = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_set1($neg_infinity);
+                let mask = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_cmpgt(values, infinity);
+                let values = <Self as SIMDOps<
+                    $scalar_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_blendv(infinity, values, mask);
+                values
+            }
+
             #[inline(always)]
             fn _initialize_min_value(_: &[$scalar_dtype]) -> $scalar_dtype {
-                <$scalar_dtype>::INFINITY
+                $infinity
             }
 
             #[inline(always)]
             fn _initialize_max_value(_: &[$scalar_dtype]) -> $scalar_dtype {
-                <$scalar_dtype>::NEG_INFINITY
+                $neg_infinity
             }
 
             #[inline(always)]
             fn _nan_check(v: $scalar_dtype) -> bool {
-                v.is_nan()
+                ($nan_check)(v)
             }
         }
     };
 }
 
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[cfg(any(
     target_arch = "x86",
     target_arch = "x86_64",
@@ -311,6 +498,277 @@ macro_rules! impl_SIMDInit_FloatIgnoreNaN {
 ))]
 pub(crate) use impl_SIMDInit_FloatIgnoreNaN; // Now classic paths Just Work™
 
+// --------------- Ordinal floats (f16, bf16 and FP8)
+
+/// Macros for implementing the SIMD traits for the float data types that have no SIMD
+/// instructions of their own (f16, bf16, F8E4M3 and F8E5M2).
+/// These data types are viewed as ordinal signed integers of the same width (see the
+/// `simd_f16_*.rs` and `simd_f8_*.rs` files), so that the SIMDOps of that integer data
+/// type (the `$int_simd_struct`) can be reused:
+/// - `$to_ord` transforms a loaded SIMD register to ordinal integers
+/// - `$from_ord` transforms the (ordinal) horizontal min / max value back to the float
+///
+/// For the return NaN case, the ordinal integer comparisons are used as is (NaNs are
+/// the largest / smallest ordinal values and thus always end up in the accumulating
+/// SIMD register).
+/// For the ignore NaN case, `$non_nan_mask` clears the comparison mask for the NaN
+/// lanes and `$set1` broadcasts the ordinal value of a scalar.
+///
+/// The scalar data type should implement `is_nan_bits`, `MAX_VALUE` and `MIN_VALUE`
+/// (see the `Float16` and `Float8` traits).
+#[cfg(any(feature = "half", feature = "float8"))]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+macro_rules! impl_SIMDOps_OrdinalFloat {
+    ($scalar_dtype:ty, $simd_vec_dtype:ty, $simd_mask_dtype:ty, $lane_size:expr, $simd_struct:ty, $int_dtype:ty, $int_simd_struct:ty, $to_ord:expr, $from_ord:expr $(, $non_nan_mask:expr, $set1:expr)?) => {
+        impl SIMDOps<$scalar_dtype, $simd_vec_dtype, $simd_mask_dtype, $lane_size>
+            for $simd_struct
+        {
+            const INITIAL_INDEX: $simd_vec_dtype = <$int_simd_struct as SIMDOps<
+                $int_dtype,
+                $simd_vec_dtype,
+                $simd_mask_dtype,
+                $lane_size,
+            >>::INITIAL_INDEX;
+            const INDEX_INCREMENT: $simd_vec_dtype = <$int_simd_struct as SIMDOps<
+                $int_dtype,
+                $simd_vec_dtype,
+                $simd_mask_dtype,
+                $lane_size,
+            >>::INDEX_INCREMENT;
+            const MAX_INDEX: usize = <$int_simd_struct as SIMDOps<
+                $int_dtype,
+                $simd_vec_dtype,
+                $simd_mask_dtype,
+                $lane_size,
+            >>::MAX_INDEX;
+
+            #[inline(always)]
+            unsafe fn _reg_to_arr(_: $simd_vec_dtype) -> [$scalar_dtype; $lane_size] {
+                // Not implemented because we will perform the horizontal operations on the
+                // signed integer values instead of trying to retransform **only** the values
+                // (and thus not the indices) to floats.
+                unimplemented!()
+            }
+
+            #[inline(always)]
+            unsafe fn _mm_loadu(data: *const $scalar_dtype) -> $simd_vec_dtype {
+                ($to_ord)(<$int_simd_struct as SIMDOps<
+                    $int_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_loadu(data as *const $int_dtype))
+            }
+
+            #[inline(always)]
+            unsafe fn _mm_add(a: $simd_vec_dtype, b: $simd_vec_dtype) -> $simd_vec_dtype {
+                <$int_simd_struct as SIMDOps<
+                    $int_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_add(a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn _mm_cmpgt(a: $simd_vec_dtype, b: $simd_vec_dtype) -> $simd_mask_dtype {
+                let mask = <$int_simd_struct as SIMDOps<
+                    $int_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_cmpgt(a, b);
+                $(let mask = ($non_nan_mask)(mask, a);)?
+                mask
+            }
+
+            #[inline(always)]
+            unsafe fn _mm_cmplt(a: $simd_vec_dtype, b: $simd_vec_dtype) -> $simd_mask_dtype {
+                let mask = <$int_simd_struct as SIMDOps<
+                    $int_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_cmplt(a, b);
+                $(let mask = ($non_nan_mask)(mask, a);)?
+                mask
+            }
+
+            #[inline(always)]
+            unsafe fn _mm_blendv(
+                a: $simd_vec_dtype,
+                b: $simd_vec_dtype,
+                mask: $simd_mask_dtype,
+            ) -> $simd_vec_dtype {
+                <$int_simd_struct as SIMDOps<
+                    $int_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_mm_blendv(a, b, mask)
+            }
+
+            #[inline(always)]
+            unsafe fn _horiz_min<const LAST: bool>(
+                index: $simd_vec_dtype,
+                value: $simd_vec_dtype,
+            ) -> (usize, $scalar_dtype) {
+                let (min_index, min_value) = <$int_simd_struct as SIMDOps<
+                    $int_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_horiz_min::<LAST>(index, value);
+                (min_index, ($from_ord)(min_value))
+            }
+
+            #[inline(always)]
+            unsafe fn _horiz_max<const LAST: bool>(
+                index: $simd_vec_dtype,
+                value: $simd_vec_dtype,
+            ) -> (usize, $scalar_dtype) {
+                let (max_index, max_value) = <$int_simd_struct as SIMDOps<
+                    $int_dtype,
+                    $simd_vec_dtype,
+                    $simd_mask_dtype,
+                    $lane_size,
+                >>::_horiz_max::<LAST>(index, value);
+                (max_index, ($from_ord)(max_value))
+            }
+
+            $(
+                // --- Necessary for impl_SIMDInit_FloatIgnoreNaN!
+
+                #[inline(always)]
+                unsafe fn _mm_set1(a: $scalar_dtype) -> $simd_vec_dtype {
+                    ($set1)(a)
+                }
+            )?
+        }
+    };
+}
+
+#[cfg(any(feature = "half", feature = "float8"))]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+macro_rules! impl_SIMD_OrdinalFloatReturnNaN {
+    ($scalar_dtype:ty, $simd_vec_dtype:ty, $simd_mask_dtype:ty, $lane_size:expr, $simd_struct:ty, $target:expr, $int_dtype:ty, $int_simd_struct:ty, $to_ord:expr, $from_ord:expr) => {
+        impl_SIMDOps_OrdinalFloat!(
+            $scalar_dtype,
+            $simd_vec_dtype,
+            $simd_mask_dtype,
+            $lane_size,
+            $simd_struct,
+            $int_dtype,
+            $int_simd_struct,
+            $to_ord,
+            $from_ord
+        );
+
+        impl_SIMDInit_FloatReturnNaN!(
+            $scalar_dtype,
+            $simd_vec_dtype,
+            $simd_mask_dtype,
+            $lane_size,
+            $simd_struct,
+            <$scalar_dtype>::is_nan_bits
+        );
+
+        impl
+            SIMDArgMinMax<
+                $scalar_dtype,
+                $simd_vec_dtype,
+                $simd_mask_dtype,
+                $lane_size,
+                SCALAR<FloatReturnNaN>,
+            > for $simd_struct
+        {
+            #[target_feature(enable = $target)]
+            unsafe fn argminmax(data: &[$scalar_dtype]) -> (usize, usize) {
+                Self::_argminmax(data)
+            }
+
+            #[target_feature(enable = $target)]
+            unsafe fn argminmax_with_values(
+                data: &[$scalar_dtype],
+            ) -> (usize, $scalar_dtype, usize, $scalar_dtype) {
+                Self::_argminmax_with_values(data)
+            }
+
+            unsafe fn argmin(data: &[$scalar_dtype]) -> usize {
+                Self::argminmax(data).0
+            }
+
+            unsafe fn argmax(data: &[$scalar_dtype]) -> usize {
+                Self::argminmax(data).1
+            }
+
+            #[target_feature(enable = $target)]
+            unsafe fn argminmax_last(data: &[$scalar_dtype]) -> (usize, usize) {
+                Self::_argminmax_last(data)
+            }
+
+            unsafe fn argmin_last(data: &[$scalar_dtype]) -> usize {
+                Self::argminmax_last(data).0
+            }
+
+            unsafe fn argmax_last(data: &[$scalar_dtype]) -> usize {
+                Self::argminmax_last(data).1
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "half", feature = "float8"))]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+macro_rules! impl_SIMD_OrdinalFloatIgnoreNaN {
+    ($scalar_dtype:ty, $simd_vec_dtype:ty, $simd_mask_dtype:ty, $lane_size:expr, $simd_struct:ty, $target:expr, $int_dtype:ty, $int_simd_struct:ty, $to_ord:expr, $from_ord:expr, $non_nan_mask:expr, $set1:expr) => {
+        impl_SIMDOps_OrdinalFloat!(
+            $scalar_dtype,
+            $simd_vec_dtype,
+            $simd_mask_dtype,
+            $lane_size,
+            $simd_struct,
+            $int_dtype,
+            $int_simd_struct,
+            $to_ord,
+            $from_ord,
+            $non_nan_mask,
+            $set1
+        );
+
+        impl_SIMDInit_FloatIgnoreNaN!(
+            $scalar_dtype,
+            $simd_vec_dtype,
+            $simd_mask_dtype,
+            $lane_size,
+            $simd_struct,
+            <$scalar_dtype>::is_nan_bits,
+            <$scalar_dtype>::MAX_VALUE,
+            <$scalar_dtype>::MIN_VALUE
+        );
+
+        impl_SIMDArgMinMax!(
+            $scalar_dtype,
+            $simd_vec_dtype,
+            $simd_mask_dtype,
+            $lane_size,
+            SCALAR<FloatIgnoreNaN>,
+            $simd_struct,
+            $target
+        );
+    };
+}
+
+#[cfg(any(feature = "half", feature = "float8"))]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+pub(crate) use impl_SIMDOps_OrdinalFloat; // Now classic paths Just Work™
+#[cfg(any(feature = "half", feature = "float8"))]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+pub(crate) use impl_SIMD_OrdinalFloatIgnoreNaN; // Now classic paths Just Work™
+#[cfg(any(feature = "half", feature = "float8"))]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+pub(crate) use impl_SIMD_OrdinalFloatReturnNaN; // Now classic paths Just Work™
+
 // ---------------------------------- SIMD algorithm -----------------------------------
 
 /// The SIMDCore trait (for all data types).
@@ -338,12 +796,17 @@ where
     /// Note that this method is not overflow safe, as it assumes that the array length
     /// is <= MAX_INDEX. The `_overflow_safe_core_argminmax` method is overflow safe.
     ///
+    /// When `LAST` is true, the index of the last occurrence of the min/max value is
+    /// returned (through non-strict comparisons and the horizontal min/max).
+    ///
     #[inline(always)]
-    unsafe fn _core_argminmax(arr: &[ScalarDType]) -> (usize, ScalarDType, usize, ScalarDType) {
+    unsafe fn _core_argminmax<const LAST: bool>(
+        arr: &[ScalarDType],
+    ) -> (usize, ScalarDType, usize, ScalarDType) {
         assert_eq!(arr.len() % LANE_SIZE, 0);
         // Efficient calculation of argmin and argmax together
 
-        let mut arr_ptr = arr.as_ptr(); // Array pointer we will increment in the loop
+        let mut arr_ptr = arr.as_ptr(); // Array pointer we will move in the loop
         let mut new_index = Self::INITIAL_INDEX; // Index we will increment in the loop
         let (mut index_low, mut values_low) = Self::_initialize_index_values_low(arr_ptr);
         let (mut index_high, mut values_high) = Self::_initialize_index_values_high(arr_ptr);
@@ -375,19 +838,37 @@ where
             let new_values = Self::_mm_loadu(arr_ptr);
 
             // Update the lowest values and index
-            let mask_low = Self::_mm_cmplt(new_values, values_low);
-            values_low = Self::_mm_blendv(values_low, new_values, mask_low);
-            index_low = Self::_mm_blendv(index_low, new_index, mask_low);
+            if LAST {
+                // Non-strict comparison -> keep the old values only when the new values
+                // are strictly larger (i.e., the last index is kept on ties)
+                let new_values = Self::_replace_nan_low(new_values);
+                let mask_low = Self::_mm_cmpgt(new_values, values_low);
+                values_low = Self::_mm_blendv(new_values, values_low, mask_low);
+                index_low = Self::_mm_blendv(new_index, index_low, mask_low);
+            } else {
+                let mask_low = Self::_mm_cmplt(new_values, values_low);
+                values_low = Self::_mm_blendv(values_low, new_values, mask_low);
+                index_low = Self::_mm_blendv(index_low, new_index, mask_low);
+            }
 
             // Update the highest values and index
-            let mask_high = Self::_mm_cmpgt(new_values, values_high);
-            values_high = Self::_mm_blendv(values_high, new_values, mask_high);
-            index_high = Self::_mm_blendv(index_high, new_index, mask_high);
+            if LAST {
+                // Non-strict comparison -> keep the old values only when the new values
+                // are strictly smaller (i.e., the last index is kept on ties)
+                let new_values = Self::_replace_nan_high(new_values);
+                let mask_high = Self::_mm_cmplt(new_values, values_high);
+                values_high = Self::_mm_blendv(new_values, values_high, mask_high);
+                index_high = Self::_mm_blendv(new_index, index_high, mask_high);
+            } else {
+                let mask_high = Self::_mm_cmpgt(new_values, values_high);
+                values_high = Self::_mm_blendv(values_high, new_values, mask_high);
+                index_high = Self::_mm_blendv(index_high, new_index, mask_high);
+            }
         }
 
         // Get the min/max index and corresponding value from the SIMD vectors and return
-        let (min_index, min_value) = Self::_horiz_min(index_low, values_low);
-        let (max_index, max_value) = Self::_horiz_max(index_high, values_high);
+        let (min_index, min_value) = Self::_horiz_min::<LAST>(index_low, values_low);
+        let (max_index, max_value) = Self::_horiz_max::<LAST>(index_high, values_high);
         (min_index, min_value, max_index, max_value)
     }
 
@@ -401,9 +882,12 @@ where
     /// Note that this method is not overflow safe, as it assumes that the array length
     /// is <= MAX_INDEX. The `_overflow_safe_core_argmin` method is overflow safe.
     ///
+    /// When `LAST` is true, the index of the last occurrence of the min value is
+    /// returned (through non-strict comparisons and the horizontal min).
+    ///
     #[inline(always)]
-    unsafe fn _core_argmin(arr: &[ScalarDType]) -> (usize, ScalarDType) {
-        let mut arr_ptr = arr.as_ptr(); // Array pointer we will increment in the loop
+    unsafe fn _core_argmin<const LAST: bool>(arr: &[ScalarDType]) -> (usize, ScalarDType) {
+        let mut arr_ptr = arr.as_ptr(); // Array pointer we will move in the loop
         let mut new_index = Self::INITIAL_INDEX; // Index we will increment in the loop
         let (mut index_low, mut values_low) = Self::_initialize_index_values_low(arr_ptr);
 
@@ -415,13 +899,23 @@ where
             let new_values = Self::_mm_loadu(arr_ptr);
 
             // Update the lowest values and index
-            let mask_low = Self::_mm_cmplt(new_values, values_low);
-            values_low = Self::_mm_blendv(values_low, new_values, mask_low);
-            index_low = Self::_mm_blendv(index_low, new_index, mask_low);
+            if LAST {
+                // Non-strict comparison -> keep the old values only when the new values
+                // are strictly larger (i.e., the last index is kept on ties)
+                let new_values = Self::_replace_nan_low(new_values);
+                let mask_low = Self::_mm_cmpgt(new_values, values_low);
+                values_low = Self::_mm_blendv(new_values, values_low, mask_low);
+                index_low = Self::_mm_blendv(new_index, index_low, mask_low);
+            } else {
+                let mask_low = Self::_mm_cmplt(new_values, values_low);
+                values_low = Self::_mm_blendv(values_low, new_values, mask_low);
+                index_low = Self::_mm_blendv(index_low, new_index, mask_low);
+            }
         }
 
         // Get the min index and corresponding value from the SIMD vectors and return
-        Self::_horiz_min(index_low, values_low)
+        let (min_index, min_value) = Self::_horiz_min::<LAST>(index_low, values_low);
+        (min_index, min_value)
     }
 
     /// Core argmax algorithm - returns (argmax, max)
@@ -434,9 +928,12 @@ where
     /// Note that this method is not overflow safe, as it assumes that the array length
     /// is <= MAX_INDEX. The `_overflow_safe_core_argmax` method is overflow safe.
     ///
+    /// When `LAST` is true, the index of the last occurrence of the max value is
+    /// returned (through non-strict comparisons and the horizontal max).
+    ///
     #[inline(always)]
-    unsafe fn _core_argmax(arr: &[ScalarDType]) -> (usize, ScalarDType) {
-        let mut arr_ptr = arr.as_ptr(); // Array pointer we will increment in the loop
+    unsafe fn _core_argmax<const LAST: bool>(arr: &[ScalarDType]) -> (usize, ScalarDType) {
+        let mut arr_ptr = arr.as_ptr(); // Array pointer we will move in the loop
         let mut new_index = Self::INITIAL_INDEX; // Index we will increment in the loop
         let (mut index_high, mut values_high) = Self::_initialize_index_values_high(arr_ptr);
 
@@ -448,13 +945,23 @@ where
             let new_values = Self::_mm_loadu(arr_ptr);
 
             // Update the highest values and index
-            let mask_high = Self::_mm_cmpgt(new_values, values_high);
-            values_high = Self::_mm_blendv(values_high, new_values, mask_high);
-            index_high = Self::_mm_blendv(index_high, new_index, mask_high);
+            if LAST {
+                // Non-strict comparison -> keep the old values only when the new values
+                // are strictly smaller (i.e., the last index is kept on ties)
+                let new_values = Self::_replace_nan_high(new_values);
+                let mask_high = Self::_mm_cmplt(new_values, values_high);
+                values_high = Self::_mm_blendv(new_values, values_high, mask_high);
+                index_high = Self::_mm_blendv(new_index, index_high, mask_high);
+            } else {
+                let mask_high = Self::_mm_cmpgt(new_values, values_high);
+                values_high = Self::_mm_blendv(values_high, new_values, mask_high);
+                index_high = Self::_mm_blendv(index_high, new_index, mask_high);
+            }
         }
 
         // Get the max index and corresponding value from the SIMD vectors and return
-        Self::_horiz_max(index_high, values_high)
+        let (max_index, max_value) = Self::_horiz_max::<LAST>(index_high, values_high);
+        (max_index, max_value)
     }
 
     /// Overflow-safe core argminmax algorithm - returns (argmin, min, argmax, max)
@@ -463,8 +970,11 @@ where
     /// - the array is not empty
     /// - the array length is a multiple of LANE_SIZE
     ///
+    /// When `LAST` is true, the index of the last occurrence of the min/max value is
+    /// returned (i.e., on ties, the result of a later chunk is preferred).
+    ///
     #[inline(always)]
-    unsafe fn _overflow_safe_core_argminmax(
+    unsafe fn _overflow_safe_core_argminmax<const LAST: bool>(
         arr: &[ScalarDType],
     ) -> (usize, ScalarDType, usize, ScalarDType) {
         assert!(!arr.is_empty());
@@ -489,12 +999,18 @@ where
                 return (min_index, min_value, max_index, max_value);
             }
             let (min_index_, min_value_, max_index_, max_value_) =
-                Self::_core_argminmax(&arr[start..start + dtype_max]);
-            if min_value_ < min_value || Self::_return_check(min_value_) {
+                Self::_core_argminmax::<LAST>(&arr[start..start + dtype_max]);
+            if min_value_ < min_value
+                || (LAST && min_value_ == min_value)
+                || Self::_return_check(min_value_)
+            {
                 min_index = start + min_index_;
                 min_value = min_value_;
             }
-            if max_value_ > max_value || Self::_return_check(max_value_) {
+            if max_value_ > max_value
+                || (LAST && max_value_ == max_value)
+                || Self::_return_check(max_value_)
+            {
                 max_index = start + max_index_;
                 max_value = max_value_;
             }
@@ -507,12 +1023,18 @@ where
                 return (min_index, min_value, max_index, max_value);
             }
             let (min_index_, min_value_, max_index_, max_value_) =
-                Self::_core_argminmax(&arr[start..]);
-            if min_value_ < min_value || Self::_return_check(min_value_) {
+                Self::_core_argminmax::<LAST>(&arr[start..]);
+            if min_value_ < min_value
+                || (LAST && min_value_ == min_value)
+                || Self::_return_check(min_value_)
+            {
                 min_index = start + min_index_;
                 min_value = min_value_;
             }
-            if max_value_ > max_value || Self::_return_check(max_value_) {
+            if max_value_ > max_value
+                || (LAST && max_value_ == max_value)
+                || Self::_return_check(max_value_)
+            {
                 max_index = start + max_index_;
                 max_value = max_value_;
             }
@@ -528,8 +1050,13 @@ where
     /// - the array is not empty
     /// - the array length is a multiple of LANE_SIZE
     ///
+    /// When `LAST` is true, the index of the last occurrence of the min value is
+    /// returned (i.e., on ties, the result of a later chunk is preferred).
+    ///
     #[inline(always)]
-    unsafe fn _overflow_safe_core_argmin(arr: &[ScalarDType]) -> (usize, ScalarDType) {
+    unsafe fn _overflow_safe_core_argmin<const LAST: bool>(
+        arr: &[ScalarDType],
+    ) -> (usize, ScalarDType) {
         assert!(!arr.is_empty());
         assert_eq!(arr.len() % LANE_SIZE, 0);
         // 0. Get the max value of the data type - which needs to be divided by LANE_SIZE
@@ -548,8 +1075,12 @@ where
                 // We can return immediately
                 return (min_index, min_value);
             }
-            let (min_index_, min_value_) = Self::_core_argmin(&arr[start..start + dtype_max]);
-            if min_value_ < min_value || Self::_return_check(min_value_) {
+            let (min_index_, min_value_) =
+                Self::_core_argmin::<LAST>(&arr[start..start + dtype_max]);
+            if min_value_ < min_value
+                || (LAST && min_value_ == min_value)
+                || Self::_return_check(min_value_)
+            {
                 min_index = start + min_index_;
                 min_value = min_value_;
             }
@@ -561,8 +1092,11 @@ where
                 // We can return immediately
                 return (min_index, min_value);
             }
-            let (min_index_, min_value_) = Self::_core_argmin(&arr[start..]);
-            if min_value_ < min_value || Self::_return_check(min_value_) {
+            let (min_index_, min_value_) = Self::_core_argmin::<LAST>(&arr[start..]);
+            if min_value_ < min_value
+                || (LAST && min_value_ == min_value)
+                || Self::_return_check(min_value_)
+            {
                 min_index = start + min_index_;
                 min_value = min_value_;
             }
@@ -578,8 +1112,13 @@ where
     /// - the array is not empty
     /// - the array length is a multiple of LANE_SIZE
     ///
+    /// When `LAST` is true, the index of the last occurrence of the max value is
+    /// returned (i.e., on ties, the result of a later chunk is preferred).
+    ///
     #[inline(always)]
-    unsafe fn _overflow_safe_core_argmax(arr: &[ScalarDType]) -> (usize, ScalarDType) {
+    unsafe fn _overflow_safe_core_argmax<const LAST: bool>(
+        arr: &[ScalarDType],
+    ) -> (usize, ScalarDType) {
         assert!(!arr.is_empty());
         assert_eq!(arr.len() % LANE_SIZE, 0);
         // 0. Get the max value of the data type - which needs to be divided by LANE_SIZE
@@ -598,8 +1137,12 @@ where
                 // We can return immediately
                 return (max_index, max_value);
             }
-            let (max_index_, max_value_) = Self::_core_argmax(&arr[start..start + dtype_max]);
-            if max_value_ > max_value || Self::_return_check(max_value_) {
+            let (max_index_, max_value_) =
+                Self::_core_argmax::<LAST>(&arr[start..start + dtype_max]);
+            if max_value_ > max_value
+                || (LAST && max_value_ == max_value)
+                || Self::_return_check(max_value_)
+            {
                 max_index = start + max_index_;
                 max_value = max_value_;
             }
@@ -611,8 +1154,11 @@ where
                 // We can return immediately
                 return (max_index, max_value);
             }
-            let (max_index_, max_value_) = Self::_core_argmax(&arr[start..]);
-            if max_value_ > max_value || Self::_return_check(max_value_) {
+            let (max_index_, max_value_) = Self::_core_argmax::<LAST>(&arr[start..]);
+            if max_value_ > max_value
+                || (LAST && max_value_ == max_value)
+                || Self::_return_check(max_value_)
+            {
                 max_index = start + max_index_;
                 max_value = max_value_;
             }
@@ -679,16 +1225,54 @@ where
     #[doc(hidden)]
     #[inline(always)]
     unsafe fn _argminmax(data: &[ScalarDType]) -> (usize, usize)
+    where
+        SCALAR: ScalarArgMinMax<ScalarDType>,
+    {
+        let (min_index, _, max_index, _) = Self::_argminmax_with_values(data);
+        (min_index, max_index)
+    }
+
+    /// Get the index and value of the minimum and maximum values in the slice.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// A tuple of the index and value of the minimum and maximum values in the slice
+    /// `(min_index, min_value, max_index, max_value)`.
+    ///
+    /// # Safety
+    /// This function is unsafe because unsafe SIMD operations are used.  
+    /// See SIMD operations for more information:
+    /// - [`x86` SIMD docs](https://doc.rust-lang.org/core/arch/x86/index.html)
+    /// - [`x86_64` SIMD docs](https://doc.rust-lang.org/core/arch/x86_64/index.html)
+    /// - [`arm` SIMD docs](https://doc.rust-lang.org/core/arch/arm/index.html)
+    /// - [`aarch64` SIMD docs](https://doc.rust-lang.org/core/arch/aarch64/index.html)
+    ///
+    unsafe fn argminmax_with_values(
+        data: &[ScalarDType],
+    ) -> (usize, ScalarDType, usize, ScalarDType) {
+        Self::_argminmax_with_values(data)
+    }
+
+    // Is necessary to have a separate function for this so we can call it in the
+    // argminmax_with_values function when we add the target feature to the function.
+    #[doc(hidden)]
+    #[inline(always)]
+    unsafe fn _argminmax_with_values(
+        data: &[ScalarDType],
+    ) -> (usize, ScalarDType, usize, ScalarDType)
     where
         SCALAR: ScalarArgMinMax<ScalarDType>,
     {
         argminmax_generic(
             data,
             LANE_SIZE,
-            Self::_overflow_safe_core_argminmax, // SIMD operation
-            SCALAR::argminmax,                   // Scalar operation
-            Self::_nan_check,                    // NaN check - true if value is NaN
-            Self::IGNORE_NAN,                    // Ignore NaNs - if false -> return NaN
+            Self::_overflow_safe_core_argminmax::<false>, // SIMD operation
+            SCALAR::argminmax_with_values,                // Scalar operation
+            Self::_nan_check,                             // NaN check - true if value is NaN
+            Self::IGNORE_NAN,                             // Ignore NaNs - if false -> return NaN
+            false,                                        // Return the first index on ties
         )
     }
 
@@ -721,10 +1305,11 @@ where
         argmin_generic(
             data,
             LANE_SIZE,
-            Self::_overflow_safe_core_argmin, // SIMD operation
-            SCALAR::argmin,                   // Scalar operation
-            Self::_nan_check,                 // NaN check - true if value is NaN
-            Self::IGNORE_NAN,                 // Ignore NaNs - if false -> return NaN
+            Self::_overflow_safe_core_argmin::<false>, // SIMD operation
+            SCALAR::argmin,                            // Scalar operation
+            Self::_nan_check,                          // NaN check - true if value is NaN
+            Self::IGNORE_NAN,                          // Ignore NaNs - if false -> return NaN
+            false,                                     // Return the first index on ties
         )
     }
 
@@ -757,10 +1342,127 @@ where
         argmax_generic(
             data,
             LANE_SIZE,
-            Self::_overflow_safe_core_argmax, // SIMD operation
-            SCALAR::argmax,                   // Scalar operation
-            Self::_nan_check,                 // NaN check - true if value is NaN
-            Self::IGNORE_NAN,                 // Ignore NaNs - if false -> return NaN
+            Self::_overflow_safe_core_argmax::<false>, // SIMD operation
+            SCALAR::argmax,                            // Scalar operation
+            Self::_nan_check,                          // NaN check - true if value is NaN
+            Self::IGNORE_NAN,                          // Ignore NaNs - if false -> return NaN
+            false,                                     // Return the first index on ties
+        )
+    }
+
+    /// Get the index of the minimum and maximum values in the slice - returning the index of the
+    /// last occurrence on ties.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// A tuple of the index of the minimum and maximum values in the slice
+    /// `(min_index, max_index)`.
+    ///
+    /// # Safety
+    /// This function is unsafe because unsafe SIMD operations are used.  
+    /// See SIMD operations for more information:
+    /// - [`x86` SIMD docs](https://doc.rust-lang.org/core/arch/x86/index.html)
+    /// - [`x86_64` SIMD docs](https://doc.rust-lang.org/core/arch/x86_64/index.html)
+    /// - [`arm` SIMD docs](https://doc.rust-lang.org/core/arch/arm/index.html)
+    /// - [`aarch64` SIMD docs](https://doc.rust-lang.org/core/arch/aarch64/index.html)
+    ///
+    unsafe fn argminmax_last(data: &[ScalarDType]) -> (usize, usize);
+
+    // Is necessary to have a separate function for this so we can call it in the
+    // argminmax_last function when we add the target feature to the function.
+    #[doc(hidden)]
+    #[inline(always)]
+    unsafe fn _argminmax_last(data: &[ScalarDType]) -> (usize, usize)
+    where
+        SCALAR: ScalarArgMinMax<ScalarDType>,
+    {
+        let (min_index, _, max_index, _) = argminmax_generic(
+            data,
+            LANE_SIZE,
+            Self::_overflow_safe_core_argminmax::<true>, // SIMD operation
+            SCALAR::argminmax_last_with_values,          // Scalar operation
+            Self::_nan_check,                            // NaN check - true if value is NaN
+            Self::IGNORE_NAN,                            // Ignore NaNs - if false -> return NaN
+            true,                                        // Return the last index on ties
+        );
+        (min_index, max_index)
+    }
+
+    /// Get the index of the minimum value in the slice - returning the index of the
+    /// last occurrence on ties.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// The index of the minimum value in the slice.
+    ///
+    /// # Safety
+    /// This function is unsafe because unsafe SIMD operations are used.  
+    /// See SIMD operations for more information:
+    /// - [`x86` SIMD docs](https://doc.rust-lang.org/core/arch/x86/index.html)
+    /// - [`x86_64` SIMD docs](https://doc.rust-lang.org/core/arch/x86_64/index.html)
+    /// - [`arm` SIMD docs](https://doc.rust-lang.org/core/arch/arm/index.html)
+    /// - [`aarch64` SIMD docs](https://doc.rust-lang.org/core/arch/aarch64/index.html)
+    ///
+    unsafe fn argmin_last(data: &[ScalarDType]) -> usize;
+
+    // Is necessary to have a separate function for this so we can call it in the
+    // argmin_last function when we add the target feature to the function.
+    #[doc(hidden)]
+    #[inline(always)]
+    unsafe fn _argmin_last(data: &[ScalarDType]) -> usize
+    where
+        SCALAR: ScalarArgMinMax<ScalarDType>,
+    {
+        argmin_generic(
+            data,
+            LANE_SIZE,
+            Self::_overflow_safe_core_argmin::<true>, // SIMD operation
+            SCALAR::argmin_last,                      // Scalar operation
+            Self::_nan_check,                         // NaN check - true if value is NaN
+            Self::IGNORE_NAN,                         // Ignore NaNs - if false -> return NaN
+            true,                                     // Return the last index on ties
+        )
+    }
+
+    /// Get the index of the maximum value in the slice - returning the index of the
+    /// last occurrence on ties.
+    ///
+    /// # Arguments
+    /// - `data` - the slice of data.
+    ///
+    /// # Returns
+    /// The index of the maximum value in the slice.
+    ///
+    /// # Safety
+    /// This function is unsafe because unsafe SIMD operations are used.  
+    /// See SIMD operations for more information:
+    /// - [`x86` SIMD docs](https://doc.rust-lang.org/core/arch/x86/index.html)
+    /// - [`x86_64` SIMD docs](https://doc.rust-lang.org/core/arch/x86_64/index.html)
+    /// - [`arm` SIMD docs](https://doc.rust-lang.org/core/arch/arm/index.html)
+    /// - [`aarch64` SIMD docs](https://doc.rust-lang.org/core/arch/aarch64/index.html)
+    ///
+    unsafe fn argmax_last(data: &[ScalarDType]) -> usize;
+
+    // Is necessary to have a separate function for this so we can call it in the
+    // argmax_last function when we add the target feature to the function.
+    #[doc(hidden)]
+    #[inline(always)]
+    unsafe fn _argmax_last(data: &[ScalarDType]) -> usize
+    where
+        SCALAR: ScalarArgMinMax<ScalarDType>,
+    {
+        argmax_generic(
+            data,
+            LANE_SIZE,
+            Self::_overflow_safe_core_argmax::<true>, // SIMD operation
+            SCALAR::argmax_last,                      // Scalar operation
+            Self::_nan_check,                         // NaN check - true if value is NaN
+            Self::IGNORE_NAN,                         // Ignore NaNs - if false -> return NaN
+            true,                                     // Return the last index on ties
         )
     }
 }
@@ -787,6 +1489,13 @@ macro_rules! impl_SIMDArgMinMax {
                 Self::_argminmax(data)
             }
 
+            #[target_feature(enable = $target)]
+            unsafe fn argminmax_with_values(
+                data: &[$scalar_dtype],
+            ) -> (usize, $scalar_dtype, usize, $scalar_dtype) {
+                Self::_argminmax_with_values(data)
+            }
+
             #[target_feature(enable = $target)]
             unsafe fn argmin(data: &[$scalar_dtype]) -> usize {
                 Self::_argmin(data)
@@ -799,6 +1508,21 @@ macro_rules! impl_SIMDArgMinMax {
                 Self::_argmax(data)
                 // Self::_argminmax(data).1
             }
+
+            #[target_feature(enable = $target)]
+            unsafe fn argminmax_last(data: &[$scalar_dtype]) -> (usize, usize) {
+                Self::_argminmax_last(data)
+            }
+
+            #[target_feature(enable = $target)]
+            unsafe fn argmin_last(data: &[$scalar_dtype]) -> usize {
+                Self::_argmin_last(data)
+            }
+
+            #[target_feature(enable = $target)]
+            unsafe fn argmax_last(data: &[$scalar_dtype]) -> usize {
+                Self::_argmax_last(data)
+            }
         }
     };
 }
@@ -865,6 +1589,12 @@ macro_rules! unimpl_SIMDArgMinMax {
                 unimplemented!()
             }
 
+            unsafe fn argminmax_with_values(
+                _data: &[$scalar_type],
+            ) -> (usize, $scalar_type, usize, $scalar_type) {
+                unimplemented!()
+            }
+
             unsafe fn argmin(_data: &[$scalar_type]) -> usize {
                 unimplemented!()
             }
@@ -872,6 +1602,18 @@ macro_rules! unimpl_SIMDArgMinMax {
             unsafe fn argmax(_data: &[$scalar_type]) -> usize {
                 unimplemented!()
             }
+
+            unsafe fn argminmax_last(_data: &[$scalar_type]) -> (usize, usize) {
+                unimplemented!()
+            }
+
+            unsafe fn argmin_last(_data: &[$scalar_type]) -> usize {
+                unimplemented!()
+            }
+
+            unsafe fn argmax_last(_data: &[$scalar_type]) -> usize {
+                unimplemented!()
+            }
         }
     };
 }
@@ -11,6 +11,7 @@ mod task;
 // --- SIMD implementations ---
 
 // FLOAT
+// (the f16 modules also implement bf16)
 #[cfg(feature = "half")]
 mod simd_f16_ignore_nan;
 #[cfg(feature = "half")]
@@ -23,6 +24,12 @@ mod simd_f32_return_nan;
 mod simd_f64_ignore_nan;
 #[cfg(feature = "float")]
 mod simd_f64_return_nan;
+#[cfg(feature = "float8")]
+mod simd_f8_ignore_nan;
+#[cfg(feature = "float8")]
+mod simd_f8_return_nan;
+// 128-BIT INT (signed and unsigned)
+mod simd_int128;
 // SIGNED INT
 mod simd_i16;
 mod simd_i32;
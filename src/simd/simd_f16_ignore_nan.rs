@@ -1,4 +1,4 @@
-/// Implementation of the argminmax operations for f32 that ignores NaN values.
+/// Implementation of the argminmax operations for f16 and bf16 that ignores NaN values.
 /// This implementation returns the index of the minimum and maximum values.
 /// However, unexpected behavior may occur when there are
 /// - *only* NaN values in the array
@@ -22,6 +22,11 @@
 /// Through this transformation we can perform the argminmax operations on the ordinal
 /// integer values and then transform the result back to the original f16 values.
 ///
+/// bf16 has the same layout as the upper 16 bits of an f32 (1 sign bit, 8 exponent bits
+/// and 7 mantissa bits), so the same transformation is used for both data types (only
+/// the NaN threshold differs) and the SIMD operations are generated once (through
+/// `impl_SIMD_OrdinalFloatIgnoreNaN!`) on top of the i16 SIMD operations.
+///
 /// Note that most x86 CPUs do not support f16 instructions - making this implementation
 /// multitudes (up to 300x) faster than trying to use a vanilla scalar implementation.
 ///
@@ -29,9 +34,12 @@
 use super::config::SIMDInstructionSet;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
 use super::generic::{
-    impl_SIMDArgMinMax, impl_SIMDInit_FloatIgnoreNaN, SIMDArgMinMax, SIMDInit, SIMDOps,
+    impl_SIMDArgMinMax, impl_SIMDInit_FloatIgnoreNaN, impl_SIMDOps_OrdinalFloat,
+    impl_SIMD_OrdinalFloatIgnoreNaN, SIMDArgMinMax, SIMDInit, SIMDOps,
 };
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use crate::scalar::Float16;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
 use crate::SCALAR;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
 use num_traits::Zero;
@@ -45,28 +53,17 @@ use std::arch::x86::*;
 use std::arch::x86_64::*;
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
-use half::f16;
+use half::{bf16, f16};
 
 /// The dtype-strategy for performing operations on f16 data: ignore NaN values
+/// (the SIMD operations of the Int strategy are reused on the ordinal i16 values)
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
-use super::super::dtype_strategy::FloatIgnoreNaN;
+use super::super::dtype_strategy::{FloatIgnoreNaN, Int};
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
 const BIT_SHIFT: i32 = 15;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
 const MASK_VALUE: i16 = 0x7FFF; // i16::MAX - masks everything but the sign bit
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
-const NAN_VALUE: i16 = 0x7C00; // absolute values above this are NaN
-
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
-#[inline(always)]
-fn _i16ord_to_f16(ord_i16: i16) -> f16 {
-    let v = ((ord_i16 >> BIT_SHIFT) & MASK_VALUE) ^ ord_i16;
-    f16::from_bits(v as u16)
-}
-
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
-const MAX_INDEX: usize = i16::MAX as usize;
 
 // --------------------------------------- AVX2 ----------------------------------------
 
@@ -77,7 +74,6 @@ mod avx2_ignore_nan {
 
     const LANE_SIZE: usize = AVX2::<FloatIgnoreNaN>::LANE_SIZE_16;
     const LOWER_15_MASK: __m256i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
-    const NAN_MASK: __m256i = unsafe { std::mem::transmute([NAN_VALUE + 1; LANE_SIZE]) };
 
     #[inline(always)]
     unsafe fn _f16_as_m256i_to_i16ord(f16_as_m256i: __m256i) -> __m256i {
@@ -93,142 +89,48 @@ mod avx2_ignore_nan {
     }
 
     #[inline(always)]
-    unsafe fn _non_nan_check(f16_as_m256i: __m256i) -> __m256i {
-        // on a scalar: (v & 0x7FFF) > 0x7C00
-        let abs_value = _mm256_and_si256(f16_as_m256i, LOWER_15_MASK);
-        _mm256_cmpgt_epi16(NAN_MASK, abs_value)
+    unsafe fn _non_nan_mask<T: Float16>(mask: __m256i, ord_i16: __m256i) -> __m256i {
+        // on a scalar: (ord ^ (ord >> 15)) <= NAN_VALUE
+        // (for negative values this undoes the flipping of the lower 15 bits)
+        let sign_bit_shifted = _mm256_srai_epi16(ord_i16, BIT_SHIFT);
+        let abs_value = _mm256_xor_si256(ord_i16, sign_bit_shifted);
+        let nan_threshold = _mm256_set1_epi16(T::NAN_VALUE as i16 + 1);
+        _mm256_and_si256(mask, _mm256_cmpgt_epi16(nan_threshold, abs_value))
     }
 
     #[inline(always)]
-    unsafe fn _reg_to_i16_arr(reg: __m256i) -> [i16; LANE_SIZE] {
-        std::mem::transmute::<__m256i, [i16; LANE_SIZE]>(reg)
+    unsafe fn _mm_set1_ord<T: Float16>(a: T) -> __m256i {
+        _mm256_set1_epi16(a.to_i16ord())
     }
 
-    impl SIMDOps<f16, __m256i, __m256i, LANE_SIZE> for AVX2<FloatIgnoreNaN> {
-        const INITIAL_INDEX: __m256i = unsafe {
-            std::mem::transmute([
-                0i16, 1i16, 2i16, 3i16, 4i16, 5i16, 6i16, 7i16, 8i16, 9i16, 10i16, 11i16, 12i16,
-                13i16, 14i16, 15i16,
-            ])
-        };
-        const INDEX_INCREMENT: __m256i =
-            unsafe { std::mem::transmute([LANE_SIZE as i16; LANE_SIZE]) };
-        const MAX_INDEX: usize = MAX_INDEX;
-
-        #[inline(always)]
-        unsafe fn _reg_to_arr(_: __m256i) -> [f16; LANE_SIZE] {
-            // Not implemented because we will perform the horizontal operations on the
-            // signed integer values instead of trying to retransform **only** the values
-            // (and thus not the indices) to floats.
-            unimplemented!()
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_loadu(data: *const f16) -> __m256i {
-            _f16_as_m256i_to_i16ord(_mm256_loadu_si256(data as *const __m256i))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_add(a: __m256i, b: __m256i) -> __m256i {
-            _mm256_add_epi16(a, b)
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_cmpgt(a: __m256i, b: __m256i) -> __m256i {
-            // TODO for argminmax the non-nan check is avoided twice -> optimize this
-            _mm256_and_si256(_mm256_cmpgt_epi16(a, b), _non_nan_check(a))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_cmplt(a: __m256i, b: __m256i) -> __m256i {
-            _mm256_and_si256(_mm256_cmpgt_epi16(b, a), _non_nan_check(a))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_blendv(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
-            _mm256_blendv_epi8(a, b, mask)
-        }
-
-        #[inline(always)]
-        unsafe fn _horiz_min(index: __m256i, value: __m256i) -> (usize, f16) {
-            // 0. Find the minimum value
-            let mut vmin: __m256i = value;
-            vmin = _mm256_min_epi16(vmin, _mm256_permute2x128_si256(vmin, vmin, 1));
-            vmin = _mm256_min_epi16(vmin, _mm256_alignr_epi8(vmin, vmin, 8));
-            vmin = _mm256_min_epi16(vmin, _mm256_alignr_epi8(vmin, vmin, 4));
-            vmin = _mm256_min_epi16(vmin, _mm256_alignr_epi8(vmin, vmin, 2));
-            let min_value: i16 = _mm256_extract_epi16(vmin, 0) as i16;
-
-            // Extract the index of the minimum value
-            // 1. Create a mask with the index of the minimum value
-            let mask = _mm256_cmpeq_epi16(value, vmin);
-            // 2. Blend the mask with the index
-            let search_index = _mm256_blendv_epi8(
-                _mm256_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
-                index,                       // if mask is 1, use index
-                mask,
-            );
-            // 3. Find the minimum index
-            let mut imin: __m256i = search_index;
-            imin = _mm256_min_epi16(imin, _mm256_permute2x128_si256(imin, imin, 1));
-            imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 8));
-            imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 4));
-            imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 2));
-            let min_index: usize = _mm256_extract_epi16(imin, 0) as usize;
-
-            (min_index, _i16ord_to_f16(min_value))
-        }
-
-        #[inline(always)]
-        unsafe fn _horiz_max(index: __m256i, value: __m256i) -> (usize, f16) {
-            // 0. Find the maximum value
-            let mut vmax: __m256i = value;
-            vmax = _mm256_max_epi16(vmax, _mm256_permute2x128_si256(vmax, vmax, 1));
-            vmax = _mm256_max_epi16(vmax, _mm256_alignr_epi8(vmax, vmax, 8));
-            vmax = _mm256_max_epi16(vmax, _mm256_alignr_epi8(vmax, vmax, 4));
-            vmax = _mm256_max_epi16(vmax, _mm256_alignr_epi8(vmax, vmax, 2));
-            let max_value: i16 = _mm256_extract_epi16(vmax, 0) as i16;
-
-            // Extract the index of the maximum value
-            // 1. Create a mask with the index of the maximum value
-            let mask = _mm256_cmpeq_epi16(value, vmax);
-            // 2. Blend the mask with the index
-            let search_index = _mm256_blendv_epi8(
-                _mm256_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
-                index,                       // if mask is 1, use index
-                mask,
-            );
-            // 3. Find the maximum index
-            let mut imin: __m256i = search_index;
-            imin = _mm256_min_epi16(imin, _mm256_permute2x128_si256(imin, imin, 1));
-            imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 8));
-            imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 4));
-            imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 2));
-            let max_index: usize = _mm256_extract_epi16(imin, 0) as usize;
-
-            (max_index, _i16ord_to_f16(max_value))
-        }
-
-        // --- Necessary for impl_SIMDInit_FloatIgnoreNaN!
-
-        #[inline(always)]
-        unsafe fn _mm_set1(a: f16) -> __m256i {
-            // TODO: can better perhaps?
-            let data: [f16; LANE_SIZE] = [a; LANE_SIZE];
-            _f16_as_m256i_to_i16ord(_mm256_loadu_si256(data.as_ptr() as *const __m256i))
-        }
-    }
-
-    impl_SIMDInit_FloatIgnoreNaN!(f16, __m256i, __m256i, LANE_SIZE, AVX2<FloatIgnoreNaN>);
-
-    impl_SIMDArgMinMax!(
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
         f16,
         __m256i,
         __m256i,
         LANE_SIZE,
-        SCALAR<FloatIgnoreNaN>,
         AVX2<FloatIgnoreNaN>,
-        "avx2"
+        "avx2",
+        i16,
+        AVX2<Int>,
+        _f16_as_m256i_to_i16ord,
+        <f16>::from_i16ord,
+        _non_nan_mask::<f16>,
+        _mm_set1_ord::<f16>
+    );
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        bf16,
+        __m256i,
+        __m256i,
+        LANE_SIZE,
+        AVX2<FloatIgnoreNaN>,
+        "avx2",
+        i16,
+        AVX2<Int>,
+        _f16_as_m256i_to_i16ord,
+        <bf16>::from_i16ord,
+        _non_nan_mask::<bf16>,
+        _mm_set1_ord::<bf16>
     );
 }
 
@@ -241,7 +143,6 @@ mod sse_ignore_nan {
 
     const LANE_SIZE: usize = SSE::<FloatIgnoreNaN>::LANE_SIZE_16;
     const LOWER_15_MASK: __m128i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
-    const NAN_MASK: __m128i = unsafe { std::mem::transmute([NAN_VALUE + 1; LANE_SIZE]) };
 
     #[inline(always)]
     unsafe fn _f16_as_m128i_to_i16ord(f16_as_m128i: __m128i) -> __m128i {
@@ -252,132 +153,48 @@ mod sse_ignore_nan {
     }
 
     #[inline(always)]
-    unsafe fn _non_nan_check(f16_as_m128i: __m128i) -> __m128i {
-        // on a scalar: (v & 0x7FFF) > 0x7C00
-        let abs_value = _mm_and_si128(f16_as_m128i, LOWER_15_MASK);
-        _mm_cmplt_epi16(abs_value, NAN_MASK)
+    unsafe fn _non_nan_mask<T: Float16>(mask: __m128i, ord_i16: __m128i) -> __m128i {
+        // on a scalar: (ord ^ (ord >> 15)) <= NAN_VALUE
+        // (for negative values this undoes the flipping of the lower 15 bits)
+        let sign_bit_shifted = _mm_srai_epi16(ord_i16, BIT_SHIFT);
+        let abs_value = _mm_xor_si128(ord_i16, sign_bit_shifted);
+        let nan_threshold = _mm_set1_epi16(T::NAN_VALUE as i16 + 1);
+        _mm_and_si128(mask, _mm_cmplt_epi16(abs_value, nan_threshold))
     }
 
     #[inline(always)]
-    unsafe fn _reg_to_i16_arr(reg: __m128i) -> [i16; LANE_SIZE] {
-        std::mem::transmute::<__m128i, [i16; LANE_SIZE]>(reg)
-    }
-
-    impl SIMDOps<f16, __m128i, __m128i, LANE_SIZE> for SSE<FloatIgnoreNaN> {
-        const INITIAL_INDEX: __m128i =
-            unsafe { std::mem::transmute([0i16, 1i16, 2i16, 3i16, 4i16, 5i16, 6i16, 7i16]) };
-        const INDEX_INCREMENT: __m128i =
-            unsafe { std::mem::transmute([LANE_SIZE as i16; LANE_SIZE]) };
-        const MAX_INDEX: usize = MAX_INDEX;
-
-        #[inline(always)]
-        unsafe fn _reg_to_arr(_: __m128i) -> [f16; LANE_SIZE] {
-            // Not implemented because we will perform the horizontal operations on the
-            // signed integer values instead of trying to retransform **only** the values
-            // (and thus not the indices) to floats.
-            unimplemented!()
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_loadu(data: *const f16) -> __m128i {
-            _f16_as_m128i_to_i16ord(_mm_loadu_si128(data as *const __m128i))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_add(a: __m128i, b: __m128i) -> __m128i {
-            _mm_add_epi16(a, b)
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_cmpgt(a: __m128i, b: __m128i) -> __m128i {
-            _mm_and_si128(_mm_cmpgt_epi16(a, b), _non_nan_check(a))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_cmplt(a: __m128i, b: __m128i) -> __m128i {
-            _mm_and_si128(_mm_cmplt_epi16(a, b), _non_nan_check(a))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_blendv(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
-            _mm_blendv_epi8(a, b, mask)
-        }
-
-        #[inline(always)]
-        unsafe fn _horiz_min(index: __m128i, value: __m128i) -> (usize, f16) {
-            // 0. Find the minimum value
-            let mut vmin: __m128i = value;
-            vmin = _mm_min_epi16(vmin, _mm_alignr_epi8(vmin, vmin, 8));
-            vmin = _mm_min_epi16(vmin, _mm_alignr_epi8(vmin, vmin, 4));
-            vmin = _mm_min_epi16(vmin, _mm_alignr_epi8(vmin, vmin, 2));
-            let min_value: i16 = _mm_extract_epi16(vmin, 0) as i16;
-
-            // Extract the index of the minimum value
-            // 1. Create a mask with the index of the minimum value
-            let mask = _mm_cmpeq_epi16(value, vmin);
-            // 2. Blend the mask with the index
-            let search_index = _mm_blendv_epi8(
-                _mm_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
-                index,                    // if mask is 1, use index
-                mask,
-            );
-            // 3. Find the minimum index
-            let mut imin: __m128i = search_index;
-            imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 8));
-            imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 4));
-            imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 2));
-            let min_index: usize = _mm_extract_epi16(imin, 0) as usize;
-
-            (min_index, _i16ord_to_f16(min_value))
-        }
-
-        #[inline(always)]
-        unsafe fn _horiz_max(index: __m128i, value: __m128i) -> (usize, f16) {
-            // 0. Find the maximum value
-            let mut vmax: __m128i = value;
-            vmax = _mm_max_epi16(vmax, _mm_alignr_epi8(vmax, vmax, 8));
-            vmax = _mm_max_epi16(vmax, _mm_alignr_epi8(vmax, vmax, 4));
-            vmax = _mm_max_epi16(vmax, _mm_alignr_epi8(vmax, vmax, 2));
-            let max_value: i16 = _mm_extract_epi16(vmax, 0) as i16;
-
-            // Extract the index of the maximum value
-            // 1. Create a mask with the index of the maximum value
-            let mask = _mm_cmpeq_epi16(value, vmax);
-            // 2. Blend the mask with the index
-            let search_index = _mm_blendv_epi8(
-                _mm_set1_epi16(i16::MAX), // if mask is 0, use i8::MAX
-                index,                    // if mask is 1, use index
-                mask,
-            );
-            // 3. Find the maximum index
-            let mut imin: __m128i = search_index;
-            imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 8));
-            imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 4));
-            imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 2));
-            let max_index: usize = _mm_extract_epi16(imin, 0) as usize;
-
-            (max_index, _i16ord_to_f16(max_value))
-        }
-
-        // --- Necessary for impl_SIMDInit_FloatIgnoreNaN!
-
-        #[inline(always)]
-        unsafe fn _mm_set1(a: f16) -> __m128i {
-            let data: [f16; LANE_SIZE] = [a; LANE_SIZE];
-            _f16_as_m128i_to_i16ord(_mm_loadu_si128(data.as_ptr() as *const __m128i))
-        }
+    unsafe fn _mm_set1_ord<T: Float16>(a: T) -> __m128i {
+        _mm_set1_epi16(a.to_i16ord())
     }
 
-    impl_SIMDInit_FloatIgnoreNaN!(f16, __m128i, __m128i, LANE_SIZE, SSE<FloatIgnoreNaN>);
-
-    impl_SIMDArgMinMax!(
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
         f16,
         __m128i,
         __m128i,
         LANE_SIZE,
-        SCALAR<FloatIgnoreNaN>,
         SSE<FloatIgnoreNaN>,
-        "sse4.1"
+        "sse4.1",
+        i16,
+        SSE<Int>,
+        _f16_as_m128i_to_i16ord,
+        <f16>::from_i16ord,
+        _non_nan_mask::<f16>,
+        _mm_set1_ord::<f16>
+    );
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        bf16,
+        __m128i,
+        __m128i,
+        LANE_SIZE,
+        SSE<FloatIgnoreNaN>,
+        "sse4.1",
+        i16,
+        SSE<Int>,
+        _f16_as_m128i_to_i16ord,
+        <bf16>::from_i16ord,
+        _non_nan_mask::<bf16>,
+        _mm_set1_ord::<bf16>
     );
 }
 // -------------------------------------- AVX512 ---------------------------------------
@@ -390,7 +207,6 @@ mod avx512_ignore_nan {
 
     const LANE_SIZE: usize = AVX512::<FloatIgnoreNaN>::LANE_SIZE_16;
     const LOWER_15_MASK: __m512i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
-    const NAN_MASK: __m512i = unsafe { std::mem::transmute([NAN_VALUE + 1; LANE_SIZE]) };
 
     #[inline(always)]
     unsafe fn _f16_as_m521i_to_i16ord(f16_as_m512i: __m512i) -> __m512i {
@@ -401,145 +217,48 @@ mod avx512_ignore_nan {
     }
 
     #[inline(always)]
-    unsafe fn _non_nan_check(f16_as_m512i: __m512i) -> u32 {
-        // on a scalar: (v & 0x7FFF) < 0x7C00
-        let abs_value = _mm512_and_si512(f16_as_m512i, LOWER_15_MASK);
-        _mm512_cmplt_epi16_mask(abs_value, NAN_MASK)
+    unsafe fn _non_nan_mask<T: Float16>(mask: u32, ord_i16: __m512i) -> u32 {
+        // on a scalar: (ord ^ (ord >> 15)) <= NAN_VALUE
+        // (for negative values this undoes the flipping of the lower 15 bits)
+        let sign_bit_shifted = _mm512_srai_epi16(ord_i16, BIT_SHIFT as u32);
+        let abs_value = _mm512_xor_si512(ord_i16, sign_bit_shifted);
+        let nan_threshold = _mm512_set1_epi16(T::NAN_VALUE as i16 + 1);
+        mask & _mm512_cmplt_epi16_mask(abs_value, nan_threshold)
     }
 
     #[inline(always)]
-    unsafe fn _reg_to_i16_arr(reg: __m512i) -> [i16; LANE_SIZE] {
-        std::mem::transmute::<__m512i, [i16; LANE_SIZE]>(reg)
-    }
-
-    impl SIMDOps<f16, __m512i, u32, LANE_SIZE> for AVX512<FloatIgnoreNaN> {
-        const INITIAL_INDEX: __m512i = unsafe {
-            std::mem::transmute([
-                0i16, 1i16, 2i16, 3i16, 4i16, 5i16, 6i16, 7i16, 8i16, 9i16, 10i16, 11i16, 12i16,
-                13i16, 14i16, 15i16, 16i16, 17i16, 18i16, 19i16, 20i16, 21i16, 22i16, 23i16, 24i16,
-                25i16, 26i16, 27i16, 28i16, 29i16, 30i16, 31i16,
-            ])
-        };
-        const INDEX_INCREMENT: __m512i =
-            unsafe { std::mem::transmute([LANE_SIZE as i16; LANE_SIZE]) };
-        const MAX_INDEX: usize = MAX_INDEX;
-
-        #[inline(always)]
-        unsafe fn _reg_to_arr(_: __m512i) -> [f16; LANE_SIZE] {
-            // Not implemented because we will perform the horizontal operations on the
-            // signed integer values instead of trying to retransform **only** the values
-            // (and thus not the indices) to floats.
-            unimplemented!()
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_loadu(data: *const f16) -> __m512i {
-            _f16_as_m521i_to_i16ord(_mm512_loadu_epi16(data as *const i16))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_add(a: __m512i, b: __m512i) -> __m512i {
-            _mm512_add_epi16(a, b)
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_cmpgt(a: __m512i, b: __m512i) -> u32 {
-            _mm512_cmpgt_epi16_mask(a, b) & _non_nan_check(a)
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_cmplt(a: __m512i, b: __m512i) -> u32 {
-            _mm512_cmplt_epi16_mask(a, b) & _non_nan_check(a)
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_blendv(a: __m512i, b: __m512i, mask: u32) -> __m512i {
-            _mm512_mask_blend_epi16(mask, a, b)
-        }
-
-        #[inline(always)]
-        unsafe fn _horiz_min(index: __m512i, value: __m512i) -> (usize, f16) {
-            // 0. Find the minimum value
-            let mut vmin: __m512i = value;
-            vmin = _mm512_min_epi16(vmin, _mm512_alignr_epi32(vmin, vmin, 8));
-            vmin = _mm512_min_epi16(vmin, _mm512_alignr_epi32(vmin, vmin, 4));
-            vmin = _mm512_min_epi16(vmin, _mm512_alignr_epi8(vmin, vmin, 8));
-            vmin = _mm512_min_epi16(vmin, _mm512_alignr_epi8(vmin, vmin, 4));
-            vmin = _mm512_min_epi16(vmin, _mm512_alignr_epi8(vmin, vmin, 2));
-            let min_value: i16 = _mm_extract_epi16(_mm512_castsi512_si128(vmin), 0) as i16;
-
-            // Extract the index of the minimum value
-            // 1. Create a mask with the index of the minimum value
-            let mask = _mm512_cmpeq_epi16_mask(value, vmin);
-            // 2. Blend the mask with the index
-            let search_index = _mm512_mask_blend_epi16(
-                mask,
-                _mm512_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
-                index,                       // if mask is 1, use index
-            );
-            // 3. Find the minimum index
-            let mut imin: __m512i = search_index;
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi32(imin, imin, 8));
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi32(imin, imin, 4));
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 8));
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 4));
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 2));
-            let min_index: usize = _mm_extract_epi16(_mm512_castsi512_si128(imin), 0) as usize;
-
-            (min_index, _i16ord_to_f16(min_value))
-        }
-
-        #[inline(always)]
-        unsafe fn _horiz_max(index: __m512i, value: __m512i) -> (usize, f16) {
-            // 0. Find the maximum value
-            let mut vmax: __m512i = value;
-            vmax = _mm512_max_epi16(vmax, _mm512_alignr_epi32(vmax, vmax, 8));
-            vmax = _mm512_max_epi16(vmax, _mm512_alignr_epi32(vmax, vmax, 4));
-            vmax = _mm512_max_epi16(vmax, _mm512_alignr_epi8(vmax, vmax, 8));
-            vmax = _mm512_max_epi16(vmax, _mm512_alignr_epi8(vmax, vmax, 4));
-            vmax = _mm512_max_epi16(vmax, _mm512_alignr_epi8(vmax, vmax, 2));
-            let max_value: i16 = _mm_extract_epi16(_mm512_castsi512_si128(vmax), 0) as i16;
-
-            // Extract the index of the maximum value
-            // 1. Create a mask with the index of the maximum value
-            let mask = _mm512_cmpeq_epi16_mask(value, vmax);
-            // 2. Blend the mask with the index
-            let search_index = _mm512_mask_blend_epi16(
-                mask,
-                _mm512_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
-                index,                       // if mask is 1, use index
-            );
-            // 3. Find the maximum index
-            let mut imin: __m512i = search_index;
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi32(imin, imin, 8));
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi32(imin, imin, 4));
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 8));
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 4));
-            imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 2));
-            let max_index: usize = _mm_extract_epi16(_mm512_castsi512_si128(imin), 0) as usize;
-
-            (max_index, _i16ord_to_f16(max_value))
-        }
-
-        // --- Necessary for impl_SIMDInit_FloatIgnoreNaN!
-
-        #[inline(always)]
-        unsafe fn _mm_set1(a: f16) -> __m512i {
-            let data: [f16; LANE_SIZE] = [a; LANE_SIZE];
-            _f16_as_m521i_to_i16ord(_mm512_loadu_si512(data.as_ptr() as *const i32))
-        }
+    unsafe fn _mm_set1_ord<T: Float16>(a: T) -> __m512i {
+        _mm512_set1_epi16(a.to_i16ord())
     }
 
-    impl_SIMDInit_FloatIgnoreNaN!(f16, __m512i, u32, LANE_SIZE, AVX512<FloatIgnoreNaN>);
-
-    impl_SIMDArgMinMax!(
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
         f16,
         __m512i,
         u32,
         LANE_SIZE,
-        SCALAR<FloatIgnoreNaN>,
         AVX512<FloatIgnoreNaN>,
-        "avx512bw"
+        "avx512bw",
+        i16,
+        AVX512<Int>,
+        _f16_as_m521i_to_i16ord,
+        <f16>::from_i16ord,
+        _non_nan_mask::<f16>,
+        _mm_set1_ord::<f16>
+    );
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        bf16,
+        __m512i,
+        u32,
+        LANE_SIZE,
+        AVX512<FloatIgnoreNaN>,
+        "avx512bw",
+        i16,
+        AVX512<Int>,
+        _f16_as_m521i_to_i16ord,
+        <bf16>::from_i16ord,
+        _non_nan_mask::<bf16>,
+        _mm_set1_ord::<bf16>
     );
 }
 
@@ -553,7 +272,6 @@ mod neon_ignore_nan {
 
     const LANE_SIZE: usize = NEON::<FloatIgnoreNaN>::LANE_SIZE_16;
     const LOWER_15_MASK: int16x8_t = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
-    const NAN_MASK: int16x8_t = unsafe { std::mem::transmute([NAN_VALUE + 1; LANE_SIZE]) };
 
     #[inline(always)]
     unsafe fn _f16_as_int16x8_to_i16ord(f16_as_int16x8: int16x8_t) -> int16x8_t {
@@ -564,130 +282,48 @@ mod neon_ignore_nan {
     }
 
     #[inline(always)]
-    unsafe fn _non_nan_check(f16_as_int16x8: int16x8_t) -> uint16x8_t {
-        // on a scalar: (v & 0x7FFF) > 0x7C00
-        let abs_value = vandq_s16(f16_as_int16x8, LOWER_15_MASK);
-        vcltq_s16(abs_value, NAN_MASK)
+    unsafe fn _non_nan_mask<T: Float16>(mask: uint16x8_t, ord_i16: int16x8_t) -> uint16x8_t {
+        // on a scalar: (ord ^ (ord >> 15)) <= NAN_VALUE
+        // (for negative values this undoes the flipping of the lower 15 bits)
+        let sign_bit_shifted = vshrq_n_s16(ord_i16, BIT_SHIFT);
+        let abs_value = veorq_s16(ord_i16, sign_bit_shifted);
+        let nan_threshold = vdupq_n_s16(T::NAN_VALUE as i16 + 1);
+        vandq_u16(mask, vcltq_s16(abs_value, nan_threshold))
     }
 
     #[inline(always)]
-    unsafe fn _reg_to_i16_arr(reg: int16x8_t) -> [i16; LANE_SIZE] {
-        std::mem::transmute::<int16x8_t, [i16; LANE_SIZE]>(reg)
-    }
-
-    impl SIMDOps<f16, int16x8_t, uint16x8_t, LANE_SIZE> for NEON<FloatIgnoreNaN> {
-        const INITIAL_INDEX: int16x8_t =
-            unsafe { std::mem::transmute([0i16, 1i16, 2i16, 3i16, 4i16, 5i16, 6i16, 7i16]) };
-        const INDEX_INCREMENT: int16x8_t =
-            unsafe { std::mem::transmute([LANE_SIZE as i16; LANE_SIZE]) };
-        const MAX_INDEX: usize = MAX_INDEX;
-
-        #[inline(always)]
-        unsafe fn _reg_to_arr(_: int16x8_t) -> [f16; LANE_SIZE] {
-            // Not implemented because we will perform the horizontal operations on the
-            // signed integer values instead of trying to retransform **only** the values
-            // (and thus not the indices) to floats.
-            unimplemented!()
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_loadu(data: *const f16) -> int16x8_t {
-            _f16_as_int16x8_to_i16ord(vld1q_s16(data as *const i16))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_add(a: int16x8_t, b: int16x8_t) -> int16x8_t {
-            vaddq_s16(a, b)
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_cmpgt(a: int16x8_t, b: int16x8_t) -> uint16x8_t {
-            vandq_u16(vcgtq_s16(a, b), _non_nan_check(a))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_cmplt(a: int16x8_t, b: int16x8_t) -> uint16x8_t {
-            vandq_u16(vcltq_s16(a, b), _non_nan_check(a))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_blendv(a: int16x8_t, b: int16x8_t, mask: uint16x8_t) -> int16x8_t {
-            vbslq_s16(mask, b, a)
-        }
-
-        #[inline(always)]
-        unsafe fn _horiz_min(index: int16x8_t, value: int16x8_t) -> (usize, f16) {
-            // 0. Find the minimum value
-            let mut vmin: int16x8_t = value;
-            vmin = vminq_s16(vmin, vextq_s16(vmin, vmin, 4));
-            vmin = vminq_s16(vmin, vextq_s16(vmin, vmin, 2));
-            vmin = vminq_s16(vmin, vextq_s16(vmin, vmin, 1));
-            let min_value: i16 = vgetq_lane_s16(vmin, 0);
-
-            // Extract the index of the minimum value
-            // 1. Create a mask with the index of the minimum value
-            let mask = vceqq_s16(value, vmin);
-            // 2. Blend the mask with the index
-            let search_index = vbslq_s16(
-                mask,
-                index,                 // if mask is 1, use index
-                vdupq_n_s16(i16::MAX), // if mask is 0, use i16::MAX
-            );
-            // 3. Find the minimum index
-            let mut imin: int16x8_t = search_index;
-            imin = vminq_s16(imin, vextq_s16(imin, imin, 4));
-            imin = vminq_s16(imin, vextq_s16(imin, imin, 2));
-            imin = vminq_s16(imin, vextq_s16(imin, imin, 1));
-            let min_index: usize = vgetq_lane_s16(imin, 0) as usize;
-
-            (min_index, _i16ord_to_f16(min_value))
-        }
-
-        #[inline(always)]
-        unsafe fn _horiz_max(index: int16x8_t, value: int16x8_t) -> (usize, f16) {
-            // 0. Find the maximum value
-            let mut vmax: int16x8_t = value;
-            vmax = vmaxq_s16(vmax, vextq_s16(vmax, vmax, 4));
-            vmax = vmaxq_s16(vmax, vextq_s16(vmax, vmax, 2));
-            vmax = vmaxq_s16(vmax, vextq_s16(vmax, vmax, 1));
-            let max_value: i16 = vgetq_lane_s16(vmax, 0);
-
-            // Extract the index of the maximum value
-            // 1. Create a mask with the index of the maximum value
-            let mask = vceqq_s16(value, vmax);
-            // 2. Blend the mask with the index
-            let search_index = vbslq_s16(
-                mask,
-                index,                 // if mask is 1, use index
-                vdupq_n_s16(i16::MAX), // if mask is 0, use i16::MAX
-            );
-            // 3. Find the maximum index
-            let mut imin: int16x8_t = search_index;
-            imin = vminq_s16(imin, vextq_s16(imin, imin, 4));
-            imin = vminq_s16(imin, vextq_s16(imin, imin, 2));
-            imin = vminq_s16(imin, vextq_s16(imin, imin, 1));
-            let max_index: usize = vgetq_lane_s16(imin, 0) as usize;
-
-            (max_index, _i16ord_to_f16(max_value))
-        }
-
-        #[inline(always)]
-        unsafe fn _mm_set1(a: f16) -> int16x8_t {
-            let data: [f16; LANE_SIZE] = [a; LANE_SIZE];
-            _f16_as_int16x8_to_i16ord(vld1q_s16(data.as_ptr() as *const i16))
-        }
+    unsafe fn _mm_set1_ord<T: Float16>(a: T) -> int16x8_t {
+        vdupq_n_s16(a.to_i16ord())
     }
 
-    impl_SIMDInit_FloatIgnoreNaN!(f16, int16x8_t, uint16x8_t, LANE_SIZE, NEON<FloatIgnoreNaN>);
-
-    impl_SIMDArgMinMax!(
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
         f16,
         int16x8_t,
         uint16x8_t,
         LANE_SIZE,
-        SCALAR<FloatIgnoreNaN>,
         NEON<FloatIgnoreNaN>,
-        "neon"
+        "neon",
+        i16,
+        NEON<Int>,
+        _f16_as_int16x8_to_i16ord,
+        <f16>::from_i16ord,
+        _non_nan_mask::<f16>,
+        _mm_set1_ord::<f16>
+    );
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        bf16,
+        int16x8_t,
+        uint16x8_t,
+        LANE_SIZE,
+        NEON<FloatIgnoreNaN>,
+        "neon",
+        i16,
+        NEON<Int>,
+        _f16_as_int16x8_to_i16ord,
+        <bf16>::from_i16ord,
+        _non_nan_mask::<bf16>,
+        _mm_set1_ord::<bf16>
     );
 }
 
@@ -705,7 +341,7 @@ mod tests {
     use rstest_reuse::{self, *};
     use std::marker::PhantomData;
 
-    use half::f16;
+    use half::{bf16, f16};
 
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[cfg(feature = "nightly_simd")]
@@ -717,8 +353,9 @@ mod tests {
     use crate::{FloatIgnoreNaN, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_no_overflow_argminmax,
-        test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_no_overflow_argminmax, test_return_same_result_argminmax,
+        test_return_same_result_argminmax_last,
     };
     // Float specific tests
     use super::super::test_utils::{test_ignore_nans_argminmax, test_return_infs_argminmax};
@@ -730,6 +367,11 @@ mod tests {
         arr.iter().map(|x| f16::from_f32(*x as f32)).collect()
     }
 
+    fn get_array_bf16(n: usize) -> Vec<bf16> {
+        let arr = utils::get_random_array(n, i16::MIN, i16::MAX);
+        arr.iter().map(|x| bf16::from_f32(*x as f32)).collect()
+    }
+
     // The scalar implementation
     const SCALAR_STRATEGY: SCALAR<FloatIgnoreNaN> = SCALAR {
         _dtype_strategy: PhantomData::<FloatIgnoreNaN>,
@@ -763,83 +405,130 @@ mod tests {
 
     // ----------------- The actual tests -----------------
 
-    #[apply(simd_implementations)]
-    fn test_first_index_is_returned_when_identical_values_found<
-        T,
-        SIMDV,
-        SIMDM,
-        const LANE_SIZE: usize,
-    >(
-        #[case] simd: T,
-        #[case] simd_available: bool,
-    ) where
-        T: SIMDArgMinMax<f16, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
-        SIMDV: Copy,
-        SIMDM: Copy,
-    {
-        if !simd_available {
-            return;
-        }
-        test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
-    }
-
-    #[apply(simd_implementations)]
-    fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
-        #[case] simd: T,
-        #[case] simd_available: bool,
-    ) where
-        T: SIMDArgMinMax<f16, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
-        SIMDV: Copy,
-        SIMDM: Copy,
-    {
-        if !simd_available {
-            return;
-        }
-        test_return_same_result_argminmax(get_array_f16, SCALAR_STRATEGY, simd);
-    }
-
-    #[apply(simd_implementations)]
-    fn test_no_overflow<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
-        #[case] simd: T,
-        #[case] simd_available: bool,
-    ) where
-        T: SIMDArgMinMax<f16, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
-        SIMDV: Copy,
-        SIMDM: Copy,
-    {
-        if !simd_available {
-            return;
-        }
-        test_no_overflow_argminmax(get_array_f16, SCALAR_STRATEGY, simd, None);
-    }
-
-    #[apply(simd_implementations)]
-    fn test_return_infs<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
-        #[case] simd: T,
-        #[case] simd_available: bool,
-    ) where
-        T: SIMDArgMinMax<f16, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
-        SIMDV: Copy,
-        SIMDM: Copy,
-    {
-        if !simd_available {
-            return;
-        }
-        test_return_infs_argminmax(get_array_f16, SCALAR_STRATEGY, simd);
+    // The tests are identical for f16 and bf16 -> generate a module per data type
+    macro_rules! impl_f16_tests {
+        ($mod_name:ident, $f16_type:ty, $get_array:ident) => {
+            mod $mod_name {
+                use super::*;
+
+                #[apply(simd_implementations)]
+                fn test_first_index_is_returned_when_identical_values_found<
+                    T,
+                    SIMDV,
+                    SIMDM,
+                    const LANE_SIZE: usize,
+                >(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f16_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_last_index_is_returned_when_identical_values_found<
+                    T,
+                    SIMDV,
+                    SIMDM,
+                    const LANE_SIZE: usize,
+                >(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f16_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f16_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_same_result_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f16_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_same_result_argminmax_last($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_no_overflow<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f16_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_no_overflow_argminmax($get_array, SCALAR_STRATEGY, simd, None);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_infs<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f16_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_infs_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_ignore_nans<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f16_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_ignore_nans_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+            }
+        };
     }
 
-    #[apply(simd_implementations)]
-    fn test_ignore_nans<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
-        #[case] simd: T,
-        #[case] simd_available: bool,
-    ) where
-        T: SIMDArgMinMax<f16, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
-        SIMDV: Copy,
-        SIMDM: Copy,
-    {
-        if !simd_available {
-            return;
-        }
-        test_ignore_nans_argminmax(get_array_f16, SCALAR_STRATEGY, simd);
-    }
+    impl_f16_tests!(f16_tests, f16, get_array_f16);
+    impl_f16_tests!(bf16_tests, bf16, get_array_bf16);
 }
@@ -129,18 +129,18 @@ mod avx2 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m256i, value: __m256i) -> (usize, f32) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, f32) {
             let index_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(index);
             let value_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i32ord_to_f32(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m256i, value: __m256i) -> (usize, f32) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, f32) {
             let index_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(index);
             let value_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i32ord_to_f32(max_value))
         }
     }
@@ -155,6 +155,11 @@ mod avx2 {
             Self::_argminmax(data)
         }
 
+        #[target_feature(enable = "avx2")]
+        unsafe fn argminmax_with_values(data: &[f32]) -> (usize, f32, usize, f32) {
+            Self::_argminmax_with_values(data)
+        }
+
         unsafe fn argmin(data: &[f32]) -> usize {
             Self::argminmax(data).0
         }
@@ -162,6 +167,19 @@ mod avx2 {
         unsafe fn argmax(data: &[f32]) -> usize {
             Self::argminmax(data).1
         }
+
+        #[target_feature(enable = "avx2")]
+        unsafe fn argminmax_last(data: &[f32]) -> (usize, usize) {
+            Self::_argminmax_last(data)
+        }
+
+        unsafe fn argmin_last(data: &[f32]) -> usize {
+            Self::argminmax_last(data).0
+        }
+
+        unsafe fn argmax_last(data: &[f32]) -> usize {
+            Self::argminmax_last(data).1
+        }
     }
 }
 
@@ -228,18 +246,18 @@ mod sse {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m128i, value: __m128i) -> (usize, f32) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, f32) {
             let index_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(index);
             let value_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i32ord_to_f32(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m128i, value: __m128i) -> (usize, f32) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, f32) {
             let index_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(index);
             let value_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i32ord_to_f32(max_value))
         }
     }
@@ -254,6 +272,11 @@ mod sse {
             Self::_argminmax(data)
         }
 
+        #[target_feature(enable = "sse4.1")]
+        unsafe fn argminmax_with_values(data: &[f32]) -> (usize, f32, usize, f32) {
+            Self::_argminmax_with_values(data)
+        }
+
         unsafe fn argmin(data: &[f32]) -> usize {
             Self::argminmax(data).0
         }
@@ -261,6 +284,19 @@ mod sse {
         unsafe fn argmax(data: &[f32]) -> usize {
             Self::argminmax(data).1
         }
+
+        #[target_feature(enable = "sse4.1")]
+        unsafe fn argminmax_last(data: &[f32]) -> (usize, usize) {
+            Self::_argminmax_last(data)
+        }
+
+        unsafe fn argmin_last(data: &[f32]) -> usize {
+            Self::argminmax_last(data).0
+        }
+
+        unsafe fn argmax_last(data: &[f32]) -> usize {
+            Self::argminmax_last(data).1
+        }
     }
 }
 
@@ -333,18 +369,18 @@ mod avx512 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m512i, value: __m512i) -> (usize, f32) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, f32) {
             let index_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(index);
             let value_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i32ord_to_f32(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m512i, value: __m512i) -> (usize, f32) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, f32) {
             let index_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(index);
             let value_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i32ord_to_f32(max_value))
         }
     }
@@ -359,6 +395,11 @@ mod avx512 {
             Self::_argminmax(data)
         }
 
+        #[target_feature(enable = "avx512f")]
+        unsafe fn argminmax_with_values(data: &[f32]) -> (usize, f32, usize, f32) {
+            Self::_argminmax_with_values(data)
+        }
+
         unsafe fn argmin(data: &[f32]) -> usize {
             Self::argminmax(data).0
         }
@@ -366,6 +407,19 @@ mod avx512 {
         unsafe fn argmax(data: &[f32]) -> usize {
             Self::argminmax(data).1
         }
+
+        #[target_feature(enable = "avx512f")]
+        unsafe fn argminmax_last(data: &[f32]) -> (usize, usize) {
+            Self::_argminmax_last(data)
+        }
+
+        unsafe fn argmin_last(data: &[f32]) -> usize {
+            Self::argminmax_last(data).0
+        }
+
+        unsafe fn argmax_last(data: &[f32]) -> usize {
+            Self::argminmax_last(data).1
+        }
     }
 }
 
@@ -433,18 +487,18 @@ mod neon {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: int32x4_t, value: int32x4_t) -> (usize, f32) {
+        unsafe fn _horiz_min<const LAST: bool>(index: int32x4_t, value: int32x4_t) -> (usize, f32) {
             let index_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(index);
             let value_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i32ord_to_f32(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: int32x4_t, value: int32x4_t) -> (usize, f32) {
+        unsafe fn _horiz_max<const LAST: bool>(index: int32x4_t, value: int32x4_t) -> (usize, f32) {
             let index_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(index);
             let value_arr: [i32; LANE_SIZE] = _reg_to_i32_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i32ord_to_f32(max_value))
         }
     }
@@ -459,6 +513,11 @@ mod neon {
             Self::_argminmax(data)
         }
 
+        #[target_feature(enable = "neon")]
+        unsafe fn argminmax_with_values(data: &[f32]) -> (usize, f32, usize, f32) {
+            Self::_argminmax_with_values(data)
+        }
+
         unsafe fn argmin(data: &[f32]) -> usize {
             Self::argminmax(data).0
         }
@@ -466,6 +525,19 @@ mod neon {
         unsafe fn argmax(data: &[f32]) -> usize {
             Self::argminmax(data).1
         }
+
+        #[target_feature(enable = "neon")]
+        unsafe fn argminmax_last(data: &[f32]) -> (usize, usize) {
+            Self::_argminmax_last(data)
+        }
+
+        unsafe fn argmin_last(data: &[f32]) -> usize {
+            Self::argminmax_last(data).0
+        }
+
+        unsafe fn argmax_last(data: &[f32]) -> usize {
+            Self::argminmax_last(data).1
+        }
     }
 }
 
@@ -493,7 +565,8 @@ mod tests {
     use crate::{FloatReturnNaN, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_return_same_result_argminmax, test_return_same_result_argminmax_last,
     };
     // Float specific tests
     use super::super::test_utils::{test_return_infs_argminmax, test_return_nans_argminmax};
@@ -557,6 +630,26 @@ mod tests {
         test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_last_index_is_returned_when_identical_values_found<
+        T,
+        SIMDV,
+        SIMDM,
+        const LANE_SIZE: usize,
+    >(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<f32, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -572,6 +665,21 @@ mod tests {
         test_return_same_result_argminmax(get_array_f32, SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<f32, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_return_same_result_argminmax_last(get_array_f32, SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_infs<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -369,7 +369,8 @@ mod tests {
     use crate::{FloatIgnoreNaN, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_return_same_result_argminmax, test_return_same_result_argminmax_last,
     };
     // Float specific tests
     use super::super::test_utils::{test_ignore_nans_argminmax, test_return_infs_argminmax};
@@ -433,6 +434,26 @@ mod tests {
         test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_last_index_is_returned_when_identical_values_found<
+        T,
+        SIMDV,
+        SIMDM,
+        const LANE_SIZE: usize,
+    >(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<f64, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -448,6 +469,21 @@ mod tests {
         test_return_same_result_argminmax(get_array_f64, SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<f64, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_return_same_result_argminmax_last(get_array_f64, SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_infs<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -162,18 +162,18 @@ mod avx2 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m256i, value: __m256i) -> (usize, f64) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, f64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i64ord_to_f64(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m256i, value: __m256i) -> (usize, f64) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, f64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i64ord_to_f64(max_value))
         }
     }
@@ -188,6 +188,11 @@ mod avx2 {
             Self::_argminmax(data)
         }
 
+        #[target_feature(enable = "avx2")]
+        unsafe fn argminmax_with_values(data: &[f64]) -> (usize, f64, usize, f64) {
+            Self::_argminmax_with_values(data)
+        }
+
         unsafe fn argmin(data: &[f64]) -> usize {
             Self::argminmax(data).0
         }
@@ -195,6 +200,19 @@ mod avx2 {
         unsafe fn argmax(data: &[f64]) -> usize {
             Self::argminmax(data).1
         }
+
+        #[target_feature(enable = "avx2")]
+        unsafe fn argminmax_last(data: &[f64]) -> (usize, usize) {
+            Self::_argminmax_last(data)
+        }
+
+        unsafe fn argmin_last(data: &[f64]) -> usize {
+            Self::argminmax_last(data).0
+        }
+
+        unsafe fn argmax_last(data: &[f64]) -> usize {
+            Self::argminmax_last(data).1
+        }
     }
 }
 
@@ -266,18 +284,18 @@ mod sse {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m128i, value: __m128i) -> (usize, f64) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, f64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i64ord_to_f64(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m128i, value: __m128i) -> (usize, f64) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, f64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i64ord_to_f64(max_value))
         }
     }
@@ -292,6 +310,11 @@ mod sse {
             Self::_argminmax(data)
         }
 
+        #[target_feature(enable = "sse4.2")]
+        unsafe fn argminmax_with_values(data: &[f64]) -> (usize, f64, usize, f64) {
+            Self::_argminmax_with_values(data)
+        }
+
         unsafe fn argmin(data: &[f64]) -> usize {
             Self::argminmax(data).0
         }
@@ -299,6 +322,19 @@ mod sse {
         unsafe fn argmax(data: &[f64]) -> usize {
             Self::argminmax(data).1
         }
+
+        #[target_feature(enable = "sse4.2")]
+        unsafe fn argminmax_last(data: &[f64]) -> (usize, usize) {
+            Self::_argminmax_last(data)
+        }
+
+        unsafe fn argmin_last(data: &[f64]) -> usize {
+            Self::argminmax_last(data).0
+        }
+
+        unsafe fn argmax_last(data: &[f64]) -> usize {
+            Self::argminmax_last(data).1
+        }
     }
 }
 
@@ -367,18 +403,18 @@ mod avx512 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m512i, value: __m512i) -> (usize, f64) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, f64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i64ord_to_f64(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m512i, value: __m512i) -> (usize, f64) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, f64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i64ord_to_f64(max_value))
         }
     }
@@ -391,6 +427,11 @@ mod avx512 {
             Self::_argminmax(data)
         }
 
+        #[target_feature(enable = "avx512f")]
+        unsafe fn argminmax_with_values(data: &[f64]) -> (usize, f64, usize, f64) {
+            Self::_argminmax_with_values(data)
+        }
+
         unsafe fn argmin(data: &[f64]) -> usize {
             Self::argminmax(data).0
         }
@@ -398,6 +439,19 @@ mod avx512 {
         unsafe fn argmax(data: &[f64]) -> usize {
             Self::argminmax(data).1
         }
+
+        #[target_feature(enable = "avx512f")]
+        unsafe fn argminmax_last(data: &[f64]) -> (usize, usize) {
+            Self::_argminmax_last(data)
+        }
+
+        unsafe fn argmin_last(data: &[f64]) -> usize {
+            Self::argminmax_last(data).0
+        }
+
+        unsafe fn argmax_last(data: &[f64]) -> usize {
+            Self::argminmax_last(data).1
+        }
     }
 }
 
@@ -485,18 +539,18 @@ mod neon {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: int64x2_t, value: int64x2_t) -> (usize, f64) {
+        unsafe fn _horiz_min<const LAST: bool>(index: int64x2_t, value: int64x2_t) -> (usize, f64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i64ord_to_f64(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: int64x2_t, value: int64x2_t) -> (usize, f64) {
+        unsafe fn _horiz_max<const LAST: bool>(index: int64x2_t, value: int64x2_t) -> (usize, f64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i64ord_to_f64(max_value))
         }
     }
@@ -511,6 +565,11 @@ mod neon {
             Self::_argminmax(data)
         }
 
+        #[target_feature(enable = "neon")]
+        unsafe fn argminmax_with_values(data: &[f64]) -> (usize, f64, usize, f64) {
+            Self::_argminmax_with_values(data)
+        }
+
         unsafe fn argmin(data: &[f64]) -> usize {
             Self::argminmax(data).0
         }
@@ -518,6 +577,19 @@ mod neon {
         unsafe fn argmax(data: &[f64]) -> usize {
             Self::argminmax(data).1
         }
+
+        #[target_feature(enable = "neon")]
+        unsafe fn argminmax_last(data: &[f64]) -> (usize, usize) {
+            Self::_argminmax_last(data)
+        }
+
+        unsafe fn argmin_last(data: &[f64]) -> usize {
+            Self::argminmax_last(data).0
+        }
+
+        unsafe fn argmax_last(data: &[f64]) -> usize {
+            Self::argminmax_last(data).1
+        }
     }
 }
 
@@ -544,7 +616,8 @@ mod tests {
     use crate::{FloatReturnNaN, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_return_same_result_argminmax, test_return_same_result_argminmax_last,
     };
     // Float specific tests
     use super::super::test_utils::{test_return_infs_argminmax, test_return_nans_argminmax};
@@ -608,6 +681,26 @@ mod tests {
         test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_last_index_is_returned_when_identical_values_found<
+        T,
+        SIMDV,
+        SIMDM,
+        const LANE_SIZE: usize,
+    >(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<f64, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -623,6 +716,21 @@ mod tests {
         test_return_same_result_argminmax(get_array_f64, SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<f64, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_return_same_result_argminmax_last(get_array_f64, SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_infs<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
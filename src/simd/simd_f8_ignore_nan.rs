@@ -0,0 +1,565 @@
+/// Implementation of the argminmax operations for FP8 (F8E4M3 and F8E5M2) that ignores
+/// NaN values.
+/// This implementation returns the index of the minimum and maximum values.
+/// However, unexpected behavior may occur when there are
+/// - *only* NaN values in the array
+/// - *only* +/- infinity (or +/- max for F8E4M3) values in the array
+/// - *only* NaN and +/- infinity (or +/- max for F8E4M3) values in the array
+///
+/// In these cases, index 0 is returned.
+///
+/// NaN values are ignored and treated as if they are not present in the array.
+/// To realize this we create an initial SIMD register with values +/- infinity (or
+/// +/- max for F8E4M3, as this format has no infinities) and mask out the NaN values
+/// in the comparisons.
+///
+/// As there are no FP8 SIMD instructions, we use the i8 SIMD instructions and
+/// reinterpret the FP8 values as i8 values. This is possible because we transform the
+/// FP8 values to ordinal i8 values:
+///     ord_i8 = ((v >> 7) & 0x7F) ^ v
+///
+/// This transformation is a bijection, i.e. it is reversible:
+///     v = ((ord_i8 >> 7) & 0x7F) ^ ord_i8
+///
+/// Through this transformation we can perform the argminmax operations on the ordinal
+/// integer values and then transform the result back to the original FP8 values.
+/// The SIMD operations are thus generated (through `impl_SIMD_OrdinalFloatIgnoreNaN!`)
+/// on top of the i8 SIMD operations.
+///
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use super::config::SIMDInstructionSet;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use super::generic::{
+    impl_SIMDArgMinMax, impl_SIMDInit_FloatIgnoreNaN, impl_SIMDOps_OrdinalFloat,
+    impl_SIMD_OrdinalFloatIgnoreNaN, SIMDArgMinMax, SIMDInit, SIMDOps,
+};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use crate::scalar::Float8;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use crate::SCALAR;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use num_traits::Zero;
+#[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
+use std::arch::aarch64::*;
+#[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
+use std::arch::arm::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use float8::{F8E4M3, F8E5M2};
+
+/// The dtype-strategy for performing operations on FP8 data: ignore NaN values
+/// (the SIMD operations of the Int strategy are reused on the ordinal i8 values)
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use super::super::dtype_strategy::{FloatIgnoreNaN, Int};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+const MASK_VALUE: i8 = 0x7F; // i8::MAX - masks everything but the sign bit
+
+// --------------------------------------- AVX2 ----------------------------------------
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2_ignore_nan {
+    use super::super::config::AVX2;
+    use super::*;
+
+    const LANE_SIZE: usize = AVX2::<FloatIgnoreNaN>::LANE_SIZE_8;
+    const LOWER_7_MASK: __m256i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
+
+    #[inline(always)]
+    unsafe fn _non_nan_mask<T: Float8>(mask: __m256i, ord_i8: __m256i) -> __m256i {
+        // on a scalar: (ord ^ (ord >> 7)) <= NAN_VALUE
+        // (for negative values this undoes the flipping of the lower 7 bits)
+        let sign_bit_shifted = _mm256_cmpgt_epi8(_mm256_setzero_si256(), ord_i8);
+        let abs_value = _mm256_xor_si256(ord_i8, sign_bit_shifted);
+        let nan_threshold = _mm256_set1_epi8(T::NAN_VALUE as i8 + 1);
+        _mm256_and_si256(mask, _mm256_cmpgt_epi8(nan_threshold, abs_value))
+    }
+
+    #[inline(always)]
+    unsafe fn _mm_set1_ord<T: Float8>(a: T) -> __m256i {
+        _mm256_set1_epi8(a.to_i8ord())
+    }
+
+    #[inline(always)]
+    unsafe fn _f8_as_m256i_to_i8ord(f8_as_m256i: __m256i) -> __m256i {
+        // on a scalar: ((v >> 7) & 0x7F) ^ v
+        // (there is no 8-bit arithmetic shift -> v >> 7 is computed as 0 > v)
+        let sign_bit_shifted = _mm256_cmpgt_epi8(_mm256_setzero_si256(), f8_as_m256i);
+        let sign_bit_masked = _mm256_and_si256(sign_bit_shifted, LOWER_7_MASK);
+        _mm256_xor_si256(sign_bit_masked, f8_as_m256i)
+    }
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        F8E4M3,
+        __m256i,
+        __m256i,
+        LANE_SIZE,
+        AVX2<FloatIgnoreNaN>,
+        "avx2",
+        i8,
+        AVX2<Int>,
+        _f8_as_m256i_to_i8ord,
+        <F8E4M3>::from_i8ord,
+        _non_nan_mask::<F8E4M3>,
+        _mm_set1_ord::<F8E4M3>
+    );
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        F8E5M2,
+        __m256i,
+        __m256i,
+        LANE_SIZE,
+        AVX2<FloatIgnoreNaN>,
+        "avx2",
+        i8,
+        AVX2<Int>,
+        _f8_as_m256i_to_i8ord,
+        <F8E5M2>::from_i8ord,
+        _non_nan_mask::<F8E5M2>,
+        _mm_set1_ord::<F8E5M2>
+    );
+}
+
+// ---------------------------------------- SSE ----------------------------------------
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod sse_ignore_nan {
+    use super::super::config::SSE;
+    use super::*;
+
+    const LANE_SIZE: usize = SSE::<FloatIgnoreNaN>::LANE_SIZE_8;
+    const LOWER_7_MASK: __m128i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
+
+    #[inline(always)]
+    unsafe fn _non_nan_mask<T: Float8>(mask: __m128i, ord_i8: __m128i) -> __m128i {
+        // on a scalar: (ord ^ (ord >> 7)) <= NAN_VALUE
+        // (for negative values this undoes the flipping of the lower 7 bits)
+        let sign_bit_shifted = _mm_cmplt_epi8(ord_i8, _mm_setzero_si128());
+        let abs_value = _mm_xor_si128(ord_i8, sign_bit_shifted);
+        let nan_threshold = _mm_set1_epi8(T::NAN_VALUE as i8 + 1);
+        _mm_and_si128(mask, _mm_cmplt_epi8(abs_value, nan_threshold))
+    }
+
+    #[inline(always)]
+    unsafe fn _mm_set1_ord<T: Float8>(a: T) -> __m128i {
+        _mm_set1_epi8(a.to_i8ord())
+    }
+
+    #[inline(always)]
+    unsafe fn _f8_as_m128i_to_i8ord(f8_as_m128i: __m128i) -> __m128i {
+        // on a scalar: ((v >> 7) & 0x7F) ^ v
+        // (there is no 8-bit arithmetic shift -> v >> 7 is computed as v < 0)
+        let sign_bit_shifted = _mm_cmplt_epi8(f8_as_m128i, _mm_setzero_si128());
+        let sign_bit_masked = _mm_and_si128(sign_bit_shifted, LOWER_7_MASK);
+        _mm_xor_si128(sign_bit_masked, f8_as_m128i)
+    }
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        F8E4M3,
+        __m128i,
+        __m128i,
+        LANE_SIZE,
+        SSE<FloatIgnoreNaN>,
+        "sse4.1",
+        i8,
+        SSE<Int>,
+        _f8_as_m128i_to_i8ord,
+        <F8E4M3>::from_i8ord,
+        _non_nan_mask::<F8E4M3>,
+        _mm_set1_ord::<F8E4M3>
+    );
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        F8E5M2,
+        __m128i,
+        __m128i,
+        LANE_SIZE,
+        SSE<FloatIgnoreNaN>,
+        "sse4.1",
+        i8,
+        SSE<Int>,
+        _f8_as_m128i_to_i8ord,
+        <F8E5M2>::from_i8ord,
+        _non_nan_mask::<F8E5M2>,
+        _mm_set1_ord::<F8E5M2>
+    );
+}
+
+// -------------------------------------- AVX512 ---------------------------------------
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "nightly_simd")]
+mod avx512_ignore_nan {
+    use super::super::config::AVX512;
+    use super::*;
+
+    const LANE_SIZE: usize = AVX512::<FloatIgnoreNaN>::LANE_SIZE_8;
+    const LOWER_7_MASK: __m512i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
+
+    #[inline(always)]
+    unsafe fn _non_nan_mask<T: Float8>(mask: u64, ord_i8: __m512i) -> u64 {
+        // on a scalar: (ord ^ (ord >> 7)) <= NAN_VALUE
+        // (for negative values this undoes the flipping of the lower 7 bits)
+        let sign_bit_mask = _mm512_movepi8_mask(ord_i8);
+        let abs_value = _mm512_mask_blend_epi8(
+            sign_bit_mask,
+            ord_i8,
+            _mm512_xor_si512(ord_i8, _mm512_set1_epi8(-1)),
+        );
+        let nan_threshold = _mm512_set1_epi8(T::NAN_VALUE as i8 + 1);
+        mask & _mm512_cmplt_epi8_mask(abs_value, nan_threshold)
+    }
+
+    #[inline(always)]
+    unsafe fn _mm_set1_ord<T: Float8>(a: T) -> __m512i {
+        _mm512_set1_epi8(a.to_i8ord())
+    }
+
+    #[inline(always)]
+    unsafe fn _f8_as_m512i_to_i8ord(f8_as_m512i: __m512i) -> __m512i {
+        // on a scalar: ((v >> 7) & 0x7F) ^ v
+        // (there is no 8-bit arithmetic shift -> only flip the lower 7 bits of the
+        //  negative values, i.e., where the sign bit is set)
+        let sign_bit_mask = _mm512_movepi8_mask(f8_as_m512i);
+        _mm512_mask_blend_epi8(
+            sign_bit_mask,
+            f8_as_m512i,
+            _mm512_xor_si512(f8_as_m512i, LOWER_7_MASK),
+        )
+    }
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        F8E4M3,
+        __m512i,
+        u64,
+        LANE_SIZE,
+        AVX512<FloatIgnoreNaN>,
+        "avx512bw",
+        i8,
+        AVX512<Int>,
+        _f8_as_m512i_to_i8ord,
+        <F8E4M3>::from_i8ord,
+        _non_nan_mask::<F8E4M3>,
+        _mm_set1_ord::<F8E4M3>
+    );
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        F8E5M2,
+        __m512i,
+        u64,
+        LANE_SIZE,
+        AVX512<FloatIgnoreNaN>,
+        "avx512bw",
+        i8,
+        AVX512<Int>,
+        _f8_as_m512i_to_i8ord,
+        <F8E5M2>::from_i8ord,
+        _non_nan_mask::<F8E5M2>,
+        _mm_set1_ord::<F8E5M2>
+    );
+}
+
+// --------------------------------------- NEON ----------------------------------------
+
+#[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+#[cfg(feature = "nightly_simd")]
+mod neon_ignore_nan {
+    use super::super::config::NEON;
+    use super::*;
+
+    const LANE_SIZE: usize = NEON::<FloatIgnoreNaN>::LANE_SIZE_8;
+    const LOWER_7_MASK: int8x16_t = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
+
+    #[inline(always)]
+    unsafe fn _non_nan_mask<T: Float8>(mask: uint8x16_t, ord_i8: int8x16_t) -> uint8x16_t {
+        // on a scalar: (ord ^ (ord >> 7)) <= NAN_VALUE
+        // (for negative values this undoes the flipping of the lower 7 bits)
+        let abs_value = veorq_s8(ord_i8, vshrq_n_s8(ord_i8, 7));
+        let nan_threshold = vdupq_n_s8(T::NAN_VALUE as i8 + 1);
+        vandq_u8(mask, vcltq_s8(abs_value, nan_threshold))
+    }
+
+    #[inline(always)]
+    unsafe fn _mm_set1_ord<T: Float8>(a: T) -> int8x16_t {
+        vdupq_n_s8(a.to_i8ord())
+    }
+
+    #[inline(always)]
+    unsafe fn _f8_as_int8x16_to_i8ord(f8_as_int8x16: int8x16_t) -> int8x16_t {
+        // on a scalar: ((v >> 7) & 0x7F) ^ v
+        let sign_bit_shifted = vshrq_n_s8(f8_as_int8x16, 7);
+        let sign_bit_masked = vandq_s8(sign_bit_shifted, LOWER_7_MASK);
+        veorq_s8(f8_as_int8x16, sign_bit_masked)
+    }
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        F8E4M3,
+        int8x16_t,
+        uint8x16_t,
+        LANE_SIZE,
+        NEON<FloatIgnoreNaN>,
+        "neon",
+        i8,
+        NEON<Int>,
+        _f8_as_int8x16_to_i8ord,
+        <F8E4M3>::from_i8ord,
+        _non_nan_mask::<F8E4M3>,
+        _mm_set1_ord::<F8E4M3>
+    );
+
+    impl_SIMD_OrdinalFloatIgnoreNaN!(
+        F8E5M2,
+        int8x16_t,
+        uint8x16_t,
+        LANE_SIZE,
+        NEON<FloatIgnoreNaN>,
+        "neon",
+        i8,
+        NEON<Int>,
+        _f8_as_int8x16_to_i8ord,
+        <F8E5M2>::from_i8ord,
+        _non_nan_mask::<F8E5M2>,
+        _mm_set1_ord::<F8E5M2>
+    );
+}
+
+// ======================================= TESTS =======================================
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    all(target_arch = "arm", feature = "nightly_simd"),
+    all(target_arch = "aarch64", feature = "nightly_simd"),
+))]
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rstest_reuse::{self, *};
+    use std::marker::PhantomData;
+
+    use float8::{F8E4M3, F8E5M2};
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[cfg(feature = "nightly_simd")]
+    use crate::simd::config::AVX512;
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    use crate::simd::config::NEON;
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    use crate::simd::config::{AVX2, SSE};
+    use crate::{FloatIgnoreNaN, SIMDArgMinMax, SCALAR};
+
+    use super::super::test_utils::{
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_no_overflow_argminmax, test_return_same_result_argminmax,
+        test_return_same_result_argminmax_last,
+    };
+    // Float specific tests
+    use super::super::test_utils::{test_ignore_nans_argminmax, test_return_infs_argminmax};
+
+    use dev_utils::utils;
+
+    fn get_array_f8e4m3(n: usize) -> Vec<F8E4M3> {
+        let arr = utils::get_random_array(n, i8::MIN, i8::MAX);
+        arr.iter().map(|x| F8E4M3::from_f32(*x as f32)).collect()
+    }
+
+    fn get_array_f8e5m2(n: usize) -> Vec<F8E5M2> {
+        let arr = utils::get_random_array(n, i8::MIN, i8::MAX);
+        arr.iter().map(|x| F8E5M2::from_f32(*x as f32)).collect()
+    }
+
+    // All bit patterns - i.e., also (positive and negative) NaNs and infinities
+    fn get_bits_array_f8e4m3(n: usize) -> Vec<F8E4M3> {
+        let arr = utils::get_random_array(n, u8::MIN, u8::MAX);
+        arr.iter().map(|x| F8E4M3::from_bits(*x)).collect()
+    }
+
+    fn get_bits_array_f8e5m2(n: usize) -> Vec<F8E5M2> {
+        let arr = utils::get_random_array(n, u8::MIN, u8::MAX);
+        arr.iter().map(|x| F8E5M2::from_bits(*x)).collect()
+    }
+
+    // The scalar implementation
+    const SCALAR_STRATEGY: SCALAR<FloatIgnoreNaN> = SCALAR {
+        _dtype_strategy: PhantomData::<FloatIgnoreNaN>,
+    };
+
+    // ------------ Template for x86 / x86_64 -------------
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[template]
+    #[rstest]
+    #[case::sse(SSE {_dtype_strategy: PhantomData::<FloatIgnoreNaN>}, is_x86_feature_detected!("sse4.1"))]
+    #[case::avx2(AVX2 {_dtype_strategy: PhantomData::<FloatIgnoreNaN>}, is_x86_feature_detected!("avx2"))]
+    #[cfg_attr(feature = "nightly_simd", case::avx512(AVX512 {_dtype_strategy: PhantomData::<FloatIgnoreNaN>}, is_x86_feature_detected!("avx512bw")))]
+    fn simd_implementations<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) {
+    }
+
+    // ------------ Template for ARM / AArch64 ------------
+
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    #[template]
+    #[rstest]
+    #[case::neon(NEON {_dtype_strategy: PhantomData::<FloatIgnoreNaN>}, true)]
+    fn simd_implementations<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) {
+    }
+
+    // ----------------- The actual tests -----------------
+
+    // The tests are identical for both FP8 formats -> generate a module per format
+    macro_rules! impl_f8_tests {
+        ($mod_name:ident, $f8_type:ty, $get_array:ident, $get_bits_array:ident) => {
+            mod $mod_name {
+                use super::*;
+
+                #[apply(simd_implementations)]
+                fn test_first_index_is_returned_when_identical_values_found<
+                    T,
+                    SIMDV,
+                    SIMDM,
+                    const LANE_SIZE: usize,
+                >(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_last_index_is_returned_when_identical_values_found<
+                    T,
+                    SIMDV,
+                    SIMDM,
+                    const LANE_SIZE: usize,
+                >(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_same_result_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_same_result_all_bit_patterns<
+                    T,
+                    SIMDV,
+                    SIMDM,
+                    const LANE_SIZE: usize,
+                >(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_same_result_argminmax($get_bits_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_same_result_argminmax_last($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_no_overflow<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_no_overflow_argminmax($get_array, SCALAR_STRATEGY, simd, None);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_infs<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_infs_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_ignore_nans<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatIgnoreNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_ignore_nans_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+            }
+        };
+    }
+
+    impl_f8_tests!(f8e4m3, F8E4M3, get_array_f8e4m3, get_bits_array_f8e4m3);
+    impl_f8_tests!(f8e5m2, F8E5M2, get_array_f8e5m2, get_bits_array_f8e5m2);
+}
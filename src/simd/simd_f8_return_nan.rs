@@ -0,0 +1,458 @@
+/// Implementation of the argminmax operations for FP8 (F8E4M3 and F8E5M2) where NaN
+/// values take precedence.
+/// This implementation returns the index of the first* NaN value if any are present,
+/// otherwise it returns the index of the minimum and maximum values.
+///
+/// To serve this functionality we transform the FP8 values to ordinal i8 values:
+///     ord_i8 = ((v >> 7) & 0x7F) ^ v
+///
+/// This transformation is a bijection, i.e. it is reversible:
+///     v = ((ord_i8 >> 7) & 0x7F) ^ ord_i8
+///
+/// Through this transformation we can perform the argminmax operations on the ordinal
+/// integer values (using the i8 SIMD instructions) and then transform the result back
+/// to the original FP8 values.
+/// As (positive) NaNs have a larger ordinal value than all other values and (negative)
+/// NaNs have a smaller ordinal value than all other values, NaNs always end up in the
+/// accumulating SIMD register. Both FP8 formats share this property:
+/// - F8E4M3: NaN is 0x7F / 0xFF (there are no infinities)
+/// - F8E5M2: NaN when the absolute value > 0x7C (0x7C / 0xFC are +/- infinity)
+///
+/// As there are no FP8 instructions on most CPUs, this allows to use the (fast) 8-bit
+/// integer SIMD instructions. The SIMD operations are thus generated (through
+/// `impl_SIMD_OrdinalFloatReturnNaN!`) on top of the i8 SIMD operations.
+///
+///
+/// ---
+///
+/// *Note: the first NaN value is only returned iff all NaN values have the same bit
+/// representation. When NaN values have different bit representations then the index of
+/// the highest / lowest ord_i8 is returned for the
+/// SIMDOps::_get_overflow_lane_size_limit() chunk of the data - which is not
+/// necessarily the index of the first NaN value.
+///
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use super::config::SIMDInstructionSet;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use super::generic::{
+    impl_SIMDInit_FloatReturnNaN, impl_SIMDOps_OrdinalFloat, impl_SIMD_OrdinalFloatReturnNaN,
+    SIMDArgMinMax, SIMDInit, SIMDOps,
+};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use crate::scalar::Float8;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use crate::SCALAR;
+#[cfg(all(target_arch = "aarch64", feature = "nightly_simd"))]
+use std::arch::aarch64::*;
+#[cfg(all(target_arch = "arm", feature = "nightly_simd"))]
+use std::arch::arm::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use float8::{F8E4M3, F8E5M2};
+
+/// The dtype-strategy for performing operations on FP8 data: return NaN index
+/// (the SIMD operations of the Int strategy are reused on the ordinal i8 values)
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+use super::super::dtype_strategy::{FloatReturnNaN, Int};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly_simd"))]
+const MASK_VALUE: i8 = 0x7F; // i8::MAX - masks everything but the sign bit
+
+// --------------------------------------- AVX2 ----------------------------------------
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2 {
+    use super::super::config::AVX2;
+    use super::*;
+
+    const LANE_SIZE: usize = AVX2::<FloatReturnNaN>::LANE_SIZE_8;
+    const LOWER_7_MASK: __m256i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
+
+    #[inline(always)]
+    unsafe fn _f8_as_m256i_to_i8ord(f8_as_m256i: __m256i) -> __m256i {
+        // on a scalar: ((v >> 7) & 0x7F) ^ v
+        // (there is no 8-bit arithmetic shift -> v >> 7 is computed as 0 > v)
+        let sign_bit_shifted = _mm256_cmpgt_epi8(_mm256_setzero_si256(), f8_as_m256i);
+        let sign_bit_masked = _mm256_and_si256(sign_bit_shifted, LOWER_7_MASK);
+        _mm256_xor_si256(sign_bit_masked, f8_as_m256i)
+    }
+
+    impl_SIMD_OrdinalFloatReturnNaN!(
+        F8E4M3,
+        __m256i,
+        __m256i,
+        LANE_SIZE,
+        AVX2<FloatReturnNaN>,
+        "avx2",
+        i8,
+        AVX2<Int>,
+        _f8_as_m256i_to_i8ord,
+        <F8E4M3>::from_i8ord
+    );
+
+    impl_SIMD_OrdinalFloatReturnNaN!(
+        F8E5M2,
+        __m256i,
+        __m256i,
+        LANE_SIZE,
+        AVX2<FloatReturnNaN>,
+        "avx2",
+        i8,
+        AVX2<Int>,
+        _f8_as_m256i_to_i8ord,
+        <F8E5M2>::from_i8ord
+    );
+}
+
+// ---------------------------------------- SSE ----------------------------------------
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod sse {
+    use super::super::config::SSE;
+    use super::*;
+
+    const LANE_SIZE: usize = SSE::<FloatReturnNaN>::LANE_SIZE_8;
+    const LOWER_7_MASK: __m128i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
+
+    #[inline(always)]
+    unsafe fn _f8_as_m128i_to_i8ord(f8_as_m128i: __m128i) -> __m128i {
+        // on a scalar: ((v >> 7) & 0x7F) ^ v
+        // (there is no 8-bit arithmetic shift -> v >> 7 is computed as v < 0)
+        let sign_bit_shifted = _mm_cmplt_epi8(f8_as_m128i, _mm_setzero_si128());
+        let sign_bit_masked = _mm_and_si128(sign_bit_shifted, LOWER_7_MASK);
+        _mm_xor_si128(sign_bit_masked, f8_as_m128i)
+    }
+
+    impl_SIMD_OrdinalFloatReturnNaN!(
+        F8E4M3,
+        __m128i,
+        __m128i,
+        LANE_SIZE,
+        SSE<FloatReturnNaN>,
+        "sse4.1",
+        i8,
+        SSE<Int>,
+        _f8_as_m128i_to_i8ord,
+        <F8E4M3>::from_i8ord
+    );
+
+    impl_SIMD_OrdinalFloatReturnNaN!(
+        F8E5M2,
+        __m128i,
+        __m128i,
+        LANE_SIZE,
+        SSE<FloatReturnNaN>,
+        "sse4.1",
+        i8,
+        SSE<Int>,
+        _f8_as_m128i_to_i8ord,
+        <F8E5M2>::from_i8ord
+    );
+}
+
+// -------------------------------------- AVX512 ---------------------------------------
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "nightly_simd")]
+mod avx512 {
+    use super::super::config::AVX512;
+    use super::*;
+
+    const LANE_SIZE: usize = AVX512::<FloatReturnNaN>::LANE_SIZE_8;
+    const LOWER_7_MASK: __m512i = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
+
+    #[inline(always)]
+    unsafe fn _f8_as_m512i_to_i8ord(f8_as_m512i: __m512i) -> __m512i {
+        // on a scalar: ((v >> 7) & 0x7F) ^ v
+        // (there is no 8-bit arithmetic shift -> only flip the lower 7 bits of the
+        //  negative values, i.e., where the sign bit is set)
+        let sign_bit_mask = _mm512_movepi8_mask(f8_as_m512i);
+        _mm512_mask_blend_epi8(
+            sign_bit_mask,
+            f8_as_m512i,
+            _mm512_xor_si512(f8_as_m512i, LOWER_7_MASK),
+        )
+    }
+
+    impl_SIMD_OrdinalFloatReturnNaN!(
+        F8E4M3,
+        __m512i,
+        u64,
+        LANE_SIZE,
+        AVX512<FloatReturnNaN>,
+        "avx512bw",
+        i8,
+        AVX512<Int>,
+        _f8_as_m512i_to_i8ord,
+        <F8E4M3>::from_i8ord
+    );
+
+    impl_SIMD_OrdinalFloatReturnNaN!(
+        F8E5M2,
+        __m512i,
+        u64,
+        LANE_SIZE,
+        AVX512<FloatReturnNaN>,
+        "avx512bw",
+        i8,
+        AVX512<Int>,
+        _f8_as_m512i_to_i8ord,
+        <F8E5M2>::from_i8ord
+    );
+}
+
+// --------------------------------------- NEON ----------------------------------------
+
+#[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+#[cfg(feature = "nightly_simd")]
+mod neon {
+    use super::super::config::NEON;
+    use super::*;
+
+    const LANE_SIZE: usize = NEON::<FloatReturnNaN>::LANE_SIZE_8;
+    const LOWER_7_MASK: int8x16_t = unsafe { std::mem::transmute([MASK_VALUE; LANE_SIZE]) };
+
+    #[inline(always)]
+    unsafe fn _f8_as_int8x16_to_i8ord(f8_as_int8x16: int8x16_t) -> int8x16_t {
+        // on a scalar: ((v >> 7) & 0x7F) ^ v
+        let sign_bit_shifted = vshrq_n_s8(f8_as_int8x16, 7);
+        let sign_bit_masked = vandq_s8(sign_bit_shifted, LOWER_7_MASK);
+        veorq_s8(f8_as_int8x16, sign_bit_masked)
+    }
+
+    impl_SIMD_OrdinalFloatReturnNaN!(
+        F8E4M3,
+        int8x16_t,
+        uint8x16_t,
+        LANE_SIZE,
+        NEON<FloatReturnNaN>,
+        "neon",
+        i8,
+        NEON<Int>,
+        _f8_as_int8x16_to_i8ord,
+        <F8E4M3>::from_i8ord
+    );
+
+    impl_SIMD_OrdinalFloatReturnNaN!(
+        F8E5M2,
+        int8x16_t,
+        uint8x16_t,
+        LANE_SIZE,
+        NEON<FloatReturnNaN>,
+        "neon",
+        i8,
+        NEON<Int>,
+        _f8_as_int8x16_to_i8ord,
+        <F8E5M2>::from_i8ord
+    );
+}
+
+// ======================================= TESTS =======================================
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    all(target_arch = "arm", feature = "nightly_simd"),
+    all(target_arch = "aarch64", feature = "nightly_simd"),
+))]
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rstest_reuse::{self, *};
+    use std::marker::PhantomData;
+
+    use float8::{F8E4M3, F8E5M2};
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[cfg(feature = "nightly_simd")]
+    use crate::simd::config::AVX512;
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    use crate::simd::config::NEON;
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    use crate::simd::config::{AVX2, SSE};
+    use crate::{FloatReturnNaN, SIMDArgMinMax, SCALAR};
+
+    use super::super::test_utils::{
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_no_overflow_argminmax, test_return_same_result_argminmax,
+        test_return_same_result_argminmax_last,
+    };
+    // Float specific tests
+    use super::super::test_utils::{test_return_infs_argminmax, test_return_nans_argminmax};
+
+    use dev_utils::utils;
+
+    fn get_array_f8e4m3(n: usize) -> Vec<F8E4M3> {
+        let arr = utils::get_random_array(n, i8::MIN, i8::MAX);
+        arr.iter().map(|x| F8E4M3::from_f32(*x as f32)).collect()
+    }
+
+    fn get_array_f8e5m2(n: usize) -> Vec<F8E5M2> {
+        let arr = utils::get_random_array(n, i8::MIN, i8::MAX);
+        arr.iter().map(|x| F8E5M2::from_f32(*x as f32)).collect()
+    }
+
+    // The scalar implementation
+    const SCALAR_STRATEGY: SCALAR<FloatReturnNaN> = SCALAR {
+        _dtype_strategy: PhantomData::<FloatReturnNaN>,
+    };
+
+    // ------------ Template for x86 / x86_64 -------------
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[template]
+    #[rstest]
+    #[case::sse(SSE {_dtype_strategy: PhantomData::<FloatReturnNaN>}, is_x86_feature_detected!("sse4.1"))]
+    #[case::avx2(AVX2 {_dtype_strategy: PhantomData::<FloatReturnNaN>}, is_x86_feature_detected!("avx2"))]
+    #[cfg_attr(feature = "nightly_simd", case::avx512(AVX512 {_dtype_strategy: PhantomData::<FloatReturnNaN>}, is_x86_feature_detected!("avx512bw")))]
+    fn simd_implementations<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) {
+    }
+
+    // ------------ Template for ARM / AArch64 ------------
+
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    #[template]
+    #[rstest]
+    #[case::neon(NEON {_dtype_strategy: PhantomData::<FloatReturnNaN>}, true)]
+    fn simd_implementations<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) {
+    }
+
+    // ----------------- The actual tests -----------------
+
+    // The tests are identical for both FP8 formats -> generate a module per format
+    macro_rules! impl_f8_tests {
+        ($mod_name:ident, $f8_type:ty, $get_array:ident) => {
+            mod $mod_name {
+                use super::*;
+
+                #[apply(simd_implementations)]
+                fn test_first_index_is_returned_when_identical_values_found<
+                    T,
+                    SIMDV,
+                    SIMDM,
+                    const LANE_SIZE: usize,
+                >(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_last_index_is_returned_when_identical_values_found<
+                    T,
+                    SIMDV,
+                    SIMDM,
+                    const LANE_SIZE: usize,
+                >(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_same_result_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_same_result_argminmax_last($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_no_overflow<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_no_overflow_argminmax($get_array, SCALAR_STRATEGY, simd, None);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_infs<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_infs_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+
+                #[apply(simd_implementations)]
+                fn test_return_nans<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                    #[case] simd: T,
+                    #[case] simd_available: bool,
+                ) where
+                    T: SIMDArgMinMax<$f8_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<FloatReturnNaN>>,
+                    SIMDV: Copy,
+                    SIMDM: Copy,
+                {
+                    if !simd_available {
+                        return;
+                    }
+                    test_return_nans_argminmax($get_array, SCALAR_STRATEGY, simd);
+                }
+            }
+        };
+    }
+
+    impl_f8_tests!(f8e4m3, F8E4M3, get_array_f8e4m3);
+    impl_f8_tests!(f8e5m2, F8E5M2, get_array_f8e5m2);
+}
@@ -71,7 +71,7 @@ mod avx2 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m256i, value: __m256i) -> (usize, i16) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, i16) {
             // 0. Find the minimum value
             let mut vmin: __m256i = value;
             vmin = _mm256_min_epi16(vmin, _mm256_permute2x128_si256(vmin, vmin, 1));
@@ -84,6 +84,13 @@ mod avx2 {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm256_cmpeq_epi16(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm256_xor_si256(index, _mm256_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm256_blendv_epi8(
                 _mm256_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
                 index,                       // if mask is 1, use index
@@ -95,13 +102,18 @@ mod avx2 {
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 8));
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 4));
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm256_xor_si256(imin, _mm256_set1_epi8(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm256_extract_epi16(imin, 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m256i, value: __m256i) -> (usize, i16) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, i16) {
             // 0. Find the maximum value
             let mut vmax: __m256i = value;
             vmax = _mm256_max_epi16(vmax, _mm256_permute2x128_si256(vmax, vmax, 1));
@@ -114,6 +126,13 @@ mod avx2 {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm256_cmpeq_epi16(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm256_xor_si256(index, _mm256_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm256_blendv_epi8(
                 _mm256_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
                 index,                       // if mask is 1, use index
@@ -125,6 +144,11 @@ mod avx2 {
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 8));
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 4));
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm256_xor_si256(imin, _mm256_set1_epi8(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm256_extract_epi16(imin, 0) as usize;
 
             (max_index, max_value)
@@ -191,7 +215,7 @@ mod sse {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m128i, value: __m128i) -> (usize, i16) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, i16) {
             // 0. Find the minimum value
             let mut vmin: __m128i = value;
             vmin = _mm_min_epi16(vmin, _mm_alignr_epi8(vmin, vmin, 8));
@@ -203,6 +227,13 @@ mod sse {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm_cmpeq_epi16(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm_xor_si128(index, _mm_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm_blendv_epi8(
                 _mm_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
                 index,                    // if mask is 1, use index
@@ -213,13 +244,18 @@ mod sse {
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 8));
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 4));
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm_xor_si128(imin, _mm_set1_epi8(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm_extract_epi16(imin, 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m128i, value: __m128i) -> (usize, i16) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, i16) {
             // 0. Find the maximum value
             let mut vmax: __m128i = value;
             vmax = _mm_max_epi16(vmax, _mm_alignr_epi8(vmax, vmax, 8));
@@ -231,6 +267,13 @@ mod sse {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm_cmpeq_epi16(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm_xor_si128(index, _mm_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm_blendv_epi8(
                 _mm_set1_epi16(i16::MAX), // if mask is 0, use i8::MAX
                 index,                    // if mask is 1, use index
@@ -241,6 +284,11 @@ mod sse {
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 8));
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 4));
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm_xor_si128(imin, _mm_set1_epi8(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm_extract_epi16(imin, 0) as usize;
 
             (max_index, max_value)
@@ -313,7 +361,7 @@ mod avx512 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m512i, value: __m512i) -> (usize, i16) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, i16) {
             // 0. Find the minimum value
             let mut vmin: __m512i = value;
             vmin = _mm512_min_epi16(vmin, _mm512_alignr_epi32(vmin, vmin, 8));
@@ -327,6 +375,13 @@ mod avx512 {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm512_cmpeq_epi16_mask(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm512_xor_si512(index, _mm512_set1_epi32(-1))
+            } else {
+                index
+            };
             let search_index = _mm512_mask_blend_epi16(
                 mask,
                 _mm512_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
@@ -339,13 +394,18 @@ mod avx512 {
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 8));
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 4));
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm512_xor_si512(imin, _mm512_set1_epi32(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm_extract_epi16(_mm512_castsi512_si128(imin), 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m512i, value: __m512i) -> (usize, i16) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, i16) {
             // 0. Find the maximum value
             let mut vmax: __m512i = value;
             vmax = _mm512_max_epi16(vmax, _mm512_alignr_epi32(vmax, vmax, 8));
@@ -359,6 +419,13 @@ mod avx512 {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm512_cmpeq_epi16_mask(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm512_xor_si512(index, _mm512_set1_epi32(-1))
+            } else {
+                index
+            };
             let search_index = _mm512_mask_blend_epi16(
                 mask,
                 _mm512_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
@@ -371,6 +438,11 @@ mod avx512 {
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 8));
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 4));
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm512_xor_si512(imin, _mm512_set1_epi32(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm_extract_epi16(_mm512_castsi512_si128(imin), 0) as usize;
 
             (max_index, max_value)
@@ -438,7 +510,7 @@ mod neon {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: int16x8_t, value: int16x8_t) -> (usize, i16) {
+        unsafe fn _horiz_min<const LAST: bool>(index: int16x8_t, value: int16x8_t) -> (usize, i16) {
             // 0. Find the minimum value
             let mut vmin: int16x8_t = value;
             vmin = vminq_s16(vmin, vextq_s16(vmin, vmin, 4));
@@ -450,6 +522,9 @@ mod neon {
             // 1. Create a mask with the index of the minimum value
             let mask = vceqq_s16(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST { vmvnq_s16(index) } else { index };
             let search_index = vbslq_s16(
                 mask,
                 index,                 // if mask is 1, use index
@@ -460,13 +535,14 @@ mod neon {
             imin = vminq_s16(imin, vextq_s16(imin, imin, 4));
             imin = vminq_s16(imin, vextq_s16(imin, imin, 2));
             imin = vminq_s16(imin, vextq_s16(imin, imin, 1));
+            let imin = if LAST { vmvnq_s16(imin) } else { imin };
             let min_index: usize = vgetq_lane_s16(imin, 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: int16x8_t, value: int16x8_t) -> (usize, i16) {
+        unsafe fn _horiz_max<const LAST: bool>(index: int16x8_t, value: int16x8_t) -> (usize, i16) {
             // 0. Find the maximum value
             let mut vmax: int16x8_t = value;
             vmax = vmaxq_s16(vmax, vextq_s16(vmax, vmax, 4));
@@ -478,6 +554,9 @@ mod neon {
             // 1. Create a mask with the index of the maximum value
             let mask = vceqq_s16(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST { vmvnq_s16(index) } else { index };
             let search_index = vbslq_s16(
                 mask,
                 index,                 // if mask is 1, use index
@@ -488,6 +567,7 @@ mod neon {
             imin = vminq_s16(imin, vextq_s16(imin, imin, 4));
             imin = vminq_s16(imin, vextq_s16(imin, imin, 2));
             imin = vminq_s16(imin, vextq_s16(imin, imin, 1));
+            let imin = if LAST { vmvnq_s16(imin) } else { imin };
             let max_index: usize = vgetq_lane_s16(imin, 0) as usize;
 
             (max_index, max_value)
@@ -531,8 +611,9 @@ mod tests {
     use crate::{Int, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_no_overflow_argminmax,
-        test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_no_overflow_argminmax, test_return_same_result_argminmax,
+        test_return_same_result_argminmax_last,
     };
 
     use dev_utils::utils;
@@ -594,6 +675,26 @@ mod tests {
         test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_last_index_is_returned_when_identical_values_found<
+        T,
+        SIMDV,
+        SIMDM,
+        const LANE_SIZE: usize,
+    >(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<i16, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -609,6 +710,21 @@ mod tests {
         test_return_same_result_argminmax(get_array_i16, SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<i16, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_return_same_result_argminmax_last(get_array_i16, SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_no_overflow<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -72,7 +72,7 @@ mod avx2 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m256i, value: __m256i) -> (usize, i8) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, i8) {
             // 0. Find the minimum value
             let mut vmin: __m256i = value;
             vmin = _mm256_min_epi8(vmin, _mm256_permute2x128_si256(vmin, vmin, 1));
@@ -86,6 +86,13 @@ mod avx2 {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm256_cmpeq_epi8(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm256_xor_si256(index, _mm256_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm256_blendv_epi8(
                 _mm256_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
                 index,                     // if mask is 1, use index
@@ -98,13 +105,18 @@ mod avx2 {
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 4));
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 2));
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm256_xor_si256(imin, _mm256_set1_epi8(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm256_extract_epi8(imin, 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m256i, value: __m256i) -> (usize, i8) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, i8) {
             // 0. Find the maximum value
             let mut vmax: __m256i = value;
             vmax = _mm256_max_epi8(vmax, _mm256_permute2x128_si256(vmax, vmax, 1));
@@ -118,6 +130,13 @@ mod avx2 {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm256_cmpeq_epi8(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm256_xor_si256(index, _mm256_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm256_blendv_epi8(
                 _mm256_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
                 index,                     // if mask is 1, use index
@@ -130,6 +149,11 @@ mod avx2 {
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 4));
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 2));
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm256_xor_si256(imin, _mm256_set1_epi8(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm256_extract_epi8(imin, 0) as usize;
 
             (max_index, max_value)
@@ -199,7 +223,7 @@ mod sse {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m128i, value: __m128i) -> (usize, i8) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, i8) {
             // 0. Find the minimum value
             let mut vmin: __m128i = value;
             vmin = _mm_min_epi8(vmin, _mm_alignr_epi8(vmin, vmin, 8));
@@ -212,6 +236,13 @@ mod sse {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm_cmpeq_epi8(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm_xor_si128(index, _mm_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm_blendv_epi8(
                 _mm_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
                 index,                  // if mask is 1, use index
@@ -223,13 +254,18 @@ mod sse {
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 4));
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 2));
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm_xor_si128(imin, _mm_set1_epi8(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm_extract_epi8(imin, 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m128i, value: __m128i) -> (usize, i8) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, i8) {
             // 0. Find the maximum value
             let mut vmax: __m128i = value;
             vmax = _mm_max_epi8(vmax, _mm_alignr_epi8(vmax, vmax, 8));
@@ -242,6 +278,13 @@ mod sse {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm_cmpeq_epi8(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm_xor_si128(index, _mm_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm_blendv_epi8(
                 _mm_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
                 index,                  // if mask is 1, use index
@@ -253,6 +296,11 @@ mod sse {
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 4));
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 2));
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm_xor_si128(imin, _mm_set1_epi8(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm_extract_epi8(imin, 0) as usize;
 
             (max_index, max_value)
@@ -327,7 +375,7 @@ mod avx512 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m512i, value: __m512i) -> (usize, i8) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, i8) {
             // 0. Find the minimum value
             let mut vmin: __m512i = value;
             vmin = _mm512_min_epi8(vmin, _mm512_alignr_epi32(vmin, vmin, 8));
@@ -342,6 +390,13 @@ mod avx512 {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm512_cmpeq_epi8_mask(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm512_xor_si512(index, _mm512_set1_epi32(-1))
+            } else {
+                index
+            };
             let search_index = _mm512_mask_blend_epi8(
                 mask,
                 _mm512_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
@@ -355,13 +410,18 @@ mod avx512 {
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 4));
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 2));
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm512_xor_si512(imin, _mm512_set1_epi32(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm_extract_epi8(_mm512_castsi512_si128(imin), 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m512i, value: __m512i) -> (usize, i8) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, i8) {
             // 0. Find the maximum value
             let mut vmax: __m512i = value;
             vmax = _mm512_max_epi8(vmax, _mm512_alignr_epi32(vmax, vmax, 8));
@@ -376,6 +436,13 @@ mod avx512 {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm512_cmpeq_epi8_mask(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm512_xor_si512(index, _mm512_set1_epi32(-1))
+            } else {
+                index
+            };
             let search_index = _mm512_mask_blend_epi8(
                 mask,
                 _mm512_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
@@ -389,6 +456,11 @@ mod avx512 {
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 4));
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 2));
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm512_xor_si512(imin, _mm512_set1_epi32(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm_extract_epi8(_mm512_castsi512_si128(imin), 0) as usize;
 
             (max_index, max_value)
@@ -461,7 +533,7 @@ mod neon {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: int8x16_t, value: int8x16_t) -> (usize, i8) {
+        unsafe fn _horiz_min<const LAST: bool>(index: int8x16_t, value: int8x16_t) -> (usize, i8) {
             // 0. Find the minimum value
             let mut vmin: int8x16_t = value;
             vmin = vminq_s8(vmin, vextq_s8(vmin, vmin, 8));
@@ -474,6 +546,9 @@ mod neon {
             // 1. Create a mask with the index of the minimum value
             let mask = vceqq_s8(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST { vmvnq_s8(index) } else { index };
             let search_index = vbslq_s8(
                 mask,
                 index,               // if mask is 1, use index
@@ -485,13 +560,14 @@ mod neon {
             imin = vminq_s8(imin, vextq_s8(imin, imin, 4));
             imin = vminq_s8(imin, vextq_s8(imin, imin, 2));
             imin = vminq_s8(imin, vextq_s8(imin, imin, 1));
+            let imin = if LAST { vmvnq_s8(imin) } else { imin };
             let min_index: usize = vgetq_lane_s8(imin, 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: int8x16_t, value: int8x16_t) -> (usize, i8) {
+        unsafe fn _horiz_max<const LAST: bool>(index: int8x16_t, value: int8x16_t) -> (usize, i8) {
             // 0. Find the maximum value
             let mut vmax: int8x16_t = value;
             vmax = vmaxq_s8(vmax, vextq_s8(vmax, vmax, 8));
@@ -504,6 +580,9 @@ mod neon {
             // 1. Create a mask with the index of the maximum value
             let mask = vceqq_s8(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST { vmvnq_s8(index) } else { index };
             let search_index = vbslq_s8(
                 mask,
                 index,               // if mask is 1, use index
@@ -515,6 +594,7 @@ mod neon {
             imin = vminq_s8(imin, vextq_s8(imin, imin, 4));
             imin = vminq_s8(imin, vextq_s8(imin, imin, 2));
             imin = vminq_s8(imin, vextq_s8(imin, imin, 1));
+            let imin = if LAST { vmvnq_s8(imin) } else { imin };
             let max_index: usize = vgetq_lane_s8(imin, 0) as usize;
 
             (max_index, max_value)
@@ -558,8 +638,9 @@ mod tests {
     use crate::{Int, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_no_overflow_argminmax,
-        test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_no_overflow_argminmax, test_return_same_result_argminmax,
+        test_return_same_result_argminmax_last,
     };
 
     use dev_utils::utils;
@@ -621,6 +702,26 @@ mod tests {
         test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_last_index_is_returned_when_identical_values_found<
+        T,
+        SIMDV,
+        SIMDM,
+        const LANE_SIZE: usize,
+    >(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<i8, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -636,6 +737,21 @@ mod tests {
         test_return_same_result_argminmax(get_array_i8, SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<i8, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_return_same_result_argminmax_last(get_array_i8, SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_no_overflow<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
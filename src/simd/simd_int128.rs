@@ -0,0 +1,385 @@
+// Implementation of the argminmax operations for i128 and u128.
+// As there are no SIMD instructions for 128-bit integers, we compare the 128-bit lanes
+// through their 64-bit halves (on x86 & x86_64 the high half is stored in the upper
+// 64 bits of the lane):
+//     a > b  <=>  (a_hi > b_hi) | ((a_hi == b_hi) & (a_lo >(unsigned) b_lo))
+// where the comparison of the high halves is signed for i128 and unsigned for u128.
+//
+// There are no unsigned 64-bit comparisons either, hence we transform the unsigned
+// halves to i64 ordinal values before comparing them:
+//     ord_i64 = v ^ -0x8000000000000000
+//
+// This transformation is only applied in the comparison, so the registers contain the
+// original values (and the horizontal min / max can be performed on these).
+//
+// The index register holds 128-bit lanes as well, which are incremented through their
+// low (64-bit) halves - their high halves remain zero.
+//
+// The implementation for both data types is generated by the `impl_SIMD_int128!`
+// macro, which is parameterized by the transformation of the (low, high) halves.
+//
+// Note: there is no SSE implementation, as a 128-bit register holds only a single
+// 128-bit value (and is thus not faster than the scalar implementation).
+//
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use super::config::SIMDInstructionSet;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use super::generic::{impl_SIMDArgMinMax, impl_SIMDInit_Int};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use super::generic::{SIMDArgMinMax, SIMDInit, SIMDOps};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::SCALAR;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// The dtype-strategy for performing operations on 128-bit integer data: (default) Int
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use super::super::dtype_strategy::Int;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const XOR_VALUE: i64 = -0x8000000000000000; // i64::MIN
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const MAX_INDEX: usize = i64::MAX as usize; // SIMD operations on the low 64-bit halves
+
+/// Implement the SIMD operations for a 128-bit integer type.
+///
+/// - `$int_type` - the 128-bit integer type (i128 or u128)
+/// - `$xor_halves` - the values to XOR the (low, high) 64-bit halves with before the
+///   signed 64-bit comparison (i.e., `XOR_VALUE` for an unsigned half, 0 for a signed
+///   half)
+/// - `$low_half_min` / `$low_half_max` - the range of the test values that (mostly)
+///   only differ in their low 64-bit halves
+///
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+macro_rules! impl_SIMD_int128 {
+    ($int_type:ty, $xor_halves:expr, $low_half_min:expr, $low_half_max:expr) => {
+        // ----------------------------------- AVX2 ------------------------------------
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        mod avx2 {
+            use crate::simd::config::AVX2;
+            use super::*;
+
+            const LANE_SIZE: usize = AVX2::<Int>::LANE_SIZE_128;
+            const XOR_MASK: __m256i = unsafe { std::mem::transmute([$xor_halves; LANE_SIZE]) };
+
+            /// Compare the 128-bit lanes for greater-than (gt): a > b
+            /// Returns a mask where all bits of the lane are set when a > b
+            #[inline(always)]
+            unsafe fn _mm256_cmpgt_128(a: __m256i, b: __m256i) -> __m256i {
+                // Signed comparison of the (transformed) high and low halves
+                let gt = _mm256_cmpgt_epi64(
+                    _mm256_xor_si256(a, XOR_MASK),
+                    _mm256_xor_si256(b, XOR_MASK),
+                );
+                let eq = _mm256_cmpeq_epi64(a, b);
+                // Move the low half result to the high half of each 128-bit lane
+                let gt_lo = _mm256_slli_si256(gt, 8);
+                // The high half now contains the result of the 128-bit comparison
+                let res = _mm256_or_si256(gt, _mm256_and_si256(eq, gt_lo));
+                // Broadcast the high half over the whole 128-bit lane
+                _mm256_shuffle_epi32(res, 0b11_10_11_10)
+            }
+
+            impl SIMDOps<$int_type, __m256i, __m256i, LANE_SIZE> for AVX2<Int> {
+                const INITIAL_INDEX: __m256i = unsafe { std::mem::transmute([0 as $int_type, 1]) };
+                const INDEX_INCREMENT: __m256i =
+                    unsafe { std::mem::transmute([LANE_SIZE as $int_type; LANE_SIZE]) };
+                const MAX_INDEX: usize = MAX_INDEX;
+
+                #[inline(always)]
+                unsafe fn _reg_to_arr(reg: __m256i) -> [$int_type; LANE_SIZE] {
+                    std::mem::transmute::<__m256i, [$int_type; LANE_SIZE]>(reg)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_loadu(data: *const $int_type) -> __m256i {
+                    _mm256_loadu_si256(data as *const __m256i)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_add(a: __m256i, b: __m256i) -> __m256i {
+                    // Only used for the index (of which the high halves remain zero)
+                    _mm256_add_epi64(a, b)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_cmpgt(a: __m256i, b: __m256i) -> __m256i {
+                    _mm256_cmpgt_128(a, b)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_cmplt(a: __m256i, b: __m256i) -> __m256i {
+                    _mm256_cmpgt_128(b, a)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_blendv(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+                    _mm256_blendv_epi8(a, b, mask)
+                }
+            }
+
+            impl_SIMDInit_Int!($int_type, __m256i, __m256i, LANE_SIZE, AVX2<Int>);
+
+            impl_SIMDArgMinMax!(
+                $int_type,
+                __m256i,
+                __m256i,
+                LANE_SIZE,
+                SCALAR<Int>,
+                AVX2<Int>,
+                "avx2"
+            );
+        }
+
+        // ---------------------------------- AVX512 -----------------------------------
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(feature = "nightly_simd")]
+        mod avx512 {
+            use crate::simd::config::AVX512;
+            use super::*;
+
+            const LANE_SIZE: usize = AVX512::<Int>::LANE_SIZE_128;
+            const XOR_MASK: __m512i = unsafe { std::mem::transmute([$xor_halves; LANE_SIZE]) };
+            /// The bits of the mask that correspond to the high halves of the 128-bit lanes
+            const HIGH_HALVES: u8 = 0b10101010;
+
+            /// Compare the 128-bit lanes for greater-than (gt): a > b
+            /// Returns a mask where both bits of the lane are set when a > b
+            #[inline(always)]
+            unsafe fn _mm512_cmpgt_128_mask(a: __m512i, b: __m512i) -> u8 {
+                // Signed comparison of the (transformed) high and low halves
+                let gt = _mm512_cmpgt_epi64_mask(
+                    _mm512_xor_si512(a, XOR_MASK),
+                    _mm512_xor_si512(b, XOR_MASK),
+                );
+                let eq = _mm512_cmpeq_epi64_mask(a, b);
+                // The high half bits now contain the result of the 128-bit comparison
+                let res = (gt | (eq & (gt << 1))) & HIGH_HALVES;
+                // Broadcast the high half bit to the low half bit
+                res | (res >> 1)
+            }
+
+            impl SIMDOps<$int_type, __m512i, u8, LANE_SIZE> for AVX512<Int> {
+                const INITIAL_INDEX: __m512i =
+                    unsafe { std::mem::transmute([0 as $int_type, 1, 2, 3]) };
+                const INDEX_INCREMENT: __m512i =
+                    unsafe { std::mem::transmute([LANE_SIZE as $int_type; LANE_SIZE]) };
+                const MAX_INDEX: usize = MAX_INDEX;
+
+                #[inline(always)]
+                unsafe fn _reg_to_arr(reg: __m512i) -> [$int_type; LANE_SIZE] {
+                    std::mem::transmute::<__m512i, [$int_type; LANE_SIZE]>(reg)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_loadu(data: *const $int_type) -> __m512i {
+                    _mm512_loadu_epi64(data as *const i64)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_add(a: __m512i, b: __m512i) -> __m512i {
+                    // Only used for the index (of which the high halves remain zero)
+                    _mm512_add_epi64(a, b)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_cmpgt(a: __m512i, b: __m512i) -> u8 {
+                    _mm512_cmpgt_128_mask(a, b)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_cmplt(a: __m512i, b: __m512i) -> u8 {
+                    _mm512_cmpgt_128_mask(b, a)
+                }
+
+                #[inline(always)]
+                unsafe fn _mm_blendv(a: __m512i, b: __m512i, mask: u8) -> __m512i {
+                    _mm512_mask_blend_epi64(mask, a, b)
+                }
+            }
+
+            impl_SIMDInit_Int!($int_type, __m512i, u8, LANE_SIZE, AVX512<Int>);
+
+            impl_SIMDArgMinMax!(
+                $int_type,
+                __m512i,
+                u8,
+                LANE_SIZE,
+                SCALAR<Int>,
+                AVX512<Int>,
+                "avx512f"
+            );
+        }
+
+        // =================================== TESTS ===================================
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(test)]
+        mod tests {
+            use rstest::rstest;
+            use rstest_reuse::{self, *};
+            use std::marker::PhantomData;
+
+            use crate::simd::config::AVX2;
+            #[cfg(feature = "nightly_simd")]
+            use crate::simd::config::AVX512;
+            use crate::{Int, SIMDArgMinMax, SCALAR};
+
+            use crate::simd::test_utils::{
+                test_first_index_identical_values_argminmax,
+                test_last_index_identical_values_argminmax,
+                test_return_same_result_argminmax, test_return_same_result_argminmax_last,
+            };
+
+            use dev_utils::utils;
+
+            fn get_array(n: usize) -> Vec<$int_type> {
+                utils::get_random_array(n, <$int_type>::MIN, <$int_type>::MAX)
+            }
+
+            /// Values that (mostly) only differ in their low 64-bit halves
+            fn get_array_low_half(n: usize) -> Vec<$int_type> {
+                utils::get_random_array(n, $low_half_min, $low_half_max)
+            }
+
+            // The scalar implementation
+            const SCALAR_STRATEGY: SCALAR<Int> = SCALAR {
+                _dtype_strategy: PhantomData::<Int>,
+            };
+
+            // ------------ Template for x86 / x86_64 -------------
+
+            #[template]
+            #[rstest]
+            #[case::avx2(AVX2 {_dtype_strategy: PhantomData::<Int>}, is_x86_feature_detected!("avx2"))]
+            #[cfg_attr(feature = "nightly_simd", case::avx512(AVX512 {_dtype_strategy: PhantomData::<Int>}, is_x86_feature_detected!("avx512f")))]
+            fn simd_implementations<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                #[case] simd: T,
+                #[case] simd_available: bool,
+            ) {
+            }
+
+            // ----------------- The actual tests -----------------
+
+            #[apply(simd_implementations)]
+            fn test_first_index_is_returned_when_identical_values_found<
+                T,
+                SIMDV,
+                SIMDM,
+                const LANE_SIZE: usize,
+            >(
+                #[case] simd: T,
+                #[case] simd_available: bool,
+            ) where
+                T: SIMDArgMinMax<$int_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+                SIMDV: Copy,
+                SIMDM: Copy,
+            {
+                if !simd_available {
+                    return;
+                }
+                test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+            }
+
+            #[apply(simd_implementations)]
+            fn test_last_index_is_returned_when_identical_values_found<
+                T,
+                SIMDV,
+                SIMDM,
+                const LANE_SIZE: usize,
+            >(
+                #[case] simd: T,
+                #[case] simd_available: bool,
+            ) where
+                T: SIMDArgMinMax<$int_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+                SIMDV: Copy,
+                SIMDM: Copy,
+            {
+                if !simd_available {
+                    return;
+                }
+                test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+            }
+
+            #[apply(simd_implementations)]
+            fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                #[case] simd: T,
+                #[case] simd_available: bool,
+            ) where
+                T: SIMDArgMinMax<$int_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+                SIMDV: Copy,
+                SIMDM: Copy,
+            {
+                if !simd_available {
+                    return;
+                }
+                test_return_same_result_argminmax(get_array, SCALAR_STRATEGY, simd);
+            }
+
+            #[apply(simd_implementations)]
+            fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                #[case] simd: T,
+                #[case] simd_available: bool,
+            ) where
+                T: SIMDArgMinMax<$int_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+                SIMDV: Copy,
+                SIMDM: Copy,
+            {
+                if !simd_available {
+                    return;
+                }
+                test_return_same_result_argminmax_last(get_array, SCALAR_STRATEGY, simd);
+            }
+
+            #[apply(simd_implementations)]
+            fn test_return_same_result_low_half<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                #[case] simd: T,
+                #[case] simd_available: bool,
+            ) where
+                T: SIMDArgMinMax<$int_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+                SIMDV: Copy,
+                SIMDM: Copy,
+            {
+                if !simd_available {
+                    return;
+                }
+                test_return_same_result_argminmax(get_array_low_half, SCALAR_STRATEGY, simd);
+            }
+
+            #[apply(simd_implementations)]
+            fn test_return_same_result_last_low_half<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+                #[case] simd: T,
+                #[case] simd_available: bool,
+            ) where
+                T: SIMDArgMinMax<$int_type, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+                SIMDV: Copy,
+                SIMDM: Copy,
+            {
+                if !simd_available {
+                    return;
+                }
+                test_return_same_result_argminmax_last(get_array_low_half, SCALAR_STRATEGY, simd);
+            }
+        }
+    };
+}
+
+/// i128: the low half is unsigned, the high half is signed (no transformation)
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod i128_impl {
+    use super::*;
+    impl_SIMD_int128!(i128, [XOR_VALUE, 0], -(u64::MAX as i128), u64::MAX as i128);
+}
+
+/// u128: both halves are unsigned
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod u128_impl {
+    use super::*;
+    impl_SIMD_int128!(u128, [XOR_VALUE; 2], u128::MIN, 2 * (u64::MAX as u128));
+}
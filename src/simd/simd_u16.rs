@@ -112,7 +112,7 @@ mod avx2 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m256i, value: __m256i) -> (usize, u16) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, u16) {
             // 0. Find the minimum value
             let mut vmin: __m256i = value;
             vmin = _mm256_min_epi16(vmin, _mm256_permute2x128_si256(vmin, vmin, 1));
@@ -125,6 +125,13 @@ mod avx2 {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm256_cmpeq_epi16(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm256_xor_si256(index, _mm256_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm256_blendv_epi8(
                 _mm256_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
                 index,                       // if mask is 1, use index
@@ -136,13 +143,18 @@ mod avx2 {
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 8));
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 4));
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm256_xor_si256(imin, _mm256_set1_epi8(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm256_extract_epi16(imin, 0) as usize;
 
             (min_index, _i16ord_to_u16(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m256i, value: __m256i) -> (usize, u16) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, u16) {
             // 0. Find the maximum value
             let mut vmax: __m256i = value;
             vmax = _mm256_max_epi16(vmax, _mm256_permute2x128_si256(vmax, vmax, 1));
@@ -155,6 +167,13 @@ mod avx2 {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm256_cmpeq_epi16(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm256_xor_si256(index, _mm256_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm256_blendv_epi8(
                 _mm256_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
                 index,                       // if mask is 1, use index
@@ -166,6 +185,11 @@ mod avx2 {
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 8));
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 4));
             imin = _mm256_min_epi16(imin, _mm256_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm256_xor_si256(imin, _mm256_set1_epi8(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm256_extract_epi16(imin, 0) as usize;
 
             (max_index, _i16ord_to_u16(max_value))
@@ -248,7 +272,7 @@ mod sse {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m128i, value: __m128i) -> (usize, u16) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, u16) {
             // 0. Find the minimum value
             let mut vmin: __m128i = value;
             vmin = _mm_min_epi16(vmin, _mm_alignr_epi8(vmin, vmin, 8));
@@ -260,6 +284,13 @@ mod sse {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm_cmpeq_epi16(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm_xor_si128(index, _mm_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm_blendv_epi8(
                 _mm_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
                 index,                    // if mask is 1, use index
@@ -270,13 +301,18 @@ mod sse {
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 8));
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 4));
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm_xor_si128(imin, _mm_set1_epi8(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm_extract_epi16(imin, 0) as usize;
 
             (min_index, _i16ord_to_u16(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m128i, value: __m128i) -> (usize, u16) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, u16) {
             // 0. Find the maximum value
             let mut vmax: __m128i = value;
             vmax = _mm_max_epi16(vmax, _mm_alignr_epi8(vmax, vmax, 8));
@@ -288,6 +324,13 @@ mod sse {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm_cmpeq_epi16(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm_xor_si128(index, _mm_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm_blendv_epi8(
                 _mm_set1_epi16(i16::MAX), // if mask is 0, use i8::MAX
                 index,                    // if mask is 1, use index
@@ -298,6 +341,11 @@ mod sse {
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 8));
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 4));
             imin = _mm_min_epi16(imin, _mm_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm_xor_si128(imin, _mm_set1_epi8(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm_extract_epi16(imin, 0) as usize;
 
             (max_index, _i16ord_to_u16(max_value))
@@ -386,7 +434,7 @@ mod avx512 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m512i, value: __m512i) -> (usize, u16) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, u16) {
             // 0. Find the minimum value
             let mut vmin: __m512i = value;
             vmin = _mm512_min_epi16(vmin, _mm512_alignr_epi32(vmin, vmin, 8));
@@ -400,6 +448,13 @@ mod avx512 {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm512_cmpeq_epi16_mask(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm512_xor_si512(index, _mm512_set1_epi32(-1))
+            } else {
+                index
+            };
             let search_index = _mm512_mask_blend_epi16(
                 mask,
                 _mm512_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
@@ -412,13 +467,18 @@ mod avx512 {
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 8));
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 4));
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm512_xor_si512(imin, _mm512_set1_epi32(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm_extract_epi16(_mm512_castsi512_si128(imin), 0) as usize;
 
             (min_index, _i16ord_to_u16(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m512i, value: __m512i) -> (usize, u16) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, u16) {
             // 0. Find the maximum value
             let mut vmax: __m512i = value;
             vmax = _mm512_max_epi16(vmax, _mm512_alignr_epi32(vmax, vmax, 8));
@@ -432,6 +492,13 @@ mod avx512 {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm512_cmpeq_epi16_mask(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm512_xor_si512(index, _mm512_set1_epi32(-1))
+            } else {
+                index
+            };
             let search_index = _mm512_mask_blend_epi16(
                 mask,
                 _mm512_set1_epi16(i16::MAX), // if mask is 0, use i16::MAX
@@ -444,6 +511,11 @@ mod avx512 {
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 8));
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 4));
             imin = _mm512_min_epi16(imin, _mm512_alignr_epi8(imin, imin, 2));
+            let imin = if LAST {
+                _mm512_xor_si512(imin, _mm512_set1_epi32(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm_extract_epi16(_mm512_castsi512_si128(imin), 0) as usize;
 
             (max_index, _i16ord_to_u16(max_value))
@@ -511,7 +583,10 @@ mod neon {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: uint16x8_t, value: uint16x8_t) -> (usize, u16) {
+        unsafe fn _horiz_min<const LAST: bool>(
+            index: uint16x8_t,
+            value: uint16x8_t,
+        ) -> (usize, u16) {
             // 0. Find the minimum value
             let mut vmin: uint16x8_t = value;
             vmin = vminq_u16(vmin, vextq_u16(vmin, vmin, 4));
@@ -523,6 +598,9 @@ mod neon {
             // 1. Create a mask with the index of the minimum value
             let mask = vceqq_u16(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST { vmvnq_u16(index) } else { index };
             let search_index = vbslq_u16(
                 mask,
                 index,                 // if mask is 1, use index
@@ -533,13 +611,17 @@ mod neon {
             imin = vminq_u16(imin, vextq_u16(imin, imin, 4));
             imin = vminq_u16(imin, vextq_u16(imin, imin, 2));
             imin = vminq_u16(imin, vextq_u16(imin, imin, 1));
+            let imin = if LAST { vmvnq_u16(imin) } else { imin };
             let min_index: usize = vgetq_lane_u16(imin, 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: uint16x8_t, value: uint16x8_t) -> (usize, u16) {
+        unsafe fn _horiz_max<const LAST: bool>(
+            index: uint16x8_t,
+            value: uint16x8_t,
+        ) -> (usize, u16) {
             // 0. Find the maximum value
             let mut vmax: uint16x8_t = value;
             vmax = vmaxq_u16(vmax, vextq_u16(vmax, vmax, 4));
@@ -551,6 +633,9 @@ mod neon {
             // 1. Create a mask with the index of the maximum value
             let mask = vceqq_u16(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST { vmvnq_u16(index) } else { index };
             let search_index = vbslq_u16(
                 mask,
                 index,                 // if mask is 1, use index
@@ -561,6 +646,7 @@ mod neon {
             imin = vminq_u16(imin, vextq_u16(imin, imin, 4));
             imin = vminq_u16(imin, vextq_u16(imin, imin, 2));
             imin = vminq_u16(imin, vextq_u16(imin, imin, 1));
+            let imin = if LAST { vmvnq_u16(imin) } else { imin };
             let max_index: usize = vgetq_lane_u16(imin, 0) as usize;
 
             (max_index, max_value)
@@ -604,8 +690,9 @@ mod tests {
     use crate::{Int, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_no_overflow_argminmax,
-        test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_no_overflow_argminmax, test_return_same_result_argminmax,
+        test_return_same_result_argminmax_last,
     };
 
     use dev_utils::utils;
@@ -667,6 +754,26 @@ mod tests {
         test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_last_index_is_returned_when_identical_values_found<
+        T,
+        SIMDV,
+        SIMDM,
+        const LANE_SIZE: usize,
+    >(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<u16, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -682,6 +789,21 @@ mod tests {
         test_return_same_result_argminmax(get_array_u16, SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<u16, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_return_same_result_argminmax_last(get_array_u16, SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_no_overflow<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -118,18 +118,18 @@ mod avx2 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m256i, value: __m256i) -> (usize, u64) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, u64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i64ord_to_u64(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m256i, value: __m256i) -> (usize, u64) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, u64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i64ord_to_u64(max_value))
         }
     }
@@ -209,18 +209,18 @@ mod sse {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m128i, value: __m128i) -> (usize, u64) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, u64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i64ord_to_u64(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m128i, value: __m128i) -> (usize, u64) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, u64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i64ord_to_u64(max_value))
         }
     }
@@ -302,18 +302,18 @@ mod avx512 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m512i, value: __m512i) -> (usize, u64) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, u64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (min_index, min_value) = min_index_value(&index_arr, &value_arr);
+            let (min_index, min_value) = min_index_value::<_, LAST>(&index_arr, &value_arr);
             (min_index as usize, _i64ord_to_u64(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m512i, value: __m512i) -> (usize, u64) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, u64) {
             let index_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(index);
             let value_arr: [i64; LANE_SIZE] = _reg_to_i64_arr(value);
-            let (max_index, max_value) = max_index_value(&index_arr, &value_arr);
+            let (max_index, max_value) = max_index_value::<_, LAST>(&index_arr, &value_arr);
             (max_index as usize, _i64ord_to_u64(max_value))
         }
     }
@@ -434,7 +434,8 @@ mod tests {
     use crate::{Int, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_return_same_result_argminmax, test_return_same_result_argminmax_last,
     };
 
     use dev_utils::utils;
@@ -496,6 +497,26 @@ mod tests {
         test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_last_index_is_returned_when_identical_values_found<
+        T,
+        SIMDV,
+        SIMDM,
+        const LANE_SIZE: usize,
+    >(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<u64, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -510,4 +531,19 @@ mod tests {
         }
         test_return_same_result_argminmax(get_array_u64, SCALAR_STRATEGY, simd);
     }
+
+    #[apply(simd_implementations)]
+    fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<u64, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_return_same_result_argminmax_last(get_array_u64, SCALAR_STRATEGY, simd);
+    }
 }
@@ -113,7 +113,7 @@ mod avx2 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m256i, value: __m256i) -> (usize, u8) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, u8) {
             // 0. Find the minimum value
             let mut vmin: __m256i = value;
             vmin = _mm256_min_epi8(vmin, _mm256_permute2x128_si256(vmin, vmin, 1));
@@ -127,6 +127,13 @@ mod avx2 {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm256_cmpeq_epi8(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm256_xor_si256(index, _mm256_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm256_blendv_epi8(
                 _mm256_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
                 index,                     // if mask is 1, use index
@@ -139,13 +146,18 @@ mod avx2 {
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 4));
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 2));
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm256_xor_si256(imin, _mm256_set1_epi8(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm256_extract_epi8(imin, 0) as usize;
 
             (min_index, _i8ord_to_u8(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m256i, value: __m256i) -> (usize, u8) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m256i, value: __m256i) -> (usize, u8) {
             // 0. Find the maximum value
             let mut vmax: __m256i = value;
             vmax = _mm256_max_epi8(vmax, _mm256_permute2x128_si256(vmax, vmax, 1));
@@ -159,6 +171,13 @@ mod avx2 {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm256_cmpeq_epi8(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm256_xor_si256(index, _mm256_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm256_blendv_epi8(
                 _mm256_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
                 index,                     // if mask is 1, use index
@@ -171,6 +190,11 @@ mod avx2 {
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 4));
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 2));
             imin = _mm256_min_epi8(imin, _mm256_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm256_xor_si256(imin, _mm256_set1_epi8(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm256_extract_epi8(imin, 0) as usize;
 
             (max_index, _i8ord_to_u8(max_value))
@@ -257,7 +281,7 @@ mod sse {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m128i, value: __m128i) -> (usize, u8) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, u8) {
             // 0. Find the minimum value
             let mut vmin: __m128i = value;
             vmin = _mm_min_epi8(vmin, _mm_alignr_epi8(vmin, vmin, 8));
@@ -270,6 +294,13 @@ mod sse {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm_cmpeq_epi8(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm_xor_si128(index, _mm_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm_blendv_epi8(
                 _mm_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
                 index,                  // if mask is 1, use index
@@ -281,13 +312,18 @@ mod sse {
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 4));
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 2));
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm_xor_si128(imin, _mm_set1_epi8(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm_extract_epi8(imin, 0) as usize;
 
             (min_index, _i8ord_to_u8(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m128i, value: __m128i) -> (usize, u8) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m128i, value: __m128i) -> (usize, u8) {
             // 0. Find the maximum value
             let mut vmax: __m128i = value;
             vmax = _mm_max_epi8(vmax, _mm_alignr_epi8(vmax, vmax, 8));
@@ -300,6 +336,13 @@ mod sse {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm_cmpeq_epi8(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm_xor_si128(index, _mm_set1_epi8(-1))
+            } else {
+                index
+            };
             let search_index = _mm_blendv_epi8(
                 _mm_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
                 index,                  // if mask is 1, use index
@@ -311,6 +354,11 @@ mod sse {
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 4));
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 2));
             imin = _mm_min_epi8(imin, _mm_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm_xor_si128(imin, _mm_set1_epi8(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm_extract_epi8(imin, 0) as usize;
 
             (max_index, _i8ord_to_u8(max_value))
@@ -401,7 +449,7 @@ mod avx512 {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: __m512i, value: __m512i) -> (usize, u8) {
+        unsafe fn _horiz_min<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, u8) {
             // 0. Find the minimum value
             let mut vmin: __m512i = value;
             vmin = _mm512_min_epi8(vmin, _mm512_alignr_epi32(vmin, vmin, 8));
@@ -416,6 +464,13 @@ mod avx512 {
             // 1. Create a mask with the index of the minimum value
             let mask = _mm512_cmpeq_epi8_mask(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm512_xor_si512(index, _mm512_set1_epi32(-1))
+            } else {
+                index
+            };
             let search_index = _mm512_mask_blend_epi8(
                 mask,
                 _mm512_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
@@ -429,13 +484,18 @@ mod avx512 {
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 4));
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 2));
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm512_xor_si512(imin, _mm512_set1_epi32(-1))
+            } else {
+                imin
+            };
             let min_index: usize = _mm_extract_epi8(_mm512_castsi512_si128(imin), 0) as usize;
 
             (min_index, _i8ord_to_u8(min_value))
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: __m512i, value: __m512i) -> (usize, u8) {
+        unsafe fn _horiz_max<const LAST: bool>(index: __m512i, value: __m512i) -> (usize, u8) {
             // 0. Find the maximum value
             let mut vmax: __m512i = value;
             vmax = _mm512_max_epi8(vmax, _mm512_alignr_epi32(vmax, vmax, 8));
@@ -450,6 +510,13 @@ mod avx512 {
             // 1. Create a mask with the index of the maximum value
             let mask = _mm512_cmpeq_epi8_mask(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST {
+                _mm512_xor_si512(index, _mm512_set1_epi32(-1))
+            } else {
+                index
+            };
             let search_index = _mm512_mask_blend_epi8(
                 mask,
                 _mm512_set1_epi8(i8::MAX), // if mask is 0, use i8::MAX
@@ -463,6 +530,11 @@ mod avx512 {
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 4));
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 2));
             imin = _mm512_min_epi8(imin, _mm512_alignr_epi8(imin, imin, 1));
+            let imin = if LAST {
+                _mm512_xor_si512(imin, _mm512_set1_epi32(-1))
+            } else {
+                imin
+            };
             let max_index: usize = _mm_extract_epi8(_mm512_castsi512_si128(imin), 0) as usize;
 
             (max_index, _i8ord_to_u8(max_value))
@@ -534,7 +606,10 @@ mod neon {
         }
 
         #[inline(always)]
-        unsafe fn _horiz_min(index: uint8x16_t, value: uint8x16_t) -> (usize, u8) {
+        unsafe fn _horiz_min<const LAST: bool>(
+            index: uint8x16_t,
+            value: uint8x16_t,
+        ) -> (usize, u8) {
             // 0. Find the minimum value
             let mut vmin: uint8x16_t = value;
             vmin = vminq_u8(vmin, vextq_u8(vmin, vmin, 8));
@@ -547,6 +622,9 @@ mod neon {
             // 1. Create a mask with the index of the minimum value
             let mask = vceqq_u8(value, vmin);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST { vmvnq_u8(index) } else { index };
             let search_index = vbslq_u8(
                 mask,
                 index,               // if mask is 1, use index
@@ -558,13 +636,17 @@ mod neon {
             imin = vminq_u8(imin, vextq_u8(imin, imin, 4));
             imin = vminq_u8(imin, vextq_u8(imin, imin, 2));
             imin = vminq_u8(imin, vextq_u8(imin, imin, 1));
+            let imin = if LAST { vmvnq_u8(imin) } else { imin };
             let min_index: usize = vgetq_lane_u8(imin, 0) as usize;
 
             (min_index, min_value)
         }
 
         #[inline(always)]
-        unsafe fn _horiz_max(index: uint8x16_t, value: uint8x16_t) -> (usize, u8) {
+        unsafe fn _horiz_max<const LAST: bool>(
+            index: uint8x16_t,
+            value: uint8x16_t,
+        ) -> (usize, u8) {
             // 0. Find the maximum value
             let mut vmax: uint8x16_t = value;
             vmax = vmaxq_u8(vmax, vextq_u8(vmax, vmax, 8));
@@ -577,6 +659,9 @@ mod neon {
             // 1. Create a mask with the index of the maximum value
             let mask = vceqq_u8(value, vmax);
             // 2. Blend the mask with the index
+            // (when LAST, the indices are bitwise inverted so that the min search
+            // below returns the highest index)
+            let index = if LAST { vmvnq_u8(index) } else { index };
             let search_index = vbslq_u8(
                 mask,
                 index,               // if mask is 1, use index
@@ -588,6 +673,7 @@ mod neon {
             imin = vminq_u8(imin, vextq_u8(imin, imin, 4));
             imin = vminq_u8(imin, vextq_u8(imin, imin, 2));
             imin = vminq_u8(imin, vextq_u8(imin, imin, 1));
+            let imin = if LAST { vmvnq_u8(imin) } else { imin };
             let max_index: usize = vgetq_lane_u8(imin, 0) as usize;
 
             (max_index, max_value)
@@ -631,8 +717,9 @@ mod tests {
     use crate::{Int, SIMDArgMinMax, SCALAR};
 
     use super::super::test_utils::{
-        test_first_index_identical_values_argminmax, test_no_overflow_argminmax,
-        test_return_same_result_argminmax,
+        test_first_index_identical_values_argminmax, test_last_index_identical_values_argminmax,
+        test_no_overflow_argminmax, test_return_same_result_argminmax,
+        test_return_same_result_argminmax_last,
     };
 
     use dev_utils::utils;
@@ -694,6 +781,26 @@ mod tests {
         test_first_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_last_index_is_returned_when_identical_values_found<
+        T,
+        SIMDV,
+        SIMDM,
+        const LANE_SIZE: usize,
+    >(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<u8, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_last_index_identical_values_argminmax(SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_return_same_result<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
@@ -709,6 +816,21 @@ mod tests {
         test_return_same_result_argminmax(get_array_u8, SCALAR_STRATEGY, simd);
     }
 
+    #[apply(simd_implementations)]
+    fn test_return_same_result_last<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
+        #[case] simd: T,
+        #[case] simd_available: bool,
+    ) where
+        T: SIMDArgMinMax<u8, SIMDV, SIMDM, LANE_SIZE, SCALAR<Int>>,
+        SIMDV: Copy,
+        SIMDM: Copy,
+    {
+        if !simd_available {
+            return;
+        }
+        test_return_same_result_argminmax_last(get_array_u8, SCALAR_STRATEGY, simd);
+    }
+
     #[apply(simd_implementations)]
     fn test_no_overflow<T, SIMDV, SIMDM, const LANE_SIZE: usize>(
         #[case] simd: T,
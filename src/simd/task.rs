@@ -5,23 +5,25 @@ pub(crate) fn argminmax_generic<T: Copy + PartialOrd>(
     arr: &[T],
     lane_size: usize,
     core_argminmax: unsafe fn(&[T]) -> (usize, T, usize, T),
-    scalar_argminmax: fn(&[T]) -> (usize, usize),
+    scalar_argminmax: fn(&[T]) -> (usize, T, usize, T),
     nan_check: fn(T) -> bool, // returns true if value is NaN
     ignore_nan: bool,         // if false, NaNs will be returned
-) -> (usize, usize) {
+    last: bool,               // if true, the last index is returned on ties
+) -> (usize, T, usize, T) {
     assert!(!arr.is_empty()); // split_array should never return (None, None)
     match split_array(arr, lane_size) {
         (Some(simd_arr), Some(rem)) => {
             // Perform SIMD operation on the first part of the array
             let simd_result = unsafe { core_argminmax(simd_arr) };
             // Perform scalar operation on the remainder of the array
-            let (rem_min_index, rem_max_index) = scalar_argminmax(rem);
+            let (rem_min_index, rem_min_value, rem_max_index, rem_max_value) =
+                scalar_argminmax(rem);
             // let (rem_min_index, rem_max_index) = SCALAR::argminmax(rem);
             let rem_result = (
                 rem_min_index + simd_arr.len(),
-                rem[rem_min_index],
+                rem_min_value,
                 rem_max_index + simd_arr.len(),
-                rem[rem_max_index],
+                rem_max_value,
             );
             // Find the final min and max values
             let (min_index, min_value) = find_final_index_min(
@@ -29,27 +31,28 @@ pub(crate) fn argminmax_generic<T: Copy + PartialOrd>(
                 (rem_result.0, rem_result.1),
                 nan_check,
                 ignore_nan,
+                last,
             );
             let (max_index, max_value) = find_final_index_max(
                 (simd_result.2, simd_result.3),
                 (rem_result.2, rem_result.3),
                 nan_check,
                 ignore_nan,
+                last,
             );
-            get_correct_argminmax_result(
+            let result = get_correct_argminmax_result(
                 min_index, min_value, max_index, max_value, nan_check, ignore_nan,
-            )
+            );
+            get_first_nan_index(arr, result, nan_check, ignore_nan, last)
         }
         (Some(simd_arr), None) => {
             let (min_index, min_value, max_index, max_value) = unsafe { core_argminmax(simd_arr) };
-            get_correct_argminmax_result(
+            let result = get_correct_argminmax_result(
                 min_index, min_value, max_index, max_value, nan_check, ignore_nan,
-            )
-        }
-        (None, Some(rem)) => {
-            let (rem_min_index, rem_max_index) = scalar_argminmax(rem);
-            (rem_min_index, rem_max_index)
+            );
+            get_first_nan_index(arr, result, nan_check, ignore_nan, last)
         }
+        (None, Some(rem)) => scalar_argminmax(rem),
         (None, None) => panic!("Array is empty"), // Should never occur because of assert
     }
 }
@@ -62,6 +65,7 @@ pub(crate) fn argmin_generic<T: Copy + PartialOrd>(
     scalar_argmin: fn(&[T]) -> usize,
     nan_check: fn(T) -> bool, // returns true if value is NaN
     ignore_nan: bool,         // if false, NaNs will be returned
+    last: bool,               // if true, the last index is returned on ties
 ) -> usize {
     assert!(!arr.is_empty()); // split_array should never return (None, None)
     match split_array(arr, lane_size) {
@@ -72,13 +76,15 @@ pub(crate) fn argmin_generic<T: Copy + PartialOrd>(
             let rem_min_index = scalar_argmin(rem);
             let rem_result = (rem_min_index + simd_arr.len(), rem[rem_min_index]);
             // Find the final min value
-            let (min_index, _) =
-                find_final_index_min(simd_result, rem_result, nan_check, ignore_nan);
-            min_index
+            let (min_index, min_value) =
+                find_final_index_min(simd_result, rem_result, nan_check, ignore_nan, last);
+            let result = (min_index, min_value, min_index, min_value);
+            get_first_nan_index(arr, result, nan_check, ignore_nan, last).0
         }
         (Some(simd_arr), None) => {
-            let (min_index, _) = unsafe { core_argmin(simd_arr) };
-            min_index
+            let (min_index, min_value) = unsafe { core_argmin(simd_arr) };
+            let result = (min_index, min_value, min_index, min_value);
+            get_first_nan_index(arr, result, nan_check, ignore_nan, last).0
         }
         (None, Some(rem)) => scalar_argmin(rem),
         (None, None) => panic!("Array is empty"), // Should never occur because of assert
@@ -93,6 +99,7 @@ pub(crate) fn argmax_generic<T: Copy + PartialOrd>(
     scalar_argmax: fn(&[T]) -> usize,
     nan_check: fn(T) -> bool, // returns true if value is NaN
     ignore_nan: bool,         // if false, NaNs will be returned
+    last: bool,               // if true, the last index is returned on ties
 ) -> usize {
     assert!(!arr.is_empty()); // split_array should never return (None, None)
     match split_array(arr, lane_size) {
@@ -103,13 +110,15 @@ pub(crate) fn argmax_generic<T: Copy + PartialOrd>(
             let rem_max_index = scalar_argmax(rem);
             let rem_result = (rem_max_index + simd_arr.len(), rem[rem_max_index]);
             // Find the final max value
-            let (max_index, _) =
-                find_final_index_max(simd_result, rem_result, nan_check, ignore_nan);
-            max_index
+            let (max_index, max_value) =
+                find_final_index_max(simd_result, rem_result, nan_check, ignore_nan, last);
+            let result = (max_index, max_value, max_index, max_value);
+            get_first_nan_index(arr, result, nan_check, ignore_nan, last).0
         }
         (Some(simd_arr), None) => {
-            let (max_index, _) = unsafe { core_argmax(simd_arr) };
-            max_index
+            let (max_index, max_value) = unsafe { core_argmax(simd_arr) };
+            let result = (max_index, max_value, max_index, max_value);
+            get_first_nan_index(arr, result, nan_check, ignore_nan, last).0
         }
         (None, Some(rem)) => scalar_argmax(rem),
         (None, None) => panic!("Array is empty"), // Should never occur because of assert
@@ -135,6 +144,18 @@ fn split_array<T: Copy>(arr: &[T], lane_size: usize) -> (Option<&[T]>, Option<&[
     }
 }
 
+/// Compare two values, returning None if either of them is NaN (according to the
+/// nan_check). This does not rely on the PartialOrd implementation of the data type to
+/// detect NaNs, as not all data types detect all NaN encodings (e.g., FP8 E5M2).
+#[inline(always)]
+fn partial_cmp_nan<T: Copy + PartialOrd>(a: T, b: T, nan_check: fn(T) -> bool) -> Option<Ordering> {
+    if nan_check(a) || nan_check(b) {
+        None
+    } else {
+        a.partial_cmp(&b)
+    }
+}
+
 /// Get the final index of the min value when both a SIMD and scalar result is available
 /// If not ignoring NaNs (thus returning NaN index if any present):
 /// - If both values are NaN, returns the index of the simd result (as the first part
@@ -144,16 +165,20 @@ fn split_array<T: Copy>(arr: &[T], lane_size: usize) -> (Option<&[T]>, Option<&[
 /// If ignoring NaNs: returns the index of the min value
 ///
 /// Note: when the values are equal, the index of the simd result is returned (as the
-/// first part of the array is passed to the SIMD function)
+/// first part of the array is passed to the SIMD function) - unless `last` is true, in
+/// which case the index of the remainder result is returned.
 #[inline(always)]
 fn find_final_index_min<T: Copy + PartialOrd>(
     simd_result: (usize, T),
     remainder_result: (usize, T),
     nan_check: fn(T) -> bool,
     ignore_nan: bool,
+    last: bool,
 ) -> (usize, T) {
-    let (min_index, min_value) = match simd_result.1.partial_cmp(&remainder_result.1) {
+    let (min_index, min_value) = match partial_cmp_nan(simd_result.1, remainder_result.1, nan_check)
+    {
         Some(Ordering::Less) => simd_result,
+        Some(Ordering::Equal) if last => remainder_result,
         Some(Ordering::Equal) => simd_result,
         Some(Ordering::Greater) => remainder_result,
         None => {
@@ -190,16 +215,20 @@ fn find_final_index_min<T: Copy + PartialOrd>(
 /// If ignoring NaNs: returns the index of the max value
 ///
 /// Note: when the values are equal, the index of the simd result is returned (as the
-/// first part of the array is passed to the SIMD function)
+/// first part of the array is passed to the SIMD function) - unless `last` is true, in
+/// which case the index of the remainder result is returned.
 #[inline(always)]
 fn find_final_index_max<T: Copy + PartialOrd>(
     simd_result: (usize, T),
     remainder_result: (usize, T),
     nan_check: fn(T) -> bool,
     ignore_nan: bool,
+    last: bool,
 ) -> (usize, T) {
-    let (max_index, max_value) = match simd_result.1.partial_cmp(&remainder_result.1) {
+    let (max_index, max_value) = match partial_cmp_nan(simd_result.1, remainder_result.1, nan_check)
+    {
         Some(Ordering::Greater) => simd_result,
+        Some(Ordering::Equal) if last => remainder_result,
         Some(Ordering::Equal) => simd_result,
         Some(Ordering::Less) => remainder_result,
         None => {
@@ -227,12 +256,12 @@ fn find_final_index_max<T: Copy + PartialOrd>(
     (max_index, max_value)
 }
 
-/// Get the correct index(es) for the argmin and argmax functions
+/// Get the correct index(es) and value(s) for the argmin and argmax functions
 /// If not ignoring NaNs (thus returning NaN index if any present):
-/// - If both values are NaN, returns the lowest index twice
-/// - If one value is NaN, returns the index of the non-NaN value twice
-/// - If neither value is NaN, returns the min_index and max_index
-/// If ignoring NaNs: returns the min_index and max_index
+/// - If both values are NaN, returns the lowest index (and its value) twice
+/// - If one value is NaN, returns the index (and value) of the NaN value twice
+/// - If neither value is NaN, returns the min_index and max_index (and their values)
+/// If ignoring NaNs: returns the min_index and max_index (and their values)
 fn get_correct_argminmax_result<T: Copy + PartialOrd>(
     min_index: usize,
     min_value: T,
@@ -240,29 +269,61 @@ fn get_correct_argminmax_result<T: Copy + PartialOrd>(
     max_value: T,
     nan_check: fn(T) -> bool,
     ignore_nan: bool,
-) -> (usize, usize) {
+) -> (usize, T, usize, T) {
     if !ignore_nan && (nan_check(min_value) || nan_check(max_value)) {
         // --- Return NaNs
         // -> at least one of the values is NaN
         if nan_check(min_value) && nan_check(max_value) {
             // If both are NaN, return lowest index
-            let lowest_index = std::cmp::min(min_index, max_index);
-            return (lowest_index, lowest_index);
+            if min_index <= max_index {
+                return (min_index, min_value, min_index, min_value);
+            }
+            return (max_index, max_value, max_index, max_value);
         } else if nan_check(min_value) {
             // If min is the only NaN, return min index
-            return (min_index, min_index);
+            return (min_index, min_value, min_index, min_value);
         } else {
             // If max is the only NaN, return max index
-            return (max_index, max_index);
+            return (max_index, max_value, max_index, max_value);
         }
     }
-    (min_index, max_index)
+    (min_index, min_value, max_index, max_value)
+}
+
+/// Get the index (and value) of the first NaN when the index(es) point to a NaN value
+/// This is only necessary when not ignoring NaNs and returning the last index on ties;
+/// as the SIMD cores then keep the last index on ties (and thus may find a NaN that is
+/// not the first one).
+#[inline(always)]
+fn get_first_nan_index<T: Copy + PartialOrd>(
+    arr: &[T],
+    result: (usize, T, usize, T),
+    nan_check: fn(T) -> bool,
+    ignore_nan: bool,
+    last: bool,
+) -> (usize, T, usize, T) {
+    if !last || ignore_nan || !nan_check(result.1) {
+        // Note: when the min value is not NaN, the max value is also not NaN (see
+        // get_correct_argminmax_result)
+        return result;
+    }
+    let (nan_index, nan_value) = arr
+        .iter()
+        .enumerate()
+        .find(|(_, v)| nan_check(**v))
+        .map(|(i, v)| (i, *v))
+        .unwrap();
+    (nan_index, nan_value, nan_index, nan_value)
 }
 
 // ------------ Other helper functions
 
 // #[inline(always)]
-pub(crate) fn min_index_value<T: Copy + PartialOrd>(index: &[T], values: &[T]) -> (T, T) {
+/// When `LAST` is true, the highest index is returned on ties (instead of the lowest).
+pub(crate) fn min_index_value<T: Copy + PartialOrd, const LAST: bool>(
+    index: &[T],
+    values: &[T],
+) -> (T, T) {
     assert!(!index.is_empty());
     assert_eq!(index.len(), values.len());
     let mut min_index: T = unsafe { *index.get_unchecked(0) };
@@ -270,7 +331,14 @@ pub(crate) fn min_index_value<T: Copy + PartialOrd>(index: &[T], values: &[T]) -
     for i in 0..values.len() {
         let v: T = unsafe { *values.get_unchecked(i) };
         let idx: T = unsafe { *index.get_unchecked(i) };
-        if v < min_value || (v == min_value && idx < min_index) {
+        if v < min_value
+            || (v == min_value
+                && if LAST {
+                    idx > min_index
+                } else {
+                    idx < min_index
+                })
+        {
             min_value = v;
             min_index = idx;
         }
@@ -279,7 +347,11 @@ pub(crate) fn min_index_value<T: Copy + PartialOrd>(index: &[T], values: &[T]) -
 }
 
 // #[inline(always)]
-pub(crate) fn max_index_value<T: Copy + PartialOrd>(index: &[T], values: &[T]) -> (T, T) {
+/// When `LAST` is true, the highest index is returned on ties (instead of the lowest).
+pub(crate) fn max_index_value<T: Copy + PartialOrd, const LAST: bool>(
+    index: &[T],
+    values: &[T],
+) -> (T, T) {
     assert!(!index.is_empty());
     assert_eq!(index.len(), values.len());
     let mut max_index: T = unsafe { *index.get_unchecked(0) };
@@ -287,7 +359,14 @@ pub(crate) fn max_index_value<T: Copy + PartialOrd>(index: &[T], values: &[T]) -
     for i in 0..values.len() {
         let v: T = unsafe { *values.get_unchecked(i) };
         let idx: T = unsafe { *index.get_unchecked(i) };
-        if v > max_value || (v == max_value && idx < max_index) {
+        if v > max_value
+            || (v == max_value
+                && if LAST {
+                    idx > max_index
+                } else {
+                    idx < max_index
+                })
+        {
             max_value = v;
             max_index = idx;
         }
@@ -1,4 +1,4 @@
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 use num_traits::float::FloatCore;
 use num_traits::AsPrimitive;
 use num_traits::{Bounded, One};
@@ -150,6 +150,134 @@ pub(crate) fn test_first_index_identical_values_argminmax<
     assert_eq!(argmax_simd_index_single, 7);
 }
 
+/// Test if the last index is returned when the MIN/MAX value occurs multiple times
+/// (and the last index is requested).
+#[cfg(test)]
+pub(crate) fn test_last_index_identical_values_argminmax<
+    DType,
+    SCALAR,
+    SIMD,
+    SV,
+    SM,
+    const LANE_SIZE: usize,
+>(
+    _scalar: SCALAR, // necessary to use SCALAR
+    _simd: SIMD,     // necessary to use SIMD
+) where
+    DType: Copy + PartialOrd + AsPrimitive<usize> + One + Bounded,
+    SV: Copy, // SIMD vector type
+    SM: Copy, // SIMD mask type
+    SCALAR: ScalarArgMinMax<DType>,
+    SIMD: SIMDArgMinMax<DType, SV, SM, LANE_SIZE, SCALAR>,
+{
+    let mut data: [DType; 64] = [DType::one(); 64]; // multiple of lane size
+
+    // Case 1: all elements are identical
+    let (argmin_index, argmax_index) = SCALAR::argminmax_last(&data);
+    let argmin_index_single = SCALAR::argmin_last(&data);
+    let argmax_index_single = SCALAR::argmax_last(&data);
+    assert_eq!(argmin_index, 63);
+    assert_eq!(argmin_index_single, 63);
+    assert_eq!(argmax_index, 63);
+    assert_eq!(argmax_index_single, 63);
+
+    let (argmin_simd_index, argmax_simd_index) = unsafe { SIMD::argminmax_last(&data) };
+    let argmin_simd_index_single = unsafe { SIMD::argmin_last(&data) };
+    let argmax_simd_index_single = unsafe { SIMD::argmax_last(&data) };
+    assert_eq!(argmin_simd_index, 63);
+    assert_eq!(argmin_simd_index_single, 63);
+    assert_eq!(argmax_simd_index, 63);
+    assert_eq!(argmax_simd_index_single, 63);
+
+    // Case 2: all elements are identical except for a couple of MIN/MAX values
+    // Add multiple MIN values to the array
+    data[5] = DType::min_value();
+    data[13] = DType::min_value();
+    data[41] = DType::min_value();
+
+    // Add multiple MAX values to the array
+    data[7] = DType::max_value();
+    data[17] = DType::max_value();
+    data[31] = DType::max_value();
+
+    let (argmin_index, argmax_index) = SCALAR::argminmax_last(&data);
+    let argmin_index_single = SCALAR::argmin_last(&data);
+    let argmax_index_single = SCALAR::argmax_last(&data);
+    assert_eq!(argmin_index, 41);
+    assert_eq!(argmin_index_single, 41);
+    assert_eq!(argmax_index, 31);
+    assert_eq!(argmax_index_single, 31);
+
+    let (argmin_simd_index, argmax_simd_index) = unsafe { SIMD::argminmax_last(&data) };
+    let argmin_simd_index_single = unsafe { SIMD::argmin_last(&data) };
+    let argmax_simd_index_single = unsafe { SIMD::argmax_last(&data) };
+    assert_eq!(argmin_simd_index, 41);
+    assert_eq!(argmin_simd_index_single, 41);
+    assert_eq!(argmax_simd_index, 31);
+    assert_eq!(argmax_simd_index_single, 31);
+
+    // Case 3: the array does not fully fit in the SIMD registers
+    let data: [DType; 67] = [DType::one(); 67];
+    let argminmax_simd_index = unsafe { SIMD::argminmax_last(&data) };
+    assert_eq!(argminmax_simd_index, (66, 66));
+    assert_eq!(unsafe { SIMD::argmin_last(&data) }, 66);
+    assert_eq!(unsafe { SIMD::argmax_last(&data) }, 66);
+}
+
+/// Tests whether the scalar and SIMD function return the same result when the last index
+/// is requested on ties.
+/// - tests for a long array with many identical values whether the scalar and SIMD
+///   function return the same result.
+/// - tests for many arrays with many identical values whether the scalar and SIMD
+///   function return the same result.
+#[cfg(test)]
+pub(crate) fn test_return_same_result_argminmax_last<
+    DType,
+    SCALAR,
+    SIMD,
+    SV,
+    SM,
+    const LANE_SIZE: usize,
+>(
+    get_data: fn(usize) -> Vec<DType>,
+    _scalar: SCALAR, // necessary to use SCALAR
+    _simd: SIMD,     // necessary to use SIMD
+) where
+    DType: Copy + PartialOrd + AsPrimitive<usize>,
+    SV: Copy, // SIMD vector type
+    SM: Copy, // SIMD mask type
+    SCALAR: ScalarArgMinMax<DType>,
+    SIMD: SIMDArgMinMax<DType, SV, SM, LANE_SIZE, SCALAR>,
+{
+    // Create an array that only contains a few (random) distinct values
+    let get_data_with_ties = |n: usize| -> Vec<DType> {
+        let values = get_data(5);
+        (0..n).map(|i| values[(i * i + i / 3) % 5]).collect()
+    };
+
+    for (arr_len, nb_runs) in [(LONG_ARR_LEN, 10), (RANDOM_RUN_ARR_LEN, NB_RUNS / 10)] {
+        for _ in 0..nb_runs {
+            let data: &[DType] = &get_data_with_ties(arr_len);
+            // argminmax
+            let (argmin_index, argmax_index) = SCALAR::argminmax_last(data);
+            let (argmin_simd_index, argmax_simd_index) = unsafe { SIMD::argminmax_last(data) };
+            // argmin
+            let argmin_index_single = SCALAR::argmin_last(data);
+            let argmin_simd_index_single = unsafe { SIMD::argmin_last(data) };
+            // argmax
+            let argmax_index_single = SCALAR::argmax_last(data);
+            let argmax_simd_index_single = unsafe { SIMD::argmax_last(data) };
+
+            assert_eq!(argmin_index, argmin_simd_index);
+            assert_eq!(argmin_index, argmin_index_single);
+            assert_eq!(argmin_index, argmin_simd_index_single);
+            assert_eq!(argmax_index, argmax_simd_index);
+            assert_eq!(argmax_index, argmax_index_single);
+            assert_eq!(argmax_index, argmax_simd_index_single);
+        }
+    }
+}
+
 // ------- Overflow test
 
 /// Test wheter no overflow occurs when the array is too long.
@@ -201,13 +329,13 @@ pub(crate) fn test_no_overflow_argminmax<DType, SCALAR, SIMD, SV, SM, const LANE
 
 // ------- Float tests for argminmax
 
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[cfg(test)]
 const FLOAT_ARR_LEN: usize = 1024 + 3;
 
 /// Test whether infinities are handled correctly.
 /// -> infinities should be returned as the argmin/argmax
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[cfg(test)]
 pub(crate) fn test_return_infs_argminmax<DType, SCALAR, SIMD, SV, SM, const LANE_SIZE: usize>(
     get_data: fn(usize) -> Vec<DType>,
@@ -286,7 +414,7 @@ pub(crate) fn test_return_infs_argminmax<DType, SCALAR, SIMD, SV, SM, const LANE
 }
 
 /// Test whether NaNs are handled correctly - in this case, they should be ignored.
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[cfg(test)]
 pub(crate) fn test_ignore_nans_argminmax<DType, SCALAR, SIMD, SV, SM, const LANE_SIZE: usize>(
     get_data: fn(usize) -> Vec<DType>,
@@ -355,22 +483,28 @@ pub(crate) fn test_ignore_nans_argminmax<DType, SCALAR, SIMD, SV, SM, const LANE
     for i in 1..data.len() {
         data[i] = DType::from(i as f64).unwrap();
     }
+    // The first index of the max value (which is FLOAT_ARR_LEN - 1, unless the largest
+    // values cannot be represented exactly - e.g., for bf16)
+    let max_index = data
+        .iter()
+        .position(|&v| v == data[FLOAT_ARR_LEN - 1])
+        .unwrap();
 
     let (argmin_index, argmax_index) = SCALAR::argminmax(&data);
     let argmin_index_single = SCALAR::argmin(&data);
     let argmax_index_single = SCALAR::argmax(&data);
     assert_eq!(argmin_index, 1);
     assert_eq!(argmin_index_single, 1);
-    assert_eq!(argmax_index, FLOAT_ARR_LEN - 1);
-    assert_eq!(argmax_index_single, FLOAT_ARR_LEN - 1);
+    assert_eq!(argmax_index, max_index);
+    assert_eq!(argmax_index_single, max_index);
 
     let (argmin_simd_index, argmax_simd_index) = unsafe { SIMD::argminmax(&data) };
     let argmin_simd_index_single = unsafe { SIMD::argmin(&data) };
     let argmax_simd_index_single = unsafe { SIMD::argmax(&data) };
     assert_eq!(argmin_simd_index, 1);
     assert_eq!(argmin_simd_index_single, 1);
-    assert_eq!(argmax_simd_index, FLOAT_ARR_LEN - 1);
-    assert_eq!(argmax_simd_index_single, FLOAT_ARR_LEN - 1);
+    assert_eq!(argmax_simd_index, max_index);
+    assert_eq!(argmax_simd_index_single, max_index);
 
     // Case 1.3 - NaN is the first element, other values are monotonic decreasing
     let mut data: Vec<DType> = get_data(FLOAT_ARR_LEN);
@@ -529,7 +663,7 @@ pub(crate) fn test_ignore_nans_argminmax<DType, SCALAR, SIMD, SV, SM, const LANE
 
 /// Test whether NaNs are handled correctly - in this case, the index of the first NaN
 /// should be returned.
-#[cfg(any(feature = "float", feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[cfg(test)]
 pub(crate) fn test_return_nans_argminmax<DType, SCALAR, SIMD, SV, SM, const LANE_SIZE: usize>(
     get_data: fn(usize) -> Vec<DType>,
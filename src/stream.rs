@@ -0,0 +1,164 @@
+use crate::{
+    AccumulatorStrategy, ArgMinMaxAccumulator, ArgMinMaxReadError, FromLeBytes, MinMaxResult,
+};
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+/// Extension trait for finding the minimum and maximum values of an (async) stream of
+/// chunks (using [`futures`](https://docs.rs/futures)).
+///
+/// This trait is implemented for all streams (when the `futures` feature is enabled).
+/// Each chunk is processed by the (SIMD) implementation of the `Strategy` (see
+/// [`AccumulatorStrategy`]) as soon as it arrives, the returned future resolves when
+/// the stream is exhausted. The result is the same as that of a single `argminmax` (or
+/// `nanargminmax` for [`FloatReturnNaN`](crate::dtype_strategy::FloatReturnNaN)) call on
+/// the concatenated chunks.
+///
+/// Streams that are not [`Unpin`] have to be pinned first (e.g., with `Box::pin`).
+///
+pub trait ArgMinMaxStreamExt: Stream + Sized {
+    /// Get the index and value of the minimum and maximum values of a stream of chunks
+    /// of values (e.g., `Vec<T>`).
+    ///
+    /// # Returns
+    /// A future that resolves to a [`MinMaxResult`] with the indices in the
+    /// concatenated chunks, or `None` when the stream contains no values.
+    ///
+    fn argminmax_stream<T, Strategy>(self) -> ArgMinMaxStream<Self, T, Strategy>
+    where
+        Self: Unpin,
+        Self::Item: AsRef<[T]>,
+        T: Copy,
+        Strategy: AccumulatorStrategy<T>;
+
+    /// Get the index and value of the minimum and maximum values of a stream of chunks
+    /// of raw little-endian bytes (e.g., `bytes::Bytes` or `Vec<u8>`).
+    ///
+    /// A value may be split over consecutive chunks.
+    ///
+    /// # Returns
+    /// A future that resolves to a [`MinMaxResult`] with the indices of the values in
+    /// the stream.
+    ///
+    /// # Errors
+    /// - [`ArgMinMaxReadError::TrailingBytes`] when the stream ends with a partial value.
+    /// - [`ArgMinMaxReadError::EmptyInput`] when the stream contains no values.
+    ///
+    fn argminmax_bytes_stream<T, Strategy>(self) -> ArgMinMaxBytesStream<Self, T, Strategy>
+    where
+        Self: Unpin,
+        Self::Item: AsRef<[u8]>,
+        T: Copy + FromLeBytes,
+        Strategy: AccumulatorStrategy<T>;
+}
+
+/// Future for the [`argminmax_stream`](ArgMinMaxStreamExt::argminmax_stream) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ArgMinMaxStream<S, T, Strategy> {
+    stream: S,
+    acc: ArgMinMaxAccumulator<T, Strategy>,
+}
+
+impl<S, T, Strategy> ArgMinMaxStream<S, T, Strategy>
+where
+    T: Copy,
+    Strategy: AccumulatorStrategy<T>,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        ArgMinMaxStream {
+            stream,
+            acc: ArgMinMaxAccumulator::new(),
+        }
+    }
+}
+
+// The stream is the only field that is polled (and it is Unpin)
+impl<S: Unpin, T, Strategy> Unpin for ArgMinMaxStream<S, T, Strategy> {}
+
+impl<S, T, Strategy> Future for ArgMinMaxStream<S, T, Strategy>
+where
+    S: Stream + Unpin,
+    S::Item: AsRef<[T]>,
+    T: Copy,
+    Strategy: AccumulatorStrategy<T>,
+{
+    type Output = Option<MinMaxResult<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        while let Some(chunk) = ready!(Pin::new(&mut this.stream).poll_next(cx)) {
+            this.acc.update(chunk.as_ref());
+        }
+        Poll::Ready(this.acc.result())
+    }
+}
+
+/// Future for the [`argminmax_bytes_stream`](ArgMinMaxStreamExt::argminmax_bytes_stream)
+/// method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ArgMinMaxBytesStream<S, T, Strategy> {
+    stream: S,
+    acc: ArgMinMaxAccumulator<T, Strategy>,
+    /// The bytes of a value that is split over consecutive chunks
+    partial: Vec<u8>,
+    /// The (aligned) buffer with the decoded values of a chunk
+    values: Vec<T>,
+}
+
+impl<S, T, Strategy> ArgMinMaxBytesStream<S, T, Strategy>
+where
+    T: Copy,
+    Strategy: AccumulatorStrategy<T>,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        ArgMinMaxBytesStream {
+            stream,
+            acc: ArgMinMaxAccumulator::new(),
+            partial: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+// The stream is the only field that is polled (and it is Unpin)
+impl<S: Unpin, T, Strategy> Unpin for ArgMinMaxBytesStream<S, T, Strategy> {}
+
+impl<S, T, Strategy> Future for ArgMinMaxBytesStream<S, T, Strategy>
+where
+    S: Stream + Unpin,
+    S::Item: AsRef<[u8]>,
+    T: Copy + FromLeBytes,
+    Strategy: AccumulatorStrategy<T>,
+{
+    type Output = Result<MinMaxResult<T>, ArgMinMaxReadError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        while let Some(chunk) = ready!(Pin::new(&mut this.stream).poll_next(cx)) {
+            let mut bytes = chunk.as_ref();
+            this.values.clear();
+            // Complete the value that was split over the previous chunk(s)
+            if !this.partial.is_empty() {
+                let n = std::cmp::min(T::NB_BYTES - this.partial.len(), bytes.len());
+                this.partial.extend_from_slice(&bytes[..n]);
+                bytes = &bytes[n..];
+                if this.partial.len() < T::NB_BYTES {
+                    continue;
+                }
+                this.values.push(T::from_le_slice(&this.partial));
+                this.partial.clear();
+            }
+            let len = bytes.len() - bytes.len() % T::NB_BYTES;
+            let decoded = bytes[..len].chunks_exact(T::NB_BYTES);
+            this.values.extend(decoded.map(T::from_le_slice));
+            this.partial.extend_from_slice(&bytes[len..]);
+            this.acc.update(&this.values);
+        }
+        Poll::Ready(match this.partial.len() {
+            0 => this.acc.result().ok_or(ArgMinMaxReadError::EmptyInput),
+            n => Err(ArgMinMaxReadError::TrailingBytes(n)),
+        })
+    }
+}
@@ -0,0 +1,28 @@
+/// The index that is returned when the minimum / maximum value occurs multiple times.
+///
+/// This enum is passed to the `*_with_tie` methods of the
+/// [`ArgMinMax`](crate::ArgMinMax) and [`NaNArgMinMax`](crate::NaNArgMinMax) traits.
+///
+/// Note that this only affects ties between (non-NaN) values; when NaNs are returned
+/// (see [`NaNArgMinMax`](crate::NaNArgMinMax)), the index of the first NaN is returned.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TiePolicy {
+    /// Return the index of the first occurrence (default)
+    #[default]
+    First,
+    /// Return the index of the last occurrence
+    Last,
+}
+
+#[cfg_attr(not(feature = "ndarray"), allow(dead_code))]
+impl TiePolicy {
+    /// The policy that selects the same index when the array is traversed in reverse
+    #[inline(always)]
+    pub(crate) fn reverse(self) -> Self {
+        match self {
+            TiePolicy::First => TiePolicy::Last,
+            TiePolicy::Last => TiePolicy::First,
+        }
+    }
+}
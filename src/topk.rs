@@ -0,0 +1,176 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// The number of elements in a block - the (SIMD) argmin / argmax of each block is
+/// used to check whether the block can contain a top-k candidate.
+const BLOCK_SIZE: usize = 1024;
+
+/// A top-k candidate (value and its index in the array).
+///
+/// The ordering of the candidates is defined as "how good the candidate is", i.e.,
+/// `a > b` if `a` should be ranked before `b`:
+/// - NaNs are ranked before all other values (this is only relevant when NaNs are
+///   not ignored, as otherwise NaNs never become a candidate)
+/// - for argmax_k (`MAX = true`) larger values are ranked first, for argmin_k
+///   (`MAX = false`) smaller values are ranked first
+/// - when the values are equal, the lowest index is ranked first
+///
+struct Candidate<T, const MAX: bool> {
+    value: T,
+    index: usize,
+}
+
+impl<T: PartialOrd, const MAX: bool> Ord for Candidate<T, MAX> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let value_ordering = match self.value.partial_cmp(&other.value) {
+            Some(ordering) if MAX => ordering,
+            Some(ordering) => ordering.reverse(),
+            // At least one of the values is NaN -> NaN is ranked first
+            None => match (_is_nan(&self.value), _is_nan(&other.value)) {
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                _ => Ordering::Equal,
+            },
+        };
+        // When the values are equal, the lowest index is ranked first
+        value_ordering.then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl<T: PartialOrd, const MAX: bool> PartialOrd for Candidate<T, MAX> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd, const MAX: bool> PartialEq for Candidate<T, MAX> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd, const MAX: bool> Eq for Candidate<T, MAX> {}
+
+/// Returns true if the value is NaN (i.e., is not comparable to itself)
+#[inline(always)]
+fn _is_nan<T: PartialOrd>(value: &T) -> bool {
+    value.partial_cmp(value).is_none()
+}
+
+/// Get the best non-NaN value (and its index) of the block through a scalar pass -
+/// returns None when the block contains only NaNs.
+#[inline(always)]
+fn _block_best_non_nan<T: Copy + PartialOrd, const MAX: bool>(
+    block: &[T],
+    start: usize,
+) -> Option<Candidate<T, MAX>> {
+    block
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| !_is_nan(*value))
+        .map(|(i, &value)| Candidate::<T, MAX> {
+            value,
+            index: start + i,
+        })
+        .max()
+}
+
+/// Get the indices of the k best values in the array.
+///
+/// The array is processed in blocks of `BLOCK_SIZE` elements. Once k candidates are
+/// found, the (SIMD) `block_arg_best` function is used to check whether a block
+/// contains a value that is better than the worst candidate - only then the block is
+/// scanned element-wise.
+///
+/// The returned indices are sorted from best to worst (see `Candidate` for the
+/// ranking). When `ignore_nan` is true, NaNs are never returned (and thus less than
+/// k indices are returned when the array contains less than k non-NaN values).
+///
+#[inline(always)]
+fn topk_generic<T: Copy + PartialOrd, const MAX: bool>(
+    arr: &[T],
+    k: usize,
+    block_arg_best: fn(&[T]) -> usize, // (SIMD) argmax (if MAX) or argmin of a block
+    ignore_nan: bool,                  // if false, NaNs are ranked first
+) -> Vec<usize> {
+    if k == 0 || arr.is_empty() {
+        return Vec::new();
+    }
+    // Min-heap (through Reverse) containing the k best candidates so far
+    // -> the top of the heap is the worst candidate
+    let mut heap: BinaryHeap<Reverse<Candidate<T, MAX>>> =
+        BinaryHeap::with_capacity(std::cmp::min(k, arr.len()) + 1);
+
+    for (block_idx, block) in arr.chunks(BLOCK_SIZE).enumerate() {
+        let start = block_idx * BLOCK_SIZE;
+        if heap.len() == k {
+            // Check if the block can contain a better candidate than the worst one
+            let best_index = block_arg_best(block);
+            let best = if !_is_nan(&block[best_index]) {
+                Some(Candidate::<T, MAX> {
+                    value: block[best_index],
+                    index: start + best_index,
+                })
+            } else if ignore_nan {
+                // The (SIMD) block its best is not reliable when the block contains
+                // only NaNs and / or infinities -> use a scalar bound instead
+                _block_best_non_nan::<T, MAX>(block, start)
+            } else {
+                // NaNs are ranked first -> the block contains a NaN candidate
+                None
+            };
+            let worst = &heap.peek().unwrap().0;
+            let can_skip = match best {
+                Some(best) => best < *worst,
+                None => ignore_nan, // a block with only NaNs contains no candidates
+            };
+            if can_skip {
+                // The block its best value (and index) is worse than the worst candidate
+                continue;
+            }
+        }
+        for (i, &value) in block.iter().enumerate() {
+            if ignore_nan && _is_nan(&value) {
+                continue;
+            }
+            let candidate = Candidate::<T, MAX> {
+                value,
+                index: start + i,
+            };
+            if heap.len() < k {
+                heap.push(Reverse(candidate));
+            } else if candidate > heap.peek().unwrap().0 {
+                heap.pop();
+                heap.push(Reverse(candidate));
+            }
+        }
+    }
+
+    // Sorted ascending on Reverse -> sorted from best to worst candidate
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(candidate)| candidate.index)
+        .collect()
+}
+
+/// Get the indices of the k largest values in the array (largest first).
+#[inline(always)]
+pub(crate) fn argmax_k_generic<T: Copy + PartialOrd>(
+    arr: &[T],
+    k: usize,
+    block_argmax: fn(&[T]) -> usize,
+    ignore_nan: bool,
+) -> Vec<usize> {
+    topk_generic::<T, true>(arr, k, block_argmax, ignore_nan)
+}
+
+/// Get the indices of the k smallest values in the array (smallest first).
+#[inline(always)]
+pub(crate) fn argmin_k_generic<T: Copy + PartialOrd>(
+    arr: &[T],
+    k: usize,
+    block_argmin: fn(&[T]) -> usize,
+    ignore_nan: bool,
+) -> Vec<usize> {
+    topk_generic::<T, false>(arr, k, block_argmin, ignore_nan)
+}
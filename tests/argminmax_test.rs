@@ -1,11 +1,40 @@
-use argminmax::ArgMinMax;
-#[cfg(any(feature = "float", feature = "half"))]
-use argminmax::NaNArgMinMax;
-
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+use argminmax::dtype_strategy::{FloatIgnoreNaN, FloatReturnNaN};
+#[cfg(feature = "futures")]
+use argminmax::ArgMinMaxStreamExt;
+#[cfg(all(
+    feature = "rayon",
+    any(feature = "float", feature = "half", feature = "float8")
+))]
+use argminmax::NaNParArgMinMax;
+#[cfg(feature = "rayon")]
+use argminmax::ParArgMinMax;
+use argminmax::{
+    argminmax_reader, AccumulatorStrategy, ArgMinMax, ArgMinMaxAccumulator, ArgMinMaxError,
+    ArgMinMaxIterExt, ArgMinMaxReadError, BinnedArgMinMax, FromLeBytes, M4ArgMinMax, MinMaxLTTB,
+    MinMaxResult, RollingArgMinMax, SegmentedArgMinMax, TiePolicy,
+};
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+use argminmax::{
+    nanargminmax_reader, NaNArgMinMax, NaNBinnedArgMinMax, NaNM4ArgMinMax, NaNRollingArgMinMax,
+    NaNSegmentedArgMinMax,
+};
+#[cfg(any(feature = "arrow", feature = "arrow2"))]
+use argminmax::{ChunkedArgMinMax, ChunkedIndex, NullableArgMinMax};
+#[cfg(all(
+    any(feature = "arrow", feature = "arrow2"),
+    any(feature = "float", feature = "half", feature = "float8")
+))]
+use argminmax::{NaNChunkedArgMinMax, NaNNullableArgMinMax};
+
+#[cfg(feature = "float8")]
+use float8::{F8E4M3, F8E5M2};
 #[cfg(feature = "half")]
-use half::f16;
+use half::{bf16, f16};
 use num_traits::{AsPrimitive, FromPrimitive};
 
+use std::collections::VecDeque;
+
 use rstest::rstest;
 use rstest_reuse::{self, *};
 
@@ -15,33 +44,21 @@ use rand;
 const ARRAY_LENGTH: usize = 100_000;
 const NB_RANDOM_RUNS: usize = 500;
 const RANDOM_ARR_LENGTH: usize = 5_000;
+#[cfg(feature = "rayon")]
+const PAR_ARRAY_LENGTH: usize = 600_000;
 
 // ----- dtypes_with_nan template -----
 
-// Float and half
-#[cfg(all(feature = "float", feature = "half"))]
-#[template]
-#[rstest]
-// https://stackoverflow.com/a/3793950
-#[case::float16(f16::MIN, f16::from_usize(1 << f16::MANTISSA_DIGITS).unwrap())]
-#[case::float32(f32::MIN, f32::MAX)]
-#[case::float64(f64::MIN, f64::MAX)]
-fn dtypes_with_nan<T>(#[case] min: T, #[case] max: T) {}
-
-// Float and not half
-#[cfg(all(feature = "float", not(feature = "half")))]
-#[template]
-#[rstest]
-#[case::float32(f32::MIN, f32::MAX)]
-#[case::float64(f64::MIN, f64::MAX)]
-fn dtypes_with_nan<T>(#[case] min: T, #[case] max: T) {}
-
-// Not float and half
-#[cfg(all(not(feature = "float"), feature = "half"))]
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
 #[template]
 #[rstest]
 // https://stackoverflow.com/a/3793950
-#[case::float16(f16::MIN, f16::from_usize(1 << f16::MANTISSA_DIGITS).unwrap())]
+#[cfg_attr(feature = "float8", case::float8e4m3(F8E4M3::MIN, F8E4M3::from_usize(1 << F8E4M3::MANTISSA_DIGITS).unwrap()))]
+#[cfg_attr(feature = "float8", case::float8e5m2(F8E5M2::MIN, F8E5M2::from_usize(1 << F8E5M2::MANTISSA_DIGITS).unwrap()))]
+#[cfg_attr(feature = "half", case::float16(f16::MIN, f16::from_usize(1 << f16::MANTISSA_DIGITS).unwrap()))]
+#[cfg_attr(feature = "half", case::bfloat16(bf16::MIN, bf16::from_usize(1 << bf16::MANTISSA_DIGITS).unwrap()))]
+#[cfg_attr(feature = "float", case::float32(f32::MIN, f32::MAX))]
+#[cfg_attr(feature = "float", case::float64(f64::MIN, f64::MAX))]
 fn dtypes_with_nan<T>(#[case] min: T, #[case] max: T) {}
 
 // ----- dtypes template -----
@@ -56,10 +73,14 @@ fn dtypes_with_nan<T>(#[case] min: T, #[case] max: T) {}
 #[case::int16(i16::MIN, i16::MAX)]
 #[case::int32(i32::MIN, i32::MAX)]
 #[case::int64(i64::MIN, i64::MAX)]
+#[case::int128(i128::MIN, i128::MAX)]
 #[case::uint8(u8::MIN, u8::MAX)]
 #[case::uint16(u16::MIN, u16::MAX)]
 #[case::uint32(u32::MIN, u32::MAX)]
 #[case::uint64(u64::MIN, u64::MAX)]
+#[case::uint128(u128::MIN, u128::MAX)]
+#[case::isize(isize::MIN, isize::MAX)]
+#[case::usize(usize::MIN, usize::MAX)]
 fn dtypes<T>(#[case] min: T, #[case] max: T) {}
 
 #[cfg(not(feature = "float"))]
@@ -70,10 +91,14 @@ fn dtypes<T>(#[case] min: T, #[case] max: T) {}
 #[case::int16(i16::MIN, i16::MAX)]
 #[case::int32(i32::MIN, i32::MAX)]
 #[case::int64(i64::MIN, i64::MAX)]
+#[case::int128(i128::MIN, i128::MAX)]
 #[case::uint8(u8::MIN, u8::MAX)]
 #[case::uint16(u16::MIN, u16::MAX)]
 #[case::uint32(u32::MIN, u32::MAX)]
 #[case::uint64(u64::MIN, u64::MAX)]
+#[case::uint128(u128::MIN, u128::MAX)]
+#[case::isize(isize::MIN, isize::MAX)]
+#[case::usize(usize::MIN, usize::MAX)]
 fn dtypes<T>(#[case] min: T, #[case] max: T) {}
 
 // ----- Helpers -----
@@ -94,6 +119,211 @@ where
         .collect::<Vec<T>>()
 }
 
+/// Returns a VecDeque with the values of the array, of which the first `front_len`
+/// values are stored at the end of the ring buffer (i.e., the deque wraps around)
+fn get_vecdeque<T: Copy>(data: &[T], front_len: usize) -> VecDeque<T> {
+    let mut deque = VecDeque::with_capacity(data.len());
+    // Move the head of the (empty) deque to `front_len` slots before the end
+    for _ in 0..deque.capacity() - front_len {
+        deque.push_back(data[0]);
+    }
+    while deque.pop_front().is_some() {}
+    deque.extend(data.iter().copied());
+    assert_eq!(deque.as_slices().0.len(), front_len);
+    deque
+}
+
+/// Returns true if the value is NaN (i.e., is not comparable to itself)
+#[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+fn is_nan<T: PartialOrd>(value: T) -> bool {
+    value.partial_cmp(&value).is_none()
+}
+
+/// Returns an array of 5_000 slots with nulls (None) at every `null_every`-th slot and
+/// at the last slot. The (valid) minimum is at index 1_500 (tied at index 3_001) and
+/// the (valid) maximum is at index 2_500 (for `null_every` 7 or 700).
+#[cfg(any(feature = "arrow", feature = "arrow2"))]
+fn get_nullable_array<T: FromPrimitive>(null_every: usize) -> Vec<Option<T>> {
+    (0..5_000)
+        .map(|i| match i {
+            _ if i % null_every == 0 || i == 4_999 => None,
+            1_500 | 3_001 => T::from_usize(1),
+            2_500 => T::from_usize(5),
+            _ => T::from_usize(3),
+        })
+        .collect()
+}
+
+/// Returns an array of PAR_ARRAY_LENGTH values in 0..7, which spans multiple parallel
+/// chunks for all data types and contains many ties
+#[cfg(feature = "rayon")]
+fn get_par_array<T: FromPrimitive>() -> Vec<T> {
+    (0..PAR_ARRAY_LENGTH)
+        .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+        .collect()
+}
+
+/// Returns the sorted (and deduplicated) indices of the minimum and maximum values of
+/// each of the n_bins equal-width bins, computed by calling arg_minmax on each bin
+fn get_binned_indices<T>(
+    data: &[T],
+    n_bins: usize,
+    arg_minmax: impl Fn(&[T]) -> (usize, usize),
+) -> Vec<usize> {
+    let mut result = Vec::new();
+    for bin in 0..n_bins {
+        let (start, end) = (bin * data.len() / n_bins, (bin + 1) * data.len() / n_bins);
+        if start == end {
+            continue;
+        }
+        let (min_index, max_index) = arg_minmax(&data[start..end]);
+        result.push(start + std::cmp::min(min_index, max_index));
+        if min_index != max_index {
+            result.push(start + std::cmp::max(min_index, max_index));
+        }
+    }
+    result
+}
+
+/// Returns the sorted (and deduplicated) indices of the first, minimum, maximum and
+/// last values of each bin offsets[i]..offsets[i + 1], computed by calling arg_minmax
+/// on each bin
+fn get_m4_indices<T>(
+    data: &[T],
+    offsets: &[usize],
+    arg_minmax: impl Fn(&[T]) -> (usize, usize),
+) -> Vec<usize> {
+    let mut result = std::collections::BTreeSet::new();
+    for bin in offsets.windows(2) {
+        let (start, end) = (bin[0], bin[1]);
+        if start == end {
+            continue;
+        }
+        let (min_index, max_index) = arg_minmax(&data[start..end]);
+        result.extend([start, start + min_index, start + max_index, end - 1]);
+    }
+    result.into_iter().collect()
+}
+
+/// Returns the indices of the n_out points selected by Largest-Triangle-Three-Buckets
+fn get_lttb_indices(x: &[f64], y: &[f64], n_out: usize) -> Vec<usize> {
+    let n = x.len();
+    if n <= n_out {
+        return (0..n).collect();
+    }
+    let bucket_size = (n - 2) as f64 / (n_out - 2) as f64;
+    let bucket = |i: usize| {
+        let start = (i as f64 * bucket_size) as usize + 1;
+        let end = std::cmp::min(((i + 1) as f64 * bucket_size) as usize + 1, n);
+        start..end
+    };
+    let mut result = vec![0];
+    for i in 0..n_out - 2 {
+        let next = if i + 1 < n_out - 2 {
+            bucket(i + 1)
+        } else {
+            n - 1..n
+        };
+        let len = next.len() as f64;
+        let avg_x = next.clone().map(|j| x[j]).sum::<f64>() / len;
+        let avg_y = next.map(|j| y[j]).sum::<f64>() / len;
+        let a = *result.last().unwrap();
+        let area =
+            |j: usize| ((x[a] - avg_x) * (y[j] - y[a]) - (x[a] - x[j]) * (avg_y - y[a])).abs();
+        // The first point with the largest area
+        let best = bucket(i).fold(None, |best: Option<usize>, j| match best {
+            Some(b) if area(b) >= area(j) => Some(b),
+            _ => Some(j),
+        });
+        result.push(best.unwrap());
+    }
+    result.push(n - 1);
+    result
+}
+
+/// Returns the (min_index, max_index) of an ArgMinMaxAccumulator that is updated with
+/// chunks of the given (cycled) sizes - asserts that merging the accumulators of two
+/// parts of the data gives the same result
+fn get_accumulated_indices<T, S>(data: &[T], chunk_sizes: &[usize]) -> (usize, usize)
+where
+    T: Copy,
+    S: AccumulatorStrategy<T>,
+{
+    let accumulate = |data: &[T]| {
+        let mut acc = ArgMinMaxAccumulator::<T, S>::new();
+        let (mut start, mut sizes) = (0, chunk_sizes.iter().cycle());
+        while start < data.len() {
+            let end = std::cmp::min(start + sizes.next().unwrap(), data.len());
+            acc.update(&data[start..end]);
+            start = end;
+        }
+        assert_eq!(acc.offset(), data.len());
+        acc
+    };
+    let acc = accumulate(data);
+    let mut merged = accumulate(&data[..data.len() / 3]);
+    merged.merge(accumulate(&data[data.len() / 3..]));
+    assert_eq!(merged.offset(), data.len());
+    assert_eq!(merged.finish(), acc.finish());
+    acc.finish().unwrap()
+}
+
+/// Returns the (pseudo-random) little-endian bytes of n values of type T
+/// The most significant byte of a value is never in 0x78..0x80 or 0xf8.. - this way
+/// the exponent of a float is never all ones (i.e., there are no NaNs or infinities)
+fn get_le_bytes<T: FromLeBytes>(n: usize) -> Vec<u8> {
+    (0..n * T::NB_BYTES)
+        .map(|i| {
+            let byte = ((i * i + i / 3) % 251) as u8;
+            match (i + 1) % T::NB_BYTES == 0 {
+                true => (byte % 0x78) | (byte & 0x80),
+                false => byte,
+            }
+        })
+        .collect()
+}
+
+/// Returns the data split in chunks of the given (cycled) sizes
+#[cfg(feature = "futures")]
+fn get_chunks<T: Clone>(data: &[T], chunk_sizes: &[usize]) -> Vec<Vec<T>> {
+    let (mut chunks, mut start) = (Vec::new(), 0);
+    for size in chunk_sizes.iter().cycle() {
+        if start >= data.len() {
+            break;
+        }
+        let end = std::cmp::min(start + size, data.len());
+        chunks.push(data[start..end].to_vec());
+        start = end;
+    }
+    chunks
+}
+
+/// Returns the indices of the k largest (if max) or smallest values, computed by
+/// (stable) sorting the indices on their values
+/// NaNs are ranked first if nan_first, otherwise they are ignored
+fn get_topk_indices<T: Copy + PartialOrd>(
+    data: &[T],
+    k: usize,
+    max: bool,
+    nan_first: bool,
+) -> Vec<usize> {
+    let is_nan = |v: &T| v.partial_cmp(v).is_none();
+    let (nans, mut indices): (Vec<usize>, Vec<usize>) =
+        (0..data.len()).partition(|&i| is_nan(&data[i]));
+    indices.sort_by(|&a, &b| {
+        let ordering = data[a].partial_cmp(&data[b]).unwrap();
+        if max {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    let mut result = if nan_first { nans } else { Vec::new() };
+    result.extend(indices);
+    result.truncate(k);
+    result
+}
+
 // ======================================= TESTS =======================================
 
 /// Test the ArgMinMax trait for the default implementations: slice and vec
@@ -105,7 +335,7 @@ mod default_test {
     fn test_argminmax_slice<T>(#[case] _min: T, #[case] max: T)
     where
         T: Copy + FromPrimitive + AsPrimitive<usize>,
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
     {
         // max_index is the max value that can be represented by T
         let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
@@ -125,12 +355,12 @@ mod default_test {
         assert_eq!(max, (&data).argmax());
     }
 
-    #[cfg(any(feature = "float", feature = "half"))]
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
     #[apply(dtypes_with_nan)]
     fn test_argminmax_slice_nan<T>(#[case] _min: T, #[case] max: T)
     where
         T: Copy + FromPrimitive + AsPrimitive<usize>,
-        for<'a> &'a [T]: NaNArgMinMax,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
     {
         // max_index is the max value that can be represented by T
         let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
@@ -164,7 +394,7 @@ mod default_test {
     fn test_argminmax_vec<T>(#[case] _min: T, #[case] max: T)
     where
         T: Copy + FromPrimitive + AsPrimitive<usize>,
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
     {
         // max_index is the max value that can be represented by T
         let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
@@ -198,12 +428,12 @@ mod default_test {
         assert_eq!(max, (&mut data_mut).argmax());
     }
 
-    #[cfg(any(feature = "float", feature = "half"))]
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
     #[apply(dtypes_with_nan)]
     fn test_argminmax_vec_nan<T>(#[case] _min: T, #[case] max: T)
     where
         T: Copy + FromPrimitive + AsPrimitive<usize>,
-        for<'a> &'a [T]: NaNArgMinMax,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
     {
         // max_index is the max value that can be represented by T
         let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
@@ -223,11 +453,1153 @@ mod default_test {
         assert_eq!(max, (&data).nanargmax());
     }
 
+    #[apply(dtypes)]
+    fn test_argminmax_vecdeque<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        // Cyclic array -> the min and max value occur in both slices of the deque
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        for front_len in [1, 7, 1_025, ARRAY_LENGTH - 1, ARRAY_LENGTH] {
+            let deque = get_vecdeque(&data, front_len);
+            assert_eq!(deque.argminmax(), data.argminmax());
+            assert_eq!(deque.argmin(), data.argmin());
+            assert_eq!(deque.argmax(), data.argmax());
+            let result = deque.argminmax_with_values();
+            assert_eq!(result.indices(), data.argminmax());
+            assert_eq!(result.min_index, deque.argmin());
+            assert_eq!(deque.try_argminmax().unwrap(), data.argminmax());
+            assert_eq!(deque.try_argmin().unwrap(), data.argmin());
+            assert_eq!(deque.try_argmax().unwrap(), data.argmax());
+            for k in [1, 3, 2 * ARRAY_LENGTH] {
+                assert_eq!(deque.argmin_k(k), data.argmin_k(k));
+                assert_eq!(deque.argmax_k(k), data.argmax_k(k));
+            }
+            for tie in [TiePolicy::First, TiePolicy::Last] {
+                assert_eq!(deque.argminmax_with_tie(tie), data.argminmax_with_tie(tie));
+                assert_eq!(deque.argmin_with_tie(tie), data.argmin_with_tie(tie));
+                assert_eq!(deque.argmax_with_tie(tie), data.argmax_with_tie(tie));
+            }
+        }
+        // Empty deque
+        let deque: VecDeque<T> = VecDeque::new();
+        assert_eq!(deque.try_argminmax(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(deque.argmin_k(3), Vec::<usize>::new());
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_vecdeque_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        data[1_500] = nan;
+        data[3_000] = nan;
+        for front_len in [1, 1_000, 2_000, 4_999] {
+            let deque = get_vecdeque(&data, front_len);
+            // NaNs are ignored
+            assert_eq!(deque.argminmax(), data.argminmax());
+            assert_eq!(deque.argmin(), data.argmin());
+            assert_eq!(deque.argmax(), data.argmax());
+            assert_eq!(deque.argmin_k(3), data.argmin_k(3));
+            assert_eq!(deque.argmax_k(3), data.argmax_k(3));
+            assert_eq!(
+                deque.argminmax_with_tie(TiePolicy::Last),
+                data.argminmax_with_tie(TiePolicy::Last)
+            );
+            // The index of the first NaN is returned
+            assert_eq!(deque.nanargminmax(), (1_500, 1_500));
+            assert_eq!(deque.nanargmin(), 1_500);
+            assert_eq!(deque.nanargmax(), 1_500);
+            assert_eq!(deque.nanargminmax_with_values().indices(), (1_500, 1_500));
+            assert_eq!(deque.try_nanargminmax().unwrap(), (1_500, 1_500));
+            assert_eq!(deque.nanargmin_k(3), data.nanargmin_k(3));
+            assert_eq!(deque.nanargmax_k(3), data.nanargmax_k(3));
+            assert_eq!(deque.nanargminmax_with_tie(TiePolicy::Last), (1_500, 1_500));
+        }
+
+        // Only NaNs in the front slice -> the values of the back slice are returned
+        let mut data: Vec<T> = (0..1_025).map(|i| T::from_usize(i % 7).unwrap()).collect();
+        data[..500].iter_mut().for_each(|v| *v = nan);
+        let deque = get_vecdeque(&data, 500);
+        assert_eq!(deque.argminmax(), (504, 503));
+        assert_eq!(deque.try_argminmax().unwrap(), (504, 503));
+        assert_eq!(deque.nanargminmax(), (0, 0));
+        // Only NaNs
+        let deque = get_vecdeque(&[nan; 1_025], 500);
+        assert_eq!(deque.try_argminmax(), Err(ArgMinMaxError::AllNaN));
+        assert_eq!(deque.nanargminmax(), (0, 0));
+    }
+
+    #[apply(dtypes)]
+    fn test_rolling_argminmax<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + PartialOrd + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // Many ties -> earlier values win (as for argminmax on each window)
+        let data: Vec<T> = (0..1_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        for (window, step) in [(1, 1), (5, 1), (64, 3), (100, 100), (10, 33), (1_000, 1)] {
+            let windows = (0..=data.len() - window).step_by(step);
+            let (min_indices, max_indices): (Vec<usize>, Vec<usize>) = windows
+                .map(|start| {
+                    let (min_index, max_index) = (&data[start..start + window]).argminmax();
+                    (start + min_index, start + max_index)
+                })
+                .unzip();
+            assert_eq!(
+                data.rolling_argminmax(window, step),
+                (min_indices.clone(), max_indices.clone())
+            );
+            assert_eq!(data.as_slice().rolling_argmin(window, step), min_indices);
+            assert_eq!(data.as_slice().rolling_argmax(window, step), max_indices);
+        }
+        // Array shorter than the window
+        assert_eq!(data.rolling_argminmax(1_001, 1), (vec![], vec![]));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_rolling_argminmax_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + PartialOrd + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..1_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        // Some windows contain only NaNs
+        data[100..150].iter_mut().for_each(|v| *v = nan);
+        data[500] = nan;
+        data[999] = nan;
+        for (window, step) in [(1, 1), (5, 1), (20, 3), (30, 7), (64, 64)] {
+            let windows: Vec<usize> = (0..=data.len() - window).step_by(step).collect();
+            // NaNs are ignored
+            let (min_indices, max_indices): (Vec<usize>, Vec<usize>) = windows
+                .iter()
+                .map(|&start| {
+                    let (min_index, max_index) = (&data[start..start + window]).argminmax();
+                    (start + min_index, start + max_index)
+                })
+                .unzip();
+            assert_eq!(
+                data.rolling_argminmax(window, step),
+                (min_indices, max_indices)
+            );
+            // NaNs are returned
+            let (min_indices, max_indices): (Vec<usize>, Vec<usize>) = windows
+                .iter()
+                .map(|&start| {
+                    let (min_index, max_index) = (&data[start..start + window]).nanargminmax();
+                    (start + min_index, start + max_index)
+                })
+                .unzip();
+            assert_eq!(data.rolling_nanargmin(window, step), min_indices);
+            assert_eq!(data.rolling_nanargmax(window, step), max_indices);
+            assert_eq!(
+                data.as_slice().rolling_nanargminmax(window, step),
+                (min_indices, max_indices)
+            );
+        }
+    }
+
+    #[apply(dtypes)]
+    fn test_segmented_argminmax<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + SegmentedArgMinMax,
+    {
+        let data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        // Empty, short and long segments (the first value is not part of a segment)
+        let offsets: Vec<usize> = vec![1, 1, 2, 10, 73, 73, 1_000, 4_999, 5_000];
+        let expected: Vec<Option<(usize, usize)>> = offsets
+            .windows(2)
+            .map(|w| match w[0] == w[1] {
+                true => None,
+                false => {
+                    let (min_index, max_index) = (&data[w[0]..w[1]]).argminmax();
+                    Some((w[0] + min_index, w[0] + max_index))
+                }
+            })
+            .collect();
+        assert_eq!(data.segmented_argminmax(&offsets), expected);
+        // Arrow-like (i32) offsets
+        let offsets: Vec<i32> = offsets.iter().map(|&o| o as i32).collect();
+        assert_eq!(data.as_slice().segmented_argminmax(&offsets), expected);
+        // No segments
+        assert_eq!(data.segmented_argminmax(&[0_usize]), vec![]);
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_segmented_argminmax_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + SegmentedArgMinMax + NaNSegmentedArgMinMax,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..1_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        data[3] = nan;
+        data[10..20].iter_mut().for_each(|v| *v = nan);
+        data[500] = nan;
+        let offsets: Vec<i64> = vec![0, 5, 10, 20, 200, 1_000];
+        // NaNs are ignored (a segment with only NaNs -> index of its first value)
+        let expected: Vec<Option<(usize, usize)>> = offsets
+            .windows(2)
+            .map(|w| {
+                let (start, end) = (w[0] as usize, w[1] as usize);
+                let (min_index, max_index) = (&data[start..end]).argminmax();
+                Some((start + min_index, start + max_index))
+            })
+            .collect();
+        assert_eq!(expected[2], Some((10, 10)));
+        assert_eq!(data.segmented_argminmax(&offsets), expected);
+        // NaNs are returned
+        let expected = vec![
+            Some((3, 3)),
+            expected[1],
+            Some((10, 10)),
+            expected[3],
+            Some((500, 500)),
+        ];
+        assert_eq!(data.segmented_nanargminmax(&offsets), expected);
+    }
+
+    #[apply(dtypes)]
+    fn test_binned_argminmax<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Vec<T> = (0..1_003)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        for n_bins in [1, 7, 100, 1_003, 2_000] {
+            let expected = get_binned_indices(&data, n_bins, |s| s.argminmax());
+            assert_eq!(data.binned_argminmax(n_bins), expected);
+            assert_eq!(data.as_slice().binned_argminmax(n_bins), expected);
+            #[cfg(feature = "rayon")]
+            assert_eq!(data.par_binned_argminmax(n_bins), expected);
+        }
+        // Bins of a single value -> each index once
+        assert_eq!(data.binned_argminmax(1_003), (0..1_003).collect::<Vec<_>>());
+        // Empty array
+        assert_eq!(Vec::<T>::new().binned_argminmax(10), Vec::<usize>::new());
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_binned_argminmax_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..1_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        // The second bin (of 10) contains only NaNs
+        data[100..200].iter_mut().for_each(|v| *v = nan);
+        data[555] = nan;
+        // NaNs are ignored
+        let expected = get_binned_indices(&data, 10, |s| s.argminmax());
+        assert_eq!(data.binned_argminmax(10), expected);
+        assert!(expected.contains(&100) && !expected.contains(&555));
+        // NaNs are returned
+        let expected = get_binned_indices(&data, 10, |s| s.nanargminmax());
+        assert_eq!(data.binned_nanargminmax(10), expected);
+        assert!(expected.contains(&100) && expected.contains(&555));
+        #[cfg(feature = "rayon")]
+        assert_eq!(data.par_binned_nanargminmax(10), expected);
+    }
+
+    #[apply(dtypes)]
+    fn test_binned_argminmax_x<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Vec<T> = (0..1_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        let argminmax_range = |start: usize, end: usize| {
+            let (min_index, max_index) = (&data[start..end]).argminmax();
+            Some((start + min_index, start + max_index))
+        };
+        // Unevenly spaced (quadratic) timestamps -> 4 bins of 250_000 (the last bin
+        // also contains its right edge)
+        let x: Vec<i64> = (0..1_000).map(|i| i * i).collect();
+        let expected = vec![
+            argminmax_range(0, 500),
+            argminmax_range(500, 708),
+            argminmax_range(708, 866),
+            argminmax_range(866, 1_000),
+        ];
+        assert_eq!(data.binned_argminmax_x(&x, 4), expected);
+        // Explicit edges (values outside the edges are not part of any bin)
+        let x: Vec<f32> = (0..1_000).map(|i| i as f32 / 10.0).collect();
+        let edges = [5.0, 10.01, 10.05, 50.0, 120.0];
+        let expected = vec![
+            argminmax_range(50, 101),
+            None, // empty bin
+            argminmax_range(101, 500),
+            argminmax_range(500, 1_000),
+        ];
+        assert_eq!(data.binned_argminmax_edges(&x, &edges), expected);
+        // Last bin contains its right edge
+        assert_eq!(
+            data.binned_argminmax_edges(&x, &[0.0, 99.9])[0],
+            argminmax_range(0, 1_000)
+        );
+        // Gap in the timestamps -> empty bins
+        let x: Vec<u32> = (0..1_000)
+            .map(|i| if i < 500 { i } else { i + 10_000 })
+            .collect();
+        let result = data.binned_argminmax_x(&x, 10);
+        assert_eq!(result[0], argminmax_range(0, 500));
+        assert!(result[1..9].iter().all(|r| r.is_none()));
+        assert_eq!(result[9], argminmax_range(500, 1_000));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_binned_argminmax_x_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..1_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        data[10] = nan;
+        data[600..700].iter_mut().for_each(|v| *v = nan);
+        let x: Vec<f64> = (0..1_000).map(|i| i as f64).collect();
+        let edges = [0.0, 500.0, 600.0, 700.0, 999.0];
+        // NaNs are ignored (a bin with only NaNs -> index of its first value)
+        let result = data.binned_argminmax_edges(&x, &edges);
+        let (min_index, max_index) = (&data[..500]).argminmax();
+        assert_eq!(result[0], Some((min_index, max_index)));
+        assert_eq!(result[2], Some((600, 600)));
+        assert_eq!(data.binned_argminmax_x(&x, 1)[0], Some(data.argminmax()));
+        // NaNs are returned
+        let result = data.binned_nanargminmax_edges(&x, &edges);
+        assert_eq!(result[0], Some((10, 10)));
+        assert_eq!(result[2], Some((600, 600)));
+        assert_eq!(data.binned_nanargminmax_x(&x, 2)[0], Some((10, 10)));
+    }
+
+    #[apply(dtypes)]
+    fn test_m4<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Vec<T> = (0..1_003)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        for n_bins in [1, 7, 100, 1_003, 2_000] {
+            let offsets: Vec<usize> = (0..=n_bins).map(|i| i * data.len() / n_bins).collect();
+            let expected = get_m4_indices(&data, &offsets, |s| s.argminmax());
+            assert_eq!(data.m4(n_bins), expected);
+            assert_eq!(data.as_slice().m4(n_bins), expected);
+        }
+        // Bins of a single value -> each index once
+        assert_eq!(data.m4(1_003), (0..1_003).collect::<Vec<_>>());
+        // Empty array
+        assert_eq!(Vec::<T>::new().m4(10), Vec::<usize>::new());
+
+        // Unevenly spaced (quadratic) timestamps -> 4 bins of 250_000
+        let x: Vec<i64> = (0..1_003).map(|i| i * i).collect();
+        let offsets = [0, 501, 709, 868, 1_003];
+        let expected = get_m4_indices(&data, &offsets, |s| s.argminmax());
+        assert_eq!(data.m4_x(&x, 4), expected);
+        // Explicit edges (values outside the edges are not part of any bin)
+        let x: Vec<f32> = (0..1_003).map(|i| i as f32 / 10.0).collect();
+        let edges = [5.0, 10.01, 10.05, 50.0, 120.0];
+        let offsets = [50, 101, 101, 500, 1_003];
+        let expected = get_m4_indices(&data, &offsets, |s| s.argminmax());
+        assert_eq!(data.m4_edges(&x, &edges), expected);
+        assert_eq!(expected.first(), Some(&50));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_m4_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..1_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        // The second bin (of 10) contains only NaNs
+        data[100..200].iter_mut().for_each(|v| *v = nan);
+        data[555] = nan;
+        let offsets: Vec<usize> = (0..=10).map(|i| i * 100).collect();
+        // NaNs are ignored
+        let expected = get_m4_indices(&data, &offsets, |s| s.argminmax());
+        assert_eq!(data.m4(10), expected);
+        assert!(!expected.contains(&555));
+        // NaNs are returned
+        let expected = get_m4_indices(&data, &offsets, |s| s.nanargminmax());
+        assert_eq!(data.nan_m4(10), expected);
+        assert!(expected.contains(&555));
+        let x: Vec<f64> = (0..1_000).map(|i| i as f64).collect();
+        assert_eq!(data.nan_m4_x(&x, 10), expected);
+        let edges: Vec<f64> = (0..=10).map(|i| (i * 100) as f64 - 0.5).collect();
+        assert_eq!(data.nan_m4_edges(&x, &edges), expected);
+    }
+
+    #[apply(dtypes)]
+    fn test_minmaxlttb<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<f64>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Vec<T> = (0..1_003)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        let x: Vec<f64> = (0..1_003).map(|i| i as f64).collect();
+        let y: Vec<f64> = data.iter().map(|v| v.as_()).collect();
+        // MinMax preselection of 4 * 50 candidates (+ the first and last point)
+        let mut candidates = vec![0];
+        candidates.extend(
+            get_binned_indices(&data[1..1_002], 100, |s| s.argminmax())
+                .into_iter()
+                .map(|i| i + 1),
+        );
+        candidates.push(1_002);
+        let x_c: Vec<f64> = candidates.iter().map(|&i| x[i]).collect();
+        let y_c: Vec<f64> = candidates.iter().map(|&i| y[i]).collect();
+        let expected: Vec<usize> = get_lttb_indices(&x_c, &y_c, 50)
+            .into_iter()
+            .map(|i| candidates[i])
+            .collect();
+        let result = data.minmaxlttb(50, 4);
+        assert_eq!(result, expected);
+        assert_eq!(result.len(), 50);
+        assert_eq!((result[0], result[49]), (0, 1_002));
+        assert_eq!(data.as_slice().minmaxlttb(50, 4), expected);
+        // Equally spaced x-axis -> same bins
+        let x_int: Vec<i64> = (0..1_003).collect();
+        assert_eq!(data.minmaxlttb_x(&x_int, 50, 4), expected);
+        let x_float: Vec<f32> = (0..1_003).map(|i| i as f32 / 4.0).collect();
+        assert_eq!(data.minmaxlttb_x(&x_float, 50, 4), expected);
+        // No preselection when minmax_ratio * n_out >= n -> plain LTTB
+        assert_eq!(data.minmaxlttb(50, 100), get_lttb_indices(&x, &y, 50));
+        // n_out >= n -> all points
+        assert_eq!(data.minmaxlttb(1_003, 4), (0..1_003).collect::<Vec<_>>());
+        assert_eq!(Vec::<T>::new().minmaxlttb(10, 4), Vec::<usize>::new());
+    }
+
+    #[cfg(feature = "half")]
+    #[apply(dtypes_with_nan)]
+    fn test_minmaxlttb_f16<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<f64>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Vec<T> = (0..1_003)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        // f16 represents the integers up to 2048 exactly
+        let x: Vec<f16> = (0..1_003).map(|i| f16::from_usize(i).unwrap()).collect();
+        let expected = data.minmaxlttb(50, 4);
+        assert_eq!(expected.len(), 50);
+        assert_eq!(data.minmaxlttb_x(&x, 50, 4), expected);
+        let data: Vec<f16> = data.iter().map(|&v| f16::from_f64(v.as_())).collect();
+        assert_eq!(data.minmaxlttb_x(&x, 50, 4), expected);
+    }
+
+    fn check_accumulator_int<T>()
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        argminmax::dtype_strategy::Int: AccumulatorStrategy<T>,
+    {
+        use argminmax::dtype_strategy::Int;
+        let data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        for chunk_sizes in [&[5_000][..], &[1, 0, 37, 1_000, 3], &[64]] {
+            let result = get_accumulated_indices::<T, Int>(&data, chunk_sizes);
+            assert_eq!(result, data.argminmax());
+        }
+        // Nothing accumulated
+        let acc = ArgMinMaxAccumulator::<T, Int>::default();
+        assert!(acc.result().is_none());
+        assert_eq!(acc.finish(), None);
+        assert_eq!(acc.offset(), 0);
+    }
+
+    #[test]
+    fn test_argminmax_accumulator_int() {
+        check_accumulator_int::<i8>();
+        check_accumulator_int::<i32>();
+        check_accumulator_int::<i128>();
+        check_accumulator_int::<u16>();
+        check_accumulator_int::<u64>();
+        check_accumulator_int::<usize>();
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_accumulator_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + PartialEq + std::fmt::Debug,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+        FloatIgnoreNaN: AccumulatorStrategy<T>,
+        FloatReturnNaN: AccumulatorStrategy<T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        // The first chunks contain only NaNs
+        data[..50].iter_mut().for_each(|v| *v = nan);
+        data[2_222] = nan;
+        for chunk_sizes in [&[5_000][..], &[1, 0, 37, 1_000, 3], &[64]] {
+            let result = get_accumulated_indices::<T, FloatIgnoreNaN>(&data, chunk_sizes);
+            assert_eq!(result, data.argminmax());
+            let result = get_accumulated_indices::<T, FloatReturnNaN>(&data, chunk_sizes);
+            assert_eq!(result, (0, 0));
+            let result = get_accumulated_indices::<T, FloatReturnNaN>(&data[50..], chunk_sizes);
+            assert_eq!(result, (2_172, 2_172));
+        }
+        // The values are tracked as well
+        let mut acc = ArgMinMaxAccumulator::<T, FloatIgnoreNaN>::new();
+        acc.update(&data[..100]);
+        acc.update(&data[100..]);
+        assert_eq!(acc.result(), Some(data.argminmax_with_values()));
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_iter<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        // Multiple (partial) chunks, including lengths that are a multiple of the chunk
+        for len in [1, 1_000, 1_024, 2_048, 5_000] {
+            let data = &data[..len];
+            assert_eq!(
+                data.iter().copied().argminmax_iter(),
+                Some(data.argminmax())
+            );
+        }
+        // The indices are the positions in the (filtered) iterator
+        let filtered: Vec<T> = data.iter().copied().step_by(3).collect();
+        assert_eq!(
+            data.iter().copied().step_by(3).argminmax_iter(),
+            Some(filtered.argminmax())
+        );
+        // Empty iterator
+        assert_eq!(std::iter::empty::<T>().argminmax_iter(), None);
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_iter_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        // The first chunk contains only NaNs
+        data[..1_024].iter_mut().for_each(|v| *v = nan);
+        data[3_333] = nan;
+        // NaNs are ignored
+        assert_eq!(
+            data.iter().copied().argminmax_iter(),
+            Some(data.argminmax())
+        );
+        // NaNs are returned
+        assert_eq!(data.iter().copied().nanargminmax_iter(), Some((0, 0)));
+        let data = &data[1_024..];
+        assert_eq!(
+            data.iter().copied().nanargminmax_iter(),
+            Some((2_309, 2_309))
+        );
+        assert_eq!(std::iter::empty::<T>().nanargminmax_iter(), None);
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_reader<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromLeBytes + PartialEq + std::fmt::Debug,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let bytes = get_le_bytes::<T>(5_000);
+        let data: Vec<T> = bytes
+            .chunks_exact(T::NB_BYTES)
+            .map(T::from_le_slice)
+            .collect();
+        for buf_size in [1, 7, 1_024, 5_000, 10_000] {
+            let result = argminmax_reader::<T, _>(bytes.as_slice(), buf_size).unwrap();
+            assert_eq!(result, data.argminmax_with_values());
+        }
+        // Trailing partial value
+        if T::NB_BYTES > 1 {
+            let result = argminmax_reader::<T, _>(&bytes[..bytes.len() - 1], 100);
+            assert!(
+                matches!(result, Err(ArgMinMaxReadError::TrailingBytes(n)) if n == T::NB_BYTES - 1)
+            );
+        }
+        // Empty stream
+        let result = argminmax_reader::<T, _>(&[][..], 100);
+        assert!(matches!(result, Err(ArgMinMaxReadError::EmptyInput)));
+    }
+
+    #[test]
+    fn test_argminmax_reader_io() {
+        use std::io::{Error, ErrorKind, Read};
+
+        /// Returns at most 3 bytes per read, is interrupted every other read and fails
+        /// after `fail_at` bytes
+        struct SlowReader<'a> {
+            data: &'a [u8],
+            pos: usize,
+            interrupt: bool,
+            fail_at: usize,
+        }
+
+        impl Read for SlowReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                self.interrupt = !self.interrupt;
+                if self.interrupt {
+                    return Err(Error::new(ErrorKind::Interrupted, "interrupted"));
+                }
+                if self.pos >= self.fail_at {
+                    return Err(Error::other("broken stream"));
+                }
+                let n = [3, buf.len(), self.data.len() - self.pos]
+                    .into_iter()
+                    .min()
+                    .unwrap();
+                buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+                self.pos += n;
+                Ok(n)
+            }
+        }
+
+        // Little-endian decoding: [1, -1, 256]
+        let bytes: Vec<u8> = vec![0x01, 0x00, 0xff, 0xff, 0x00, 0x01];
+        let reader = SlowReader {
+            data: &bytes,
+            pos: 0,
+            interrupt: false,
+            fail_at: usize::MAX,
+        };
+        let result = argminmax_reader::<i16, _>(reader, 2).unwrap();
+        let expected = MinMaxResult {
+            min_index: 1,
+            min_value: -1,
+            max_index: 2,
+            max_value: 256,
+        };
+        assert_eq!(result, expected);
+        // I/O errors are returned
+        let reader = SlowReader {
+            data: &bytes,
+            pos: 0,
+            interrupt: false,
+            fail_at: 4,
+        };
+        let result = argminmax_reader::<i16, _>(reader, 2);
+        assert!(matches!(result, Err(ArgMinMaxReadError::Io(e)) if e.kind() == ErrorKind::Other));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_reader_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromLeBytes,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let mut bytes = get_le_bytes::<T>(5_000);
+        // A positive NaN (all bits but the sign bit set) for all the float types
+        for i in [3_000, 4_000] {
+            bytes[i * T::NB_BYTES..(i + 1) * T::NB_BYTES].fill(0xff);
+            bytes[(i + 1) * T::NB_BYTES - 1] = 0x7f;
+        }
+        let data: Vec<T> = bytes
+            .chunks_exact(T::NB_BYTES)
+            .map(T::from_le_slice)
+            .collect();
+        for buf_size in [1, 7, 1_024, 10_000] {
+            // NaNs are ignored
+            let result = argminmax_reader::<T, _>(bytes.as_slice(), buf_size).unwrap();
+            assert_eq!(result.indices(), data.argminmax());
+            // NaNs are returned
+            let result = nanargminmax_reader::<T, _>(bytes.as_slice(), buf_size).unwrap();
+            assert_eq!(result.indices(), data.nanargminmax());
+            assert!(result.min_index <= 3_000);
+        }
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_argminmax_stream_int() {
+        use argminmax::dtype_strategy::Int;
+        use futures::executor::block_on;
+        use futures::stream;
+
+        let data: Vec<i32> = (0..5_000).map(|i| (i * i + i / 3) % 7).collect();
+        let chunks = get_chunks(&data, &[1, 0, 37, 1_000, 3]);
+        let result = block_on(stream::iter(chunks).argminmax_stream::<_, Int>());
+        assert_eq!(result, Some(data.argminmax_with_values()));
+        // Empty stream
+        let chunks: Vec<Vec<i32>> = vec![vec![], vec![]];
+        let result = block_on(stream::iter(chunks).argminmax_stream::<_, Int>());
+        assert_eq!(result, None);
+
+        // Raw little-endian bytes (values are split over the chunks)
+        let bytes = get_le_bytes::<i64>(5_000);
+        let data: Vec<i64> = bytes.chunks_exact(8).map(i64::from_le_slice).collect();
+        let chunks = get_chunks(&bytes, &[3, 0, 5, 1_000, 8]);
+        let result = block_on(stream::iter(chunks).argminmax_bytes_stream::<i64, Int>());
+        assert_eq!(result.unwrap(), data.argminmax_with_values());
+        // Trailing partial value
+        let chunks = get_chunks(&bytes[..bytes.len() - 3], &[3, 0, 5, 1_000, 8]);
+        let result = block_on(stream::iter(chunks).argminmax_bytes_stream::<i64, Int>());
+        assert!(matches!(result, Err(ArgMinMaxReadError::TrailingBytes(5))));
+        // Empty stream
+        let chunks: Vec<Vec<u8>> = vec![];
+        let result = block_on(stream::iter(chunks).argminmax_bytes_stream::<i64, Int>());
+        assert!(matches!(result, Err(ArgMinMaxReadError::EmptyInput)));
+    }
+
+    #[cfg(all(
+        feature = "futures",
+        any(feature = "float", feature = "half", feature = "float8")
+    ))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_stream_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + FromLeBytes,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+        FloatIgnoreNaN: AccumulatorStrategy<T>,
+        FloatReturnNaN: AccumulatorStrategy<T>,
+    {
+        use futures::executor::block_on;
+        use futures::stream;
+
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        data[..50].iter_mut().for_each(|v| *v = nan);
+        data[2_222] = nan;
+        let chunks = || stream::iter(get_chunks(&data, &[1, 0, 37, 1_000, 3]));
+        let result = block_on(chunks().argminmax_stream::<_, FloatIgnoreNaN>());
+        assert_eq!(result.unwrap().indices(), data.argminmax());
+        let result = block_on(chunks().argminmax_stream::<_, FloatReturnNaN>());
+        assert_eq!(result.unwrap().indices(), (0, 0));
+
+        // Raw little-endian bytes (a positive NaN for all the float types)
+        let mut bytes = get_le_bytes::<T>(5_000);
+        bytes[3_001 * T::NB_BYTES - 1] = 0x7f;
+        bytes[3_000 * T::NB_BYTES..3_001 * T::NB_BYTES - 1].fill(0xff);
+        let data: Vec<T> = bytes
+            .chunks_exact(T::NB_BYTES)
+            .map(T::from_le_slice)
+            .collect();
+        let chunks = || stream::iter(get_chunks(&bytes, &[3, 0, 5, 1_000, 8]));
+        let result = block_on(chunks().argminmax_bytes_stream::<T, FloatIgnoreNaN>());
+        assert_eq!(result.unwrap().indices(), data.argminmax());
+        let result = block_on(chunks().argminmax_bytes_stream::<T, FloatReturnNaN>());
+        assert_eq!(result.unwrap().indices(), (3_000, 3_000));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[apply(dtypes)]
+    fn test_par_argminmax<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // Many ties across the chunks -> same (first) index as the sequential result
+        let data: Vec<T> = get_par_array();
+        let (min_index, max_index) = data.argminmax();
+        assert_eq!(data.par_argminmax(), (min_index, max_index));
+        assert_eq!(data.as_slice().par_argminmax(), (min_index, max_index));
+        assert_eq!(data.par_argmin(), data.argmin());
+        assert_eq!(data.par_argmax(), data.argmax());
+        // The extremes in a later chunk
+        let data: Vec<T> = data[1..].to_vec();
+        assert_eq!(data.par_argminmax(), data.argminmax());
+        // Small slice (a single chunk)
+        let data: &[T] = &data[..100];
+        assert_eq!(data.par_argminmax(), data.argminmax());
+    }
+
+    #[cfg(all(
+        feature = "rayon",
+        any(feature = "float", feature = "half", feature = "float8")
+    ))]
+    #[apply(dtypes_with_nan)]
+    fn test_par_argminmax_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = get_par_array();
+        // Chunks with only NaNs, followed by chunks with some NaNs
+        data[..300_000].iter_mut().for_each(|v| *v = nan);
+        data[400_000] = nan;
+        data[599_999] = nan;
+        // NaNs are ignored
+        assert_eq!(data.par_argminmax(), data.argminmax());
+        assert_eq!(data.par_argmin(), data.argmin());
+        assert_eq!(data.par_argmax(), data.argmax());
+        // NaNs are returned
+        assert_eq!(data.par_nanargminmax(), (0, 0));
+        assert_eq!(data.par_nanargmin(), 0);
+        assert_eq!(data.par_nanargmax(), 0);
+        let data: &[T] = &data[300_000..];
+        assert_eq!(data.par_nanargminmax(), (100_000, 100_000));
+        assert_eq!(data.par_nanargminmax(), data.nanargminmax());
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_with_values<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<usize> + PartialEq + std::fmt::Debug,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        // Slice
+        let slice: &[T] = &data;
+        let result = slice.argminmax_with_values();
+        assert_eq!(result.indices(), slice.argminmax());
+        assert_eq!(result.min_value, data[0]);
+        assert_eq!(result.max_value, data[max_index - 1]);
+        assert_eq!(slice.minmax(), (data[0], data[max_index - 1]));
+        // Vec
+        assert_eq!(data.argminmax_with_values(), result);
+        assert_eq!(data.minmax(), (data[0], data[max_index - 1]));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_with_values_nan<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<usize> + PartialEq + std::fmt::Debug,
+        T: PartialOrd,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let mut data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let nan = T::from_f64(f64::NAN).unwrap();
+        data[5] = nan;
+        // ArgMinMax ignores the NaN
+        let result = data.argminmax_with_values();
+        assert_eq!(result.indices(), data.argminmax());
+        assert_eq!(result.min_value, data[0]);
+        assert_eq!(result.max_value, data[max_index - 1]);
+        assert_eq!(data.as_slice().argminmax_with_values(), result);
+        // NaNArgMinMax returns the (first) NaN
+        let result = data.nanargminmax_with_values();
+        assert_eq!(result.indices(), (5, 5));
+        assert!(is_nan(result.min_value));
+        assert!(is_nan(result.max_value));
+        let (min_value, max_value) = data.as_slice().nanminmax();
+        assert!(is_nan(min_value));
+        assert!(is_nan(max_value));
+    }
+
+    #[apply(dtypes)]
+    fn test_try_argminmax<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        // Slice
+        let slice: &[T] = &data;
+        assert_eq!(slice.try_argminmax(), Ok((0, max_index - 1)));
+        assert_eq!(slice.try_argmin(), Ok(0));
+        assert_eq!(slice.try_argmax(), Ok(max_index - 1));
+        // Vec
+        assert_eq!(data.try_argminmax(), Ok((0, max_index - 1)));
+        assert_eq!(data.try_argmin(), Ok(0));
+        assert_eq!(data.try_argmax(), Ok(max_index - 1));
+
+        // Empty input
+        let empty: Vec<T> = Vec::new();
+        assert_eq!(
+            empty.as_slice().try_argminmax(),
+            Err(ArgMinMaxError::EmptyInput)
+        );
+        assert_eq!(
+            empty.as_slice().try_argmin(),
+            Err(ArgMinMaxError::EmptyInput)
+        );
+        assert_eq!(
+            empty.as_slice().try_argmax(),
+            Err(ArgMinMaxError::EmptyInput)
+        );
+        assert_eq!(empty.try_argminmax(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_argmin(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_argmax(), Err(ArgMinMaxError::EmptyInput));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_try_argminmax_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let inf = T::from_f64(f64::INFINITY).unwrap();
+        let neg_inf = T::from_f64(f64::NEG_INFINITY).unwrap();
+
+        // Empty input
+        let empty: Vec<T> = Vec::new();
+        assert_eq!(empty.try_nanargminmax(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_nanargmin(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_nanargmax(), Err(ArgMinMaxError::EmptyInput));
+
+        // Only NaNs
+        for n in [1, 7, 64, 1025] {
+            let data: Vec<T> = vec![nan; n];
+            assert_eq!(data.try_argminmax(), Err(ArgMinMaxError::AllNaN));
+            assert_eq!(data.try_argmin(), Err(ArgMinMaxError::AllNaN));
+            assert_eq!(data.try_argmax(), Err(ArgMinMaxError::AllNaN));
+            // NaNArgMinMax returns the index of the first NaN
+            assert_eq!(data.try_nanargminmax(), Ok((0, 0)));
+            assert_eq!(data.try_nanargmin(), Ok(0));
+            assert_eq!(data.try_nanargmax(), Ok(0));
+        }
+
+        // Only NaNs and infinities
+        for n in [7, 64, 1025] {
+            let mut data: Vec<T> = vec![nan; n];
+            data[n - 3] = inf;
+            data[n - 2] = neg_inf;
+            assert_eq!(data.try_argminmax(), Ok((n - 2, n - 3)));
+            assert_eq!(data.try_argmin(), Ok(n - 2));
+            assert_eq!(data.try_argmax(), Ok(n - 3));
+            assert_eq!(data.try_nanargminmax(), Ok((0, 0)));
+        }
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_k<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy
+            + PartialOrd
+            + FromPrimitive
+            + AsPrimitive<usize>
+            + rand::distributions::uniform::SampleUniform,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // Small value range -> many ties
+        let (low, high) = (T::from_u8(0).unwrap(), T::from_u8(100).unwrap());
+        for n in [0, 1, 7, 1024, 5_000] {
+            let data: Vec<T> = utils::get_random_array::<T>(n, low, high);
+            for k in [0, 1, 3, 64, 5_001] {
+                let argmin_k = data.argmin_k(k);
+                let argmax_k = data.argmax_k(k);
+                assert_eq!(argmin_k, get_topk_indices(&data, k, false, false));
+                assert_eq!(argmax_k, get_topk_indices(&data, k, true, false));
+                if n > 0 && k > 0 {
+                    assert_eq!(argmin_k[0], data.argmin());
+                    assert_eq!(argmax_k[0], data.argmax());
+                }
+            }
+        }
+
+        // Monotonic array -> all blocks after the first can be skipped
+        let data: Vec<T> = get_monotonic_array(5_000, 100);
+        assert_eq!(data.argmin_k(3), get_topk_indices(&data, 3, false, false));
+        assert_eq!(data.argmax_k(3), get_topk_indices(&data, 3, true, false));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_k_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + PartialOrd + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let data: Vec<T> = (0..3_000)
+            .map(|i| match i {
+                i if i % 701 == 5 => nan,
+                _ => T::from_usize((i * 7) % 97).unwrap(),
+            })
+            .collect();
+        for k in [0, 1, 3, 10, 3_001] {
+            // NaNs are ignored
+            assert_eq!(data.argmin_k(k), get_topk_indices(&data, k, false, false));
+            assert_eq!(data.argmax_k(k), get_topk_indices(&data, k, true, false));
+            // NaNs are ranked first
+            assert_eq!(data.nanargmin_k(k), get_topk_indices(&data, k, false, true));
+            assert_eq!(data.nanargmax_k(k), get_topk_indices(&data, k, true, true));
+        }
+
+        // Only NaNs
+        let data: Vec<T> = vec![nan; 1025];
+        assert_eq!(data.argmin_k(3), Vec::<usize>::new());
+        assert_eq!(data.argmax_k(3), Vec::<usize>::new());
+        assert_eq!(data.nanargmin_k(3), vec![0, 1, 2]);
+        assert_eq!(data.nanargmax_k(3), vec![0, 1, 2]);
+
+        // An all-NaN block, a +inf block, a block with only NaNs and -inf and a block
+        // with finite values
+        let inf = T::from_f64(f64::INFINITY).unwrap();
+        let neg_inf = T::from_f64(f64::NEG_INFINITY).unwrap();
+        let data: Vec<T> = (0..4_100)
+            .map(|i| match i {
+                0..=1023 => nan,
+                1024..=2047 => inf,
+                2048..=3071 if i % 2 == 0 => nan,
+                2048..=3071 => neg_inf,
+                _ => T::from_usize(i % 97).unwrap(),
+            })
+            .collect();
+        for k in [1, 3, 1_025, 2_050] {
+            assert_eq!(data.argmin_k(k), get_topk_indices(&data, k, false, false));
+            assert_eq!(data.argmax_k(k), get_topk_indices(&data, k, true, false));
+            assert_eq!(data.nanargmin_k(k), get_topk_indices(&data, k, false, true));
+            assert_eq!(data.nanargmax_k(k), get_topk_indices(&data, k, true, true));
+        }
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_with_tie<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + PartialOrd + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        for n in [1, 7, 1_025, ARRAY_LENGTH] {
+            // Cyclic array -> the min and max value occur multiple times
+            let data: Vec<T> = get_monotonic_array(n, max_index);
+            let min_value = data[data.argmin()];
+            let max_value = data[data.argmax()];
+            let last_min = data.iter().rposition(|v| *v == min_value).unwrap();
+            let last_max = data.iter().rposition(|v| *v == max_value).unwrap();
+
+            // First
+            assert_eq!(data.argminmax_with_tie(TiePolicy::First), data.argminmax());
+            assert_eq!(data.argmin_with_tie(TiePolicy::First), data.argmin());
+            assert_eq!(data.argmax_with_tie(TiePolicy::First), data.argmax());
+            assert_eq!(
+                data.argminmax_with_tie(TiePolicy::default()),
+                data.argminmax()
+            );
+            // Last
+            assert_eq!(
+                data.argminmax_with_tie(TiePolicy::Last),
+                (last_min, last_max)
+            );
+            assert_eq!(data.argmin_with_tie(TiePolicy::Last), last_min);
+            assert_eq!(data.argmax_with_tie(TiePolicy::Last), last_max);
+            let slice: &[T] = &data;
+            assert_eq!(
+                slice.argminmax_with_tie(TiePolicy::Last),
+                (last_min, last_max)
+            );
+        }
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_with_tie_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let zero = T::from_f64(0.0).unwrap();
+        let one = T::from_f64(1.0).unwrap();
+
+        for n in [7, 64, 1_025] {
+            let mut data: Vec<T> = (0..n)
+                .map(|i| if i % 2 == 0 { zero } else { one })
+                .collect();
+            let last_even = (n - 1) / 2 * 2;
+            let last_odd = (n - 2) / 2 * 2 + 1;
+            // NaNs are ignored
+            data[3] = nan;
+            data[n - 1] = nan;
+            let last_even = if last_even == n - 1 {
+                last_even - 2
+            } else {
+                last_even
+            };
+            let last_odd = if last_odd == n - 1 {
+                last_odd - 2
+            } else {
+                last_odd
+            };
+            assert_eq!(
+                data.argminmax_with_tie(TiePolicy::Last),
+                (last_even, last_odd)
+            );
+            assert_eq!(data.argmin_with_tie(TiePolicy::Last), last_even);
+            assert_eq!(data.argmax_with_tie(TiePolicy::Last), last_odd);
+            assert_eq!(data.argminmax_with_tie(TiePolicy::First), (0, 1));
+            // The index of the first NaN is returned
+            assert_eq!(data.nanargminmax_with_tie(TiePolicy::Last), (3, 3));
+            assert_eq!(data.nanargmin_with_tie(TiePolicy::Last), 3);
+            assert_eq!(data.nanargmax_with_tie(TiePolicy::Last), 3);
+            assert_eq!(data.nanargminmax_with_tie(TiePolicy::First), (3, 3));
+            // Without NaNs
+            data[3] = one;
+            data[n - 1] = one;
+            assert_eq!(
+                data.nanargminmax_with_tie(TiePolicy::Last),
+                (last_even, n - 1)
+            );
+            assert_eq!(data.nanargmin_with_tie(TiePolicy::Last), last_even);
+            assert_eq!(data.nanargmax_with_tie(TiePolicy::Last), n - 1);
+        }
+    }
+
     #[apply(dtypes)]
     fn test_argminmax_many_random_runs<T>(#[case] min: T, #[case] max: T)
     where
         T: Copy + FromPrimitive + AsPrimitive<usize> + rand::distributions::uniform::SampleUniform,
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
     {
         for _ in 0..NB_RANDOM_RUNS {
             let data: Vec<T> = utils::get_random_array::<T>(RANDOM_ARR_LENGTH, min, max);
@@ -254,13 +1626,16 @@ mod default_test {
 mod ndarray_tests {
     use super::*;
 
-    use ndarray::Array1;
+    use argminmax::ArgMinMaxNd;
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    use argminmax::NaNArgMinMaxNd;
+    use ndarray::{s, Array1, Array2, Array3, ArrayView1, Axis, IxDyn, RemoveAxis};
 
     #[apply(dtypes)]
     fn test_argminmax_ndarray<T>(#[case] _min: T, #[case] max: T)
     where
         T: Copy + FromPrimitive + AsPrimitive<usize>,
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
     {
         // max_index is the max value that can be represented by T
         let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
@@ -322,12 +1697,12 @@ mod ndarray_tests {
         assert_eq!(max, (&mut data_mut.view_mut()).argmax());
     }
 
-    #[cfg(any(feature = "float", feature = "half"))]
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
     #[apply(dtypes_with_nan)]
     fn test_argminmax_ndarray_nan<T>(#[case] _min: T, #[case] max: T)
     where
         T: Copy + FromPrimitive + AsPrimitive<usize>,
-        for<'a> &'a [T]: NaNArgMinMax,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
     {
         // max_index is the max value that can be represented by T
         let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
@@ -360,40 +1735,397 @@ mod ndarray_tests {
         assert_eq!(max, max_index - 1);
         assert_eq!(max, (&data.view()).nanargmax());
 
-        let mut data_mut: Array1<T> = Array1::from(get_monotonic_array(ARRAY_LENGTH, max_index));
-        // --- Array1
-        // Test owned mutable Array1
-        let (min, max) = data_mut.nanargminmax();
-        assert_eq!(min, 0);
-        assert_eq!(min, data_mut.nanargmin());
-        assert_eq!(max, max_index - 1);
-        assert_eq!(max, data_mut.nanargmax());
-        // Test borrowed mutable Array1
-        let (min, max) = (&mut data_mut).nanargminmax();
-        assert_eq!(min, 0);
-        assert_eq!(min, (&mut data_mut).nanargmin());
-        assert_eq!(max, max_index - 1);
-        assert_eq!(max, (&mut data_mut).nanargmax());
-        // --- ArrayView1
-        // Test owned mutable ArrayView1
-        let (min, max) = data_mut.view_mut().nanargminmax();
-        assert_eq!(min, 0);
-        assert_eq!(min, data_mut.view_mut().nanargmin());
-        assert_eq!(max, max_index - 1);
-        assert_eq!(max, data_mut.view_mut().nanargmax());
-        // Test borrowed mutable ArrayView1
-        let (min, max) = (&mut data_mut.view_mut()).nanargminmax();
-        assert_eq!(min, 0);
-        assert_eq!(min, (&mut data_mut.view_mut()).nanargmin());
-        assert_eq!(max, max_index - 1);
-        assert_eq!(max, (&mut data_mut.view_mut()).nanargmax());
+        let mut data_mut: Array1<T> = Array1::from(get_monotonic_array(ARRAY_LENGTH, max_index));
+        // --- Array1
+        // Test owned mutable Array1
+        let (min, max) = data_mut.nanargminmax();
+        assert_eq!(min, 0);
+        assert_eq!(min, data_mut.nanargmin());
+        assert_eq!(max, max_index - 1);
+        assert_eq!(max, data_mut.nanargmax());
+        // Test borrowed mutable Array1
+        let (min, max) = (&mut data_mut).nanargminmax();
+        assert_eq!(min, 0);
+        assert_eq!(min, (&mut data_mut).nanargmin());
+        assert_eq!(max, max_index - 1);
+        assert_eq!(max, (&mut data_mut).nanargmax());
+        // --- ArrayView1
+        // Test owned mutable ArrayView1
+        let (min, max) = data_mut.view_mut().nanargminmax();
+        assert_eq!(min, 0);
+        assert_eq!(min, data_mut.view_mut().nanargmin());
+        assert_eq!(max, max_index - 1);
+        assert_eq!(max, data_mut.view_mut().nanargmax());
+        // Test borrowed mutable ArrayView1
+        let (min, max) = (&mut data_mut.view_mut()).nanargminmax();
+        assert_eq!(min, 0);
+        assert_eq!(min, (&mut data_mut.view_mut()).nanargmin());
+        assert_eq!(max, max_index - 1);
+        assert_eq!(max, (&mut data_mut.view_mut()).nanargmax());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[apply(dtypes)]
+    fn test_par_argminmax_ndarray<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Array1<T> = Array1::from(get_par_array());
+        assert_eq!(data.par_argminmax(), data.argminmax());
+        assert_eq!(data.view().par_argmin(), data.argmin());
+        assert_eq!(data.view().par_argmax(), data.argmax());
+        // Non-contiguous arrays -> sequential implementation
+        let strided = data.slice(s![1..;3]);
+        assert_eq!(strided.par_argminmax(), strided.argminmax());
+        let reversed = data.slice(s![..;-1]);
+        assert_eq!(reversed.par_argminmax(), reversed.argminmax());
+    }
+
+    #[cfg(all(
+        feature = "rayon",
+        any(feature = "float", feature = "half", feature = "float8")
+    ))]
+    #[apply(dtypes_with_nan)]
+    fn test_par_argminmax_ndarray_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+    {
+        let mut data: Array1<T> = Array1::from(get_par_array());
+        data[400_000] = T::from_f64(f64::NAN).unwrap();
+        assert_eq!(data.par_nanargminmax(), (400_000, 400_000));
+        assert_eq!(data.par_nanargmin(), data.nanargmin());
+        assert_eq!(data.view().par_nanargmax(), data.nanargmax());
+        let reversed = data.slice(s![..;-1]);
+        assert_eq!(reversed.par_nanargminmax(), (199_999, 199_999));
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_with_values_ndarray<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<usize> + PartialEq + std::fmt::Debug,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Array1<T> = Array1::from(get_monotonic_array(ARRAY_LENGTH, max_index));
+        let result = data.argminmax_with_values();
+        assert_eq!(result.indices(), data.argminmax());
+        assert_eq!(result.values(), (data[0], data[max_index - 1]));
+        assert_eq!(data.view().argminmax_with_values(), result);
+        assert_eq!(data.minmax(), (data[0], data[max_index - 1]));
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_k_ndarray<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let arr: Array1<T> = Array1::from(data.clone());
+        assert_eq!(arr.argmin_k(5), data.argmin_k(5));
+        assert_eq!(arr.argmax_k(5), data.argmax_k(5));
+        assert_eq!(arr.view().argmax_k(5), data.argmax_k(5));
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_with_tie_ndarray<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let arr: Array1<T> = Array1::from(data.clone());
+        for tie in [TiePolicy::First, TiePolicy::Last] {
+            assert_eq!(arr.argminmax_with_tie(tie), data.argminmax_with_tie(tie));
+            assert_eq!(arr.view().argmin_with_tie(tie), data.argmin_with_tie(tie));
+            assert_eq!(arr.argmax_with_tie(tie), data.argmax_with_tie(tie));
+        }
+    }
+
+    #[apply(dtypes)]
+    fn test_try_argminmax_ndarray<T>(#[case] _min: T, #[case] max: T)
+    where
+        T: Copy + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Array1<T> = Array1::from(get_monotonic_array(ARRAY_LENGTH, max_index));
+        assert_eq!(data.try_argminmax(), Ok((0, max_index - 1)));
+        assert_eq!(data.view().try_argmin(), Ok(0));
+        assert_eq!(data.view().try_argmax(), Ok(max_index - 1));
+
+        let empty: Array1<T> = Array1::from(Vec::new());
+        assert_eq!(empty.try_argminmax(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.view().try_argmin(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.view().try_argmax(), Err(ArgMinMaxError::EmptyInput));
+    }
+
+    /// Check the ndarray (view) against its (logical order) copy
+    fn check_ndarray_view<T>(view: ArrayView1<T>)
+    where
+        T: Copy + std::fmt::Debug + PartialEq,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Vec<T> = view.to_vec();
+        assert_eq!(view.argminmax(), data.argminmax());
+        assert_eq!(view.argmin(), data.argmin());
+        assert_eq!(view.argmax(), data.argmax());
+        assert_eq!(view.argminmax_with_values(), data.argminmax_with_values());
+        assert_eq!(view.try_argminmax(), data.try_argminmax());
+        assert_eq!(view.try_argmin(), data.try_argmin());
+        assert_eq!(view.try_argmax(), data.try_argmax());
+        assert_eq!(view.argmin_k(5), data.argmin_k(5));
+        assert_eq!(view.argmax_k(5), data.argmax_k(5));
+        for tie in [TiePolicy::First, TiePolicy::Last] {
+            assert_eq!(view.argminmax_with_tie(tie), data.argminmax_with_tie(tie));
+            assert_eq!(view.argmin_with_tie(tie), data.argmin_with_tie(tie));
+            assert_eq!(view.argmax_with_tie(tie), data.argmax_with_tie(tie));
+        }
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_strided_ndarray<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + std::fmt::Debug + PartialEq,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // Many ties -> the indices must be in the logical order of the view
+        let data: Vec<T> = (0..3 * 2_500)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        let arr: Array2<T> = Array2::from_shape_vec((2_500, 3), data).unwrap();
+        // Column (stride 3)
+        check_ndarray_view(arr.column(1));
+        // Row (contiguous)
+        check_ndarray_view(arr.row(1));
+        let flat = arr.into_shape(3 * 2_500).unwrap();
+        check_ndarray_view(flat.slice(s![..;2]));
+        check_ndarray_view(flat.slice(s![..;-1]));
+        check_ndarray_view(flat.slice(s![..;-3]));
+        check_ndarray_view(flat.slice(s![5..;7]));
+        check_ndarray_view(flat.slice(s![..3;-2]));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_strided_ndarray_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + FromPrimitive + std::fmt::Debug,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        data[1_500] = nan;
+        data[3_001] = nan;
+        data[4_200] = nan;
+        let arr: Array1<T> = Array1::from(data);
+        for view in [
+            arr.slice(s![..;2]),
+            arr.slice(s![..;-1]),
+            arr.slice(s![..;-3]),
+            arr.slice(s![1..;3]),
+        ] {
+            let data: Vec<T> = view.to_vec();
+            assert_eq!(view.argminmax(), data.argminmax());
+            assert_eq!(view.try_argminmax(), data.try_argminmax());
+            assert_eq!(view.nanargminmax(), data.nanargminmax());
+            assert_eq!(view.nanargmin(), data.nanargmin());
+            assert_eq!(view.nanargmax(), data.nanargmax());
+            assert_eq!(view.try_nanargminmax(), data.try_nanargminmax());
+            assert_eq!(view.nanargmin_k(5), data.nanargmin_k(5));
+            for tie in [TiePolicy::First, TiePolicy::Last] {
+                assert_eq!(view.argminmax_with_tie(tie), data.argminmax_with_tie(tie));
+                assert_eq!(
+                    view.nanargminmax_with_tie(tie),
+                    data.nanargminmax_with_tie(tie)
+                );
+            }
+        }
+
+        // Only NaNs
+        let arr: Array1<T> = Array1::from(vec![nan; 3_000]);
+        assert_eq!(
+            arr.slice(s![..;2]).try_argminmax(),
+            Err(ArgMinMaxError::AllNaN)
+        );
+        assert_eq!(
+            arr.slice(s![..;-1]).try_argmin(),
+            Err(ArgMinMaxError::AllNaN)
+        );
+        assert_eq!(arr.slice(s![..;-2]).try_nanargminmax(), Ok((0, 0)));
+        assert_eq!(arr.slice(s![..;2]).nanargmax(), 0);
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_axis_ndarray<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + PartialOrd + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        let data: Vec<T> = (0..4 * 5 * 1_100)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        let arr: Array3<T> = Array3::from_shape_vec((4, 5, 1_100), data).unwrap();
+        // Row-major and column-major layout
+        for arr in [arr.view(), arr.view().reversed_axes()] {
+            for axis in 0..3 {
+                let (min_indices, max_indices) = arr.argminmax_axis(Axis(axis));
+                assert_eq!(min_indices, arr.argmin_axis(Axis(axis)));
+                assert_eq!(max_indices, arr.argmax_axis(Axis(axis)));
+                let shape = arr.raw_dim().remove_axis(Axis(axis));
+                assert_eq!(min_indices.raw_dim(), shape);
+                for (lane, (&min_index, &max_index)) in arr
+                    .lanes(Axis(axis))
+                    .into_iter()
+                    .zip(min_indices.iter().zip(max_indices.iter()))
+                {
+                    assert_eq!((min_index, max_index), lane.to_vec().argminmax());
+                }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_axis_ndarray_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + PartialOrd + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut arr: Array2<T> = Array2::from_shape_fn((3, 1_100), |(i, j)| {
+            T::from_usize((i * j + j / 3) % 7).unwrap()
+        });
+        arr[[0, 5]] = nan;
+        arr[[0, 700]] = nan;
+        arr[[2, 1_000]] = nan;
+
+        // NaNs are ignored
+        let (min_indices, max_indices) = arr.argminmax_axis(Axis(1));
+        for (i, row) in arr.rows().into_iter().enumerate() {
+            assert_eq!((min_indices[i], max_indices[i]), row.to_vec().argminmax());
+        }
+        // NaNs are returned (first NaN of each lane)
+        let (min_indices, max_indices) = arr.nanargminmax_axis(Axis(1));
+        assert_eq!(min_indices, arr.nanargmin_axis(Axis(1)));
+        assert_eq!(max_indices, arr.nanargmax_axis(Axis(1)));
+        assert_eq!(min_indices[0], 5);
+        assert_eq!(min_indices[2], 1_000);
+        assert_eq!(max_indices[2], 1_000);
+        let (min_indices, _) = arr.nanargminmax_axis(Axis(0));
+        assert_eq!(min_indices[5], 0);
+        assert_eq!(min_indices[1_000], 2);
+
+        // Strided lanes (incl. an all-NaN lane) -> same result as the gathered lanes
+        arr.column_mut(3).fill(nan);
+        let (min_indices, max_indices) = arr.argminmax_axis(Axis(0));
+        let (nan_min_indices, nan_max_indices) = arr.nanargminmax_axis(Axis(0));
+        assert_eq!(min_indices, arr.argmin_axis(Axis(0)));
+        assert_eq!(nan_max_indices, arr.nanargmax_axis(Axis(0)));
+        for (j, lane) in arr.columns().into_iter().enumerate() {
+            let lane = lane.to_vec();
+            assert_eq!((min_indices[j], max_indices[j]), lane.argminmax());
+            assert_eq!(
+                (nan_min_indices[j], nan_max_indices[j]),
+                lane.nanargminmax()
+            );
+        }
+    }
+
+    #[apply(dtypes)]
+    fn test_argminmax_nd_ndarray<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + PartialOrd + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+    {
+        // Unique min and max -> same result for every layout
+        let mut arr: Array2<T> = Array2::from_elem((30, 70), T::from_usize(3).unwrap());
+        arr[[17, 3]] = T::from_usize(1).unwrap();
+        arr[[2, 61]] = T::from_usize(5).unwrap();
+        let arr_f: Array2<T> = arr.t().as_standard_layout().reversed_axes().to_owned();
+        assert!(arr_f.t().is_standard_layout());
+        for arr in [arr.view(), arr_f.view()] {
+            assert_eq!(arr.argminmax_nd(), ((17, 3), (2, 61)));
+            assert_eq!(arr.argmin_nd(), (17, 3));
+            assert_eq!(arr.argmax_nd(), (2, 61));
+        }
+        assert_eq!(arr.t().argminmax_nd(), ((3, 17), (61, 2)));
+        let arr_d = arr.clone().into_dyn();
+        assert_eq!(arr_d.argmin_nd(), IxDyn(&[17, 3]));
+        assert_eq!(arr_d.argmax_nd(), IxDyn(&[2, 61]));
+
+        // Ties -> first occurrence in row-major order for any layout
+        let mut arr_f = arr_f;
+        for arr in [&mut arr, &mut arr_f] {
+            arr[[20, 2]] = T::from_usize(1).unwrap();
+            arr[[9, 50]] = T::from_usize(5).unwrap();
+            assert_eq!(arr.argminmax_nd(), ((17, 3), (2, 61)));
+        }
+        assert_eq!(arr.t().argminmax_nd(), ((2, 20), (50, 9)));
+        assert_eq!(arr.t().argmin_nd(), (2, 20));
+        assert_eq!(arr.t().argmax_nd(), (50, 9));
+
+        // Arbitrary layout -> first occurrence in row-major order
+        let data: Vec<T> = (0..4 * 5 * 300)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        let arr: Array3<T> = Array3::from_shape_vec((4, 5, 300), data).unwrap();
+        let view = arr.slice(s![..;-1, 1.., ..;3]);
+        let (ncols, nrows) = (view.shape()[2], view.shape()[1]);
+        let unravel = |i: usize| (i / (nrows * ncols), i / ncols % nrows, i % ncols);
+        let (min_index, max_index) = view.iter().copied().collect::<Vec<T>>().argminmax();
+        assert_eq!(
+            view.argminmax_nd(),
+            (unravel(min_index), unravel(max_index))
+        );
+        assert_eq!(view.argmin_nd(), unravel(min_index));
+        assert_eq!(view.argmax_nd(), unravel(max_index));
+    }
+
+    #[cfg(any(feature = "float", feature = "half", feature = "float8"))]
+    #[apply(dtypes_with_nan)]
+    fn test_argminmax_nd_ndarray_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        T: Copy + PartialOrd + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let mut arr: Array2<T> = Array2::from_elem((30, 70), T::from_usize(3).unwrap());
+        arr[[17, 3]] = T::from_usize(1).unwrap();
+        arr[[2, 61]] = T::from_usize(5).unwrap();
+        arr[[20, 1]] = nan;
+        arr[[25, 0]] = nan;
+        // NaNs are ignored
+        assert_eq!(arr.argminmax_nd(), ((17, 3), (2, 61)));
+        // NaNs are returned
+        assert_eq!(arr.nanargminmax_nd(), ((20, 1), (20, 1)));
+        assert_eq!(arr.nanargmin_nd(), (20, 1));
+        assert_eq!(arr.nanargmax_nd(), (20, 1));
+        // Fortran layout -> first NaN in row-major order (i.e., (25, 0) of the original)
+        assert_eq!(arr.t().nanargminmax_nd(), ((0, 25), (0, 25)));
+        // Arbitrary layout -> first NaN in row-major order
+        assert_eq!(arr.slice(s![.., ..;2]).nanargmax_nd(), (25, 0));
     }
 
     #[apply(dtypes)]
     fn test_argminmax_many_random_runs_ndarray<T>(#[case] min: T, #[case] max: T)
     where
         T: Copy + FromPrimitive + AsPrimitive<usize> + rand::distributions::uniform::SampleUniform,
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
     {
         for _ in 0..NB_RANDOM_RUNS {
             let data: Vec<T> = utils::get_random_array::<T>(RANDOM_ARR_LENGTH, min, max);
@@ -426,8 +2158,9 @@ mod ndarray_tests {
 mod arrow_tests {
     use super::*;
 
-    use arrow::array::PrimitiveArray;
+    use arrow::array::{ArrayRef, PrimitiveArray};
     use arrow::datatypes::*;
+    use std::sync::Arc;
 
     #[cfg(feature = "float")]
     #[template]
@@ -450,6 +2183,7 @@ mod arrow_tests {
     #[case::int16(Int16Type {}, i16::MIN, i16::MAX)]
     #[case::int32(Int32Type {}, i32::MIN, i32::MAX)]
     #[case::int64(Int64Type {}, i64::MIN, i64::MAX)]
+    #[case::decimal128(Decimal128Type {}, i128::MIN, i128::MAX)]
     #[case::uint8(UInt8Type {}, u8::MIN, u8::MAX)]
     #[case::uint16(UInt16Type {}, u16::MIN, u16::MAX)]
     #[case::uint32(UInt32Type {}, u32::MIN, u32::MAX)]
@@ -467,6 +2201,7 @@ mod arrow_tests {
     #[case::int16(Int16Type {}, i16::MIN, i16::MAX)]
     #[case::int32(Int32Type {}, i32::MIN, i32::MAX)]
     #[case::int64(Int64Type {}, i64::MIN, i64::MAX)]
+    #[case::decimal128(Decimal128Type {}, i128::MIN, i128::MAX)]
     #[case::uint8(UInt8Type {}, u8::MIN, u8::MAX)]
     #[case::uint16(UInt16Type {}, u16::MIN, u16::MAX)]
     #[case::uint32(UInt32Type {}, u32::MIN, u32::MAX)]
@@ -485,7 +2220,7 @@ mod arrow_tests {
         #[case] max: T,
     ) where
         T: Copy + FromPrimitive + AsPrimitive<usize>,
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
         ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
         PrimitiveArray<ArrowDataType>: From<Vec<T>>,
     {
@@ -516,7 +2251,7 @@ mod arrow_tests {
         #[case] max: T,
     ) where
         T: Copy + FromPrimitive + AsPrimitive<usize>,
-        for<'a> &'a [T]: NaNArgMinMax,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
         ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
         PrimitiveArray<ArrowDataType>: From<Vec<T>>,
     {
@@ -539,6 +2274,336 @@ mod arrow_tests {
         assert_eq!(max, (&data).nanargmax());
     }
 
+    #[apply(dtypes_arrow)]
+    fn test_argminmax_with_values_arrow<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] max: T,
+    ) where
+        T: Copy + FromPrimitive + AsPrimitive<usize> + PartialEq + std::fmt::Debug,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<T>>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let arrow: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(data.clone());
+        let result = arrow.argminmax_with_values();
+        assert_eq!(result, data.argminmax_with_values());
+        assert_eq!(result.values(), (data[0], data[max_index - 1]));
+        assert_eq!(arrow.minmax(), (data[0], data[max_index - 1]));
+    }
+
+    #[apply(dtypes_arrow)]
+    fn test_argminmax_k_arrow<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] max: T,
+    ) where
+        T: Copy + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<T>>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let arrow: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(data.clone());
+        assert_eq!(arrow.argmin_k(5), data.argmin_k(5));
+        assert_eq!(arrow.argmax_k(5), data.argmax_k(5));
+    }
+
+    #[apply(dtypes_arrow)]
+    fn test_argminmax_with_tie_arrow<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] max: T,
+    ) where
+        T: Copy + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<T>>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let arrow: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(data.clone());
+        for tie in [TiePolicy::First, TiePolicy::Last] {
+            assert_eq!(arrow.argminmax_with_tie(tie), data.argminmax_with_tie(tie));
+        }
+    }
+
+    #[apply(dtypes_arrow)]
+    fn test_try_argminmax_arrow<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] max: T,
+    ) where
+        T: Copy + FromPrimitive + AsPrimitive<usize>,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<T>>,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: PrimitiveArray<ArrowDataType> =
+            PrimitiveArray::from(get_monotonic_array(ARRAY_LENGTH, max_index));
+        assert_eq!(data.try_argminmax(), Ok((0, max_index - 1)));
+        assert_eq!(data.try_argmin(), Ok(0));
+        assert_eq!(data.try_argmax(), Ok(max_index - 1));
+
+        let empty: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(Vec::<T>::new());
+        assert_eq!(empty.try_argminmax(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_argmin(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_argmax(), Err(ArgMinMaxError::EmptyInput));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[apply(dtypes_arrow)]
+    fn test_par_argminmax_arrow<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] _max: T,
+    ) where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<T>>,
+    {
+        let data: Vec<T> = get_par_array();
+        let arr: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(data.clone());
+        assert_eq!(arr.par_argminmax(), data.argminmax());
+        assert_eq!(arr.par_argmin(), data.argmin());
+        assert_eq!(arr.par_argmax(), data.argmax());
+    }
+
+    #[cfg(all(feature = "rayon", feature = "float"))]
+    #[apply(dtypes_arrow_with_nan)]
+    fn test_par_argminmax_arrow_nan<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] _max: T,
+    ) where
+        T: Copy + FromPrimitive + Send + Sync,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<T>>,
+    {
+        let mut data: Vec<T> = get_par_array();
+        data[400_000] = T::from_f64(f64::NAN).unwrap();
+        let arr: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(data);
+        assert_eq!(arr.par_nanargminmax(), (400_000, 400_000));
+        assert_eq!(arr.par_nanargmin(), 400_000);
+        assert_eq!(arr.par_nanargmax(), 400_000);
+    }
+
+    #[apply(dtypes_arrow)]
+    fn test_argminmax_nullable_arrow<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] _max: T,
+    ) where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<Option<T>>> + From<Vec<T>>,
+    {
+        // The (physical) values of the null slots are 0 -> must be skipped
+        // Short (scalar pass) and long (kernel per run) valid runs
+        for null_every in [7, 700] {
+            let data: PrimitiveArray<ArrowDataType> =
+                PrimitiveArray::from(get_nullable_array(null_every));
+            assert_eq!(data.argminmax_nullable(), Some((1_500, 2_500)));
+            assert_eq!(data.argmin_nullable(), Some(1_500));
+            assert_eq!(data.argmax_nullable(), Some(2_500));
+            // Sliced array (offset in the validity bitmap)
+            let sliced = data.slice(1_000, 3_000);
+            assert_eq!(sliced.argminmax_nullable(), Some((500, 1_500)));
+        }
+        // Without nulls
+        let data: PrimitiveArray<ArrowDataType> =
+            PrimitiveArray::from(vec![T::from_usize(2).unwrap(), T::from_usize(1).unwrap()]);
+        assert_eq!(data.argminmax_nullable(), Some((1, 0)));
+        // Only nulls and empty
+        let data: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(vec![None; 100]);
+        assert_eq!(data.argminmax_nullable(), None);
+        assert_eq!(data.argmin_nullable(), None);
+        let empty: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(Vec::<T>::new());
+        assert_eq!(empty.argmax_nullable(), None);
+    }
+
+    #[cfg(feature = "float")]
+    #[apply(dtypes_arrow_with_nan)]
+    fn test_argminmax_nullable_arrow_nan<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] _max: T,
+    ) where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<Option<T>>>,
+    {
+        // Short (scalar pass) and long (kernel per run) valid runs
+        for null_every in [7, 700] {
+            let mut data: Vec<Option<T>> = get_nullable_array(null_every);
+            data[2_000] = T::from_f64(f64::NAN);
+            data[2_600] = T::from_f64(f64::NAN);
+            let data: PrimitiveArray<ArrowDataType> = PrimitiveArray::from(data);
+            // NaNs are ignored
+            assert_eq!(data.argminmax_nullable(), Some((1_500, 2_500)));
+            // NaNs are returned
+            assert_eq!(data.nanargminmax_nullable(), Some((2_000, 2_000)));
+            assert_eq!(data.nanargmin_nullable(), Some(2_000));
+            assert_eq!(data.nanargmax_nullable(), Some(2_000));
+        }
+    }
+
+    #[apply(dtypes_arrow)]
+    fn test_argminmax_chunked_arrow<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] _max: T,
+    ) where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<T>> + From<Vec<Option<T>>>,
+    {
+        // Many ties -> earlier chunks win
+        let data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        let bounds = [0, 0, 1_000, 1_001, 1_001, 3_500, 5_000];
+        let chunks: Vec<PrimitiveArray<ArrowDataType>> = bounds
+            .windows(2)
+            .map(|w| &data[w[0]..w[1]])
+            .map(|chunk| PrimitiveArray::<ArrowDataType>::from(chunk.to_vec()))
+            .collect();
+        let to_chunked_index = |index: usize| {
+            let chunk_index = bounds[1..].iter().position(|&end| index < end).unwrap();
+            ChunkedIndex {
+                chunk_index,
+                offset: index - bounds[chunk_index],
+                index,
+            }
+        };
+        let (min_index, max_index) = data.argminmax();
+        assert_eq!(
+            chunks.argminmax_chunked(),
+            Some((to_chunked_index(min_index), to_chunked_index(max_index)))
+        );
+        assert_eq!(chunks.argmin_chunked(), Some(to_chunked_index(min_index)));
+        assert_eq!(chunks.argmax_chunked(), Some(to_chunked_index(max_index)));
+        // Leading empty chunk -> the flat index equals the offset within chunk 1
+        let min_index = chunks[3..].argmin_chunked().unwrap();
+        assert_eq!(min_index.chunk_index, 1);
+        assert_eq!(min_index.index, min_index.offset);
+        // No chunks -> None
+        assert_eq!(chunks[..0].argminmax_chunked(), None);
+
+        // Null slots are skipped, chunks with only nulls -> None
+        let chunks: Vec<PrimitiveArray<ArrowDataType>> = vec![
+            PrimitiveArray::from(vec![None; 3]),
+            PrimitiveArray::from(get_nullable_array(7)),
+        ];
+        let (min_index, max_index) = chunks.argminmax_chunked().unwrap();
+        assert_eq!((min_index.chunk_index, min_index.offset), (1, 1_500));
+        assert_eq!((max_index.index, max_index.offset), (2_503, 2_500));
+        assert_eq!(chunks[..1].argminmax_chunked(), None);
+        // ArrayRef chunks -> downcast to the PrimitiveArray of their data type
+        let array_refs: Vec<ArrayRef> = chunks
+            .iter()
+            .map(|chunk| Arc::new(chunk.clone()) as ArrayRef)
+            .collect();
+        assert_eq!(array_refs.argminmax_chunked(), chunks.argminmax_chunked());
+        assert_eq!(array_refs.argmin_chunked(), Some(min_index));
+        assert_eq!(array_refs[..1].argmax_chunked(), None);
+        assert_eq!(array_refs[..0].argmax_chunked(), None);
+        // Unsupported or mixed data types -> None
+        let unsupported: Vec<ArrayRef> = vec![
+            Arc::new(arrow::array::Date32Array::from(vec![2, 1])),
+            Arc::new(arrow::array::StringArray::from(vec!["a", "b"])),
+        ];
+        assert_eq!(unsupported[..1].argminmax_chunked(), None);
+        assert_eq!(unsupported[1..].argmin_chunked(), None);
+        for other in unsupported {
+            let mut mixed = array_refs.clone();
+            mixed.push(other);
+            assert_eq!(mixed.argminmax_chunked(), None);
+            assert_eq!(mixed.argmax_chunked(), None);
+        }
+    }
+
+    #[cfg(feature = "float")]
+    #[apply(dtypes_arrow_with_nan)]
+    fn test_argminmax_chunked_arrow_nan<T, ArrowDataType>(
+        #[case] _dtype: ArrowDataType, // used to infer the arrow data type
+        #[case] _min: T,
+        #[case] _max: T,
+    ) where
+        T: Copy + FromPrimitive,
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+        ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
+        PrimitiveArray<ArrowDataType>: From<Vec<T>>,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let one = T::from_usize(1).unwrap();
+        let chunks: Vec<PrimitiveArray<ArrowDataType>> = [
+            vec![nan, nan],
+            vec![],
+            vec![one, T::from_usize(2).unwrap(), nan],
+            vec![T::from_usize(0).unwrap(), nan],
+        ]
+        .iter()
+        .map(|chunk| PrimitiveArray::<ArrowDataType>::from(chunk.to_vec()))
+        .collect();
+        // NaNs are ignored
+        let (min_index, max_index) = chunks.argminmax_chunked().unwrap();
+        assert_eq!(
+            (min_index.chunk_index, min_index.offset, min_index.index),
+            (3, 0, 5)
+        );
+        assert_eq!(
+            (max_index.chunk_index, max_index.offset, max_index.index),
+            (2, 1, 3)
+        );
+        // NaNs are returned
+        let first_nan = ChunkedIndex {
+            chunk_index: 0,
+            offset: 0,
+            index: 0,
+        };
+        assert_eq!(chunks.nanargminmax_chunked(), Some((first_nan, first_nan)));
+        // Only NaNs (and empty chunks) -> position of the first value when ignoring NaNs
+        assert_eq!(
+            chunks[..2].argminmax_chunked(),
+            Some((first_nan, first_nan))
+        );
+        // ArrayRef chunks -> downcast to the PrimitiveArray of their data type
+        let array_refs: Vec<ArrayRef> = chunks
+            .iter()
+            .map(|chunk| Arc::new(chunk.clone()) as ArrayRef)
+            .collect();
+        assert_eq!(
+            array_refs.nanargminmax_chunked(),
+            Some((first_nan, first_nan))
+        );
+        assert_eq!(array_refs.argminmax_chunked(), chunks.argminmax_chunked());
+        // Mixed data types -> None
+        let mut mixed = array_refs.clone();
+        mixed.push(Arc::new(arrow::array::Int32Array::from(vec![1, 2])));
+        assert_eq!(mixed.nanargminmax_chunked(), None);
+        let chunks = &chunks[1..];
+        assert_eq!(chunks.nanargmin_chunked().unwrap().index, 2);
+        assert_eq!(chunks.nanargmax_chunked().unwrap().chunk_index, 1);
+    }
+
     #[apply(dtypes_arrow)]
     fn test_argminmax_many_random_runs_arrow<T, ArrowDataType>(
         #[case] _dtype: ArrowDataType, // used to infer the arrow data type
@@ -546,7 +2611,7 @@ mod arrow_tests {
         #[case] max: T,
     ) where
         T: Copy + FromPrimitive + AsPrimitive<usize> + rand::distributions::uniform::SampleUniform,
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
         ArrowDataType: ArrowPrimitiveType<Native = T> + ArrowNumericType,
         PrimitiveArray<ArrowDataType>: From<Vec<T>>,
     {
@@ -592,10 +2657,41 @@ mod arrow2_tests {
     #[case::float64(f64::MIN, f64::MAX)]
     fn dtypes_with_nan_arrow2<T>(#[case] min: T, #[case] max: T) {}
 
-    #[apply(dtypes)]
+    // arrow2 has no u128 NativeType
+    #[cfg(feature = "float")]
+    #[template]
+    #[rstest]
+    #[case::float32(f32::MIN, f32::MAX)]
+    #[case::float64(f64::MIN, f64::MAX)]
+    #[case::int8(i8::MIN, i8::MAX)]
+    #[case::int16(i16::MIN, i16::MAX)]
+    #[case::int32(i32::MIN, i32::MAX)]
+    #[case::int64(i64::MIN, i64::MAX)]
+    #[case::int128(i128::MIN, i128::MAX)]
+    #[case::uint8(u8::MIN, u8::MAX)]
+    #[case::uint16(u16::MIN, u16::MAX)]
+    #[case::uint32(u32::MIN, u32::MAX)]
+    #[case::uint64(u64::MIN, u64::MAX)]
+    fn dtypes_arrow2<T>(#[case] min: T, #[case] max: T) {}
+
+    #[cfg(not(feature = "float"))]
+    #[template]
+    #[rstest]
+    #[case::int8(i8::MIN, i8::MAX)]
+    #[case::int16(i16::MIN, i16::MAX)]
+    #[case::int32(i32::MIN, i32::MAX)]
+    #[case::int64(i64::MIN, i64::MAX)]
+    #[case::int128(i128::MIN, i128::MAX)]
+    #[case::uint8(u8::MIN, u8::MAX)]
+    #[case::uint16(u16::MIN, u16::MAX)]
+    #[case::uint32(u32::MIN, u32::MAX)]
+    #[case::uint64(u64::MIN, u64::MAX)]
+    fn dtypes_arrow2<T>(#[case] min: T, #[case] max: T) {}
+
+    #[apply(dtypes_arrow2)]
     fn test_argminmax_arrow2<T>(#[case] _min: T, #[case] max: T)
     where
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
         T: Copy + FromPrimitive + AsPrimitive<usize> + NativeType,
     {
         // max_index is the max value that can be represented by T
@@ -621,7 +2717,7 @@ mod arrow2_tests {
     #[apply(dtypes_with_nan_arrow2)]
     fn test_argminmax_arrow2_nan<T>(#[case] _min: T, #[case] max: T)
     where
-        for<'a> &'a [T]: NaNArgMinMax,
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
         T: Copy + FromPrimitive + AsPrimitive<usize> + NativeType,
     {
         // max_index is the max value that can be represented by T
@@ -643,10 +2739,253 @@ mod arrow2_tests {
         assert_eq!(max, (&data).nanargmax());
     }
 
-    #[apply(dtypes)]
+    #[cfg(feature = "rayon")]
+    #[apply(dtypes_arrow2)]
+    fn test_par_argminmax_arrow2<T>(#[case] _min: T, #[case] _max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + NativeType,
+    {
+        let data: Vec<T> = get_par_array();
+        let arr: PrimitiveArray<T> = PrimitiveArray::from_vec(data.clone());
+        assert_eq!(arr.par_argminmax(), data.argminmax());
+        assert_eq!(arr.par_argmin(), data.argmin());
+        assert_eq!(arr.par_argmax(), data.argmax());
+    }
+
+    #[cfg(all(feature = "rayon", feature = "float"))]
+    #[apply(dtypes_with_nan_arrow2)]
+    fn test_par_argminmax_arrow2_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        for<'a> &'a [T]: NaNArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + NativeType,
+    {
+        let mut data: Vec<T> = get_par_array();
+        data[400_000] = T::from_f64(f64::NAN).unwrap();
+        let arr: PrimitiveArray<T> = PrimitiveArray::from_vec(data);
+        assert_eq!(arr.par_nanargminmax(), (400_000, 400_000));
+        assert_eq!(arr.par_nanargmin(), 400_000);
+        assert_eq!(arr.par_nanargmax(), 400_000);
+    }
+
+    #[apply(dtypes_arrow2)]
+    fn test_argminmax_nullable_arrow2<T>(#[case] _min: T, #[case] _max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + NativeType + PartialOrd,
+    {
+        // The (physical) values of the null slots are 0 -> must be skipped
+        // Short (scalar pass) and long (kernel per run) valid runs
+        for null_every in [7, 700] {
+            let data: PrimitiveArray<T> = PrimitiveArray::from(get_nullable_array(null_every));
+            assert_eq!(data.argminmax_nullable(), Some((1_500, 2_500)));
+            assert_eq!(data.argmin_nullable(), Some(1_500));
+            assert_eq!(data.argmax_nullable(), Some(2_500));
+            // Sliced array (offset in the validity bitmap)
+            let sliced = data.sliced(1_000, 3_000);
+            assert_eq!(sliced.argminmax_nullable(), Some((500, 1_500)));
+        }
+        // Without nulls
+        let data: PrimitiveArray<T> =
+            PrimitiveArray::from_vec(vec![T::from_usize(2).unwrap(), T::from_usize(1).unwrap()]);
+        assert_eq!(data.argminmax_nullable(), Some((1, 0)));
+        // Only nulls and empty
+        let data: PrimitiveArray<T> = PrimitiveArray::from(vec![None; 100]);
+        assert_eq!(data.argminmax_nullable(), None);
+        assert_eq!(data.argmin_nullable(), None);
+        let empty: PrimitiveArray<T> = PrimitiveArray::from_vec(Vec::new());
+        assert_eq!(empty.argmax_nullable(), None);
+    }
+
+    #[cfg(feature = "float")]
+    #[apply(dtypes_with_nan_arrow2)]
+    fn test_argminmax_nullable_arrow2_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + NativeType + PartialOrd,
+    {
+        // Short (scalar pass) and long (kernel per run) valid runs
+        for null_every in [7, 700] {
+            let mut data: Vec<Option<T>> = get_nullable_array(null_every);
+            data[2_000] = T::from_f64(f64::NAN);
+            data[2_600] = T::from_f64(f64::NAN);
+            let data: PrimitiveArray<T> = PrimitiveArray::from(data);
+            // NaNs are ignored
+            assert_eq!(data.argminmax_nullable(), Some((1_500, 2_500)));
+            // NaNs are returned
+            assert_eq!(data.nanargminmax_nullable(), Some((2_000, 2_000)));
+            assert_eq!(data.nanargmin_nullable(), Some(2_000));
+            assert_eq!(data.nanargmax_nullable(), Some(2_000));
+        }
+    }
+
+    #[apply(dtypes_arrow2)]
+    fn test_argminmax_chunked_arrow2<T>(#[case] _min: T, #[case] _max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + NativeType + PartialOrd,
+    {
+        // Many ties -> earlier chunks win
+        let data: Vec<T> = (0..5_000)
+            .map(|i| T::from_usize((i * i + i / 3) % 7).unwrap())
+            .collect();
+        let bounds = [0, 0, 1_000, 1_001, 1_001, 3_500, 5_000];
+        let chunks: Vec<PrimitiveArray<T>> = bounds
+            .windows(2)
+            .map(|w| &data[w[0]..w[1]])
+            .map(|chunk| PrimitiveArray::<T>::from_slice(chunk))
+            .collect();
+        let to_chunked_index = |index: usize| {
+            let chunk_index = bounds[1..].iter().position(|&end| index < end).unwrap();
+            ChunkedIndex {
+                chunk_index,
+                offset: index - bounds[chunk_index],
+                index,
+            }
+        };
+        let (min_index, max_index) = data.argminmax();
+        assert_eq!(
+            chunks.argminmax_chunked(),
+            Some((to_chunked_index(min_index), to_chunked_index(max_index)))
+        );
+        assert_eq!(chunks.argmin_chunked(), Some(to_chunked_index(min_index)));
+        assert_eq!(chunks.argmax_chunked(), Some(to_chunked_index(max_index)));
+        // Leading empty chunk -> the flat index equals the offset within chunk 1
+        let min_index = chunks[3..].argmin_chunked().unwrap();
+        assert_eq!(min_index.chunk_index, 1);
+        assert_eq!(min_index.index, min_index.offset);
+        // No chunks -> None
+        assert_eq!(chunks[..0].argminmax_chunked(), None);
+
+        // Null slots are skipped, chunks with only nulls -> None
+        let chunks: Vec<PrimitiveArray<T>> = vec![
+            PrimitiveArray::from(vec![None; 3]),
+            PrimitiveArray::from(get_nullable_array(7)),
+        ];
+        let (min_index, max_index) = chunks.argminmax_chunked().unwrap();
+        assert_eq!((min_index.chunk_index, min_index.offset), (1, 1_500));
+        assert_eq!((max_index.index, max_index.offset), (2_503, 2_500));
+        assert_eq!(chunks[..1].argminmax_chunked(), None);
+    }
+
+    #[cfg(feature = "float")]
+    #[apply(dtypes_with_nan_arrow2)]
+    fn test_argminmax_chunked_arrow2_nan<T>(#[case] _min: T, #[case] _max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T> + NaNArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + NativeType + PartialOrd,
+    {
+        let nan = T::from_f64(f64::NAN).unwrap();
+        let one = T::from_usize(1).unwrap();
+        let chunks: Vec<PrimitiveArray<T>> = [
+            vec![nan, nan],
+            vec![],
+            vec![one, T::from_usize(2).unwrap(), nan],
+            vec![T::from_usize(0).unwrap(), nan],
+        ]
+        .iter()
+        .map(|chunk| PrimitiveArray::<T>::from_slice(chunk))
+        .collect();
+        // NaNs are ignored
+        let (min_index, max_index) = chunks.argminmax_chunked().unwrap();
+        assert_eq!(
+            (min_index.chunk_index, min_index.offset, min_index.index),
+            (3, 0, 5)
+        );
+        assert_eq!(
+            (max_index.chunk_index, max_index.offset, max_index.index),
+            (2, 1, 3)
+        );
+        // NaNs are returned
+        let first_nan = ChunkedIndex {
+            chunk_index: 0,
+            offset: 0,
+            index: 0,
+        };
+        assert_eq!(chunks.nanargminmax_chunked(), Some((first_nan, first_nan)));
+        // Only NaNs (and empty chunks) -> position of the first value when ignoring NaNs
+        assert_eq!(
+            chunks[..2].argminmax_chunked(),
+            Some((first_nan, first_nan))
+        );
+        let chunks = &chunks[1..];
+        assert_eq!(chunks.nanargmin_chunked().unwrap().index, 2);
+        assert_eq!(chunks.nanargmax_chunked().unwrap().chunk_index, 1);
+    }
+
+    #[apply(dtypes_arrow2)]
+    fn test_argminmax_with_values_arrow2<T>(#[case] _min: T, #[case] max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + AsPrimitive<usize> + NativeType,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let arrow: PrimitiveArray<T> = PrimitiveArray::from_vec(data.clone());
+        let result = arrow.argminmax_with_values();
+        assert_eq!(result, data.argminmax_with_values());
+        assert_eq!(result.values(), (data[0], data[max_index - 1]));
+        assert_eq!(arrow.minmax(), (data[0], data[max_index - 1]));
+    }
+
+    #[apply(dtypes_arrow2)]
+    fn test_argminmax_k_arrow2<T>(#[case] _min: T, #[case] max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + AsPrimitive<usize> + NativeType,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let arrow: PrimitiveArray<T> = PrimitiveArray::from_vec(data.clone());
+        assert_eq!(arrow.argmin_k(5), data.argmin_k(5));
+        assert_eq!(arrow.argmax_k(5), data.argmax_k(5));
+    }
+
+    #[apply(dtypes_arrow2)]
+    fn test_argminmax_with_tie_arrow2<T>(#[case] _min: T, #[case] max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + AsPrimitive<usize> + NativeType,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: Vec<T> = get_monotonic_array(ARRAY_LENGTH, max_index);
+        let arrow: PrimitiveArray<T> = PrimitiveArray::from_vec(data.clone());
+        for tie in [TiePolicy::First, TiePolicy::Last] {
+            assert_eq!(arrow.argminmax_with_tie(tie), data.argminmax_with_tie(tie));
+        }
+    }
+
+    #[apply(dtypes_arrow2)]
+    fn test_try_argminmax_arrow2<T>(#[case] _min: T, #[case] max: T)
+    where
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
+        T: Copy + FromPrimitive + AsPrimitive<usize> + NativeType,
+    {
+        // max_index is the max value that can be represented by T
+        let max_index: usize = std::cmp::min(ARRAY_LENGTH, max.as_());
+
+        let data: PrimitiveArray<T> =
+            PrimitiveArray::from_vec(get_monotonic_array(ARRAY_LENGTH, max_index));
+        assert_eq!(data.try_argminmax(), Ok((0, max_index - 1)));
+        assert_eq!(data.try_argmin(), Ok(0));
+        assert_eq!(data.try_argmax(), Ok(max_index - 1));
+
+        let empty: PrimitiveArray<T> = PrimitiveArray::from_vec(Vec::new());
+        assert_eq!(empty.try_argminmax(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_argmin(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_argmax(), Err(ArgMinMaxError::EmptyInput));
+    }
+
+    #[apply(dtypes_arrow2)]
     fn test_argminmax_many_random_runs_arrow2<T>(#[case] min: T, #[case] max: T)
     where
-        for<'a> &'a [T]: ArgMinMax,
+        for<'a> &'a [T]: ArgMinMax<Item = T>,
         T: Copy
             + FromPrimitive
             + AsPrimitive<usize>
@@ -721,6 +3060,40 @@ mod arrow2_tests {
         assert_eq!(max, max_index - 1);
         assert_eq!(max, (&data).nanargmax());
 
+        // --- with values
+        let result = data.argminmax_with_values();
+        assert_eq!(result.indices(), (0, max_index - 1));
+        assert_eq!(result.min_value, data.value(0));
+        assert_eq!(result.max_value, data.value(max_index - 1));
+        assert_eq!(data.nanargminmax_with_values(), result);
+
+        // --- fallible
+        assert_eq!(data.try_argminmax(), Ok((0, max_index - 1)));
+        assert_eq!(data.try_nanargminmax(), Ok((0, max_index - 1)));
+        let empty: PrimitiveArray<arrow2::types::f16> = PrimitiveArray::from_vec(Vec::new());
+        assert_eq!(empty.try_argminmax(), Err(ArgMinMaxError::EmptyInput));
+        assert_eq!(empty.try_nanargminmax(), Err(ArgMinMaxError::EmptyInput));
+
+        // --- top-k (the array is cyclic -> equal values are sorted on their index)
+        assert_eq!(data.argmax_k(2), vec![max_index - 1, 2 * max_index - 1]);
+        assert_eq!(data.argmin_k(2), vec![0, max_index]);
+        assert_eq!(data.nanargmin_k(2), data.argmin_k(2));
+
+        // --- tie policy (the array is cyclic)
+        let last_min = (ARRAY_LENGTH - 1) / max_index * max_index;
+        assert_eq!(data.argmin_with_tie(TiePolicy::Last), last_min);
+        assert_eq!(data.nanargmin_with_tie(TiePolicy::Last), last_min);
+        assert_eq!(data.argmin_with_tie(TiePolicy::First), 0);
+
+        // --- nullable
+        let nullable: Vec<Option<arrow2::types::f16>> = get_nullable_array::<f16>(7)
+            .into_iter()
+            .map(|x| x.map(|x| arrow2::types::f16(x.to_bits())))
+            .collect();
+        let nullable: PrimitiveArray<arrow2::types::f16> = PrimitiveArray::from(nullable);
+        assert_eq!(nullable.argminmax_nullable(), Some((1_500, 2_500)));
+        assert_eq!(nullable.nanargminmax_nullable(), Some((1_500, 2_500)));
+
         // --- many random runs
         for _ in 0..NB_RANDOM_RUNS {
             let data: Vec<i16> =